@@ -172,7 +172,7 @@ fn insert_store_dictionary_file(
             enum_list.push(enum_name.clone());
 
             if let Some(operation) = &path_item.get {
-                let api_context = ApiContext::new(url, "get", path_item, operation);
+                let api_context = ApiContext::new(url, "get", path_item, operation, open_api);
                 let fn_name = &api_context.func_name;
                 let description = &api_context
                     .description
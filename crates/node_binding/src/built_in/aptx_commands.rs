@@ -4,6 +4,7 @@
 //! - `aptx:functions` - Generate function-style API calls
 //! - `aptx:react-query` - Generate React Query hooks
 //! - `aptx:vue-query` - Generate Vue Query composables
+//! - `aptx:webhooks` - Generate webhook/callback handler types and a dispatcher
 
 use std::path::Path;
 
@@ -12,11 +13,12 @@ use swagger_gen::manifest::{generate_reports, update_manifest, ManifestTracker};
 use swagger_gen::pipeline::{CodegenPipeline, FileSystemWriter};
 use swagger_gen_aptx::{
   AptxFunctionsRenderer, AptxMetaPass, AptxQueryMutationPass, AptxReactQueryRenderer,
-  AptxVueQueryRenderer,
+  AptxVueQueryRenderer, AptxWebhooksRenderer,
 };
 use swagger_tk::model::OpenAPIObject;
 
 use super::output_lock::lock_output_root;
+use super::spec_filter_args::SpecFilterArgs;
 
 /// Common options for @aptx codegen commands
 #[derive(Debug, Clone, Parser)]
@@ -53,6 +55,9 @@ pub struct AptxCodegenOps {
   /// Preview mode: generate report without updating manifest
   #[arg(long, default_value = "false")]
   dry_run: bool,
+
+  #[command(flatten)]
+  filter: SpecFilterArgs,
 }
 
 /// Build client import configuration from command-line options
@@ -62,15 +67,12 @@ fn build_client_import_config(
   client_package: Option<&str>,
   client_import_name: Option<&str>,
 ) -> Option<swagger_gen::pipeline::ClientImportConfig> {
-  match client_mode {
-    None => None,
-    Some(mode) => Some(swagger_gen::pipeline::ClientImportConfig {
-      mode: mode.to_string(),
-      client_path: client_path.map(|s| s.to_string()),
-      client_package: client_package.map(|s| s.to_string()),
-      import_name: client_import_name.map(|s| s.to_string()),
-    }),
-  }
+  client_mode.map(|mode| swagger_gen::pipeline::ClientImportConfig {
+    mode: mode.to_string(),
+    client_path: client_path.map(|s| s.to_string()),
+    client_package: client_package.map(|s| s.to_string()),
+    import_name: client_import_name.map(|s| s.to_string()),
+  })
 }
 
 /// Build model import configuration from command-line options
@@ -175,10 +177,14 @@ fn run_aptx_codegen(
       .with_transform(Box::new(AptxMetaPass))
       .with_client_import(client_import)
       .with_model_import(model_import)
+      .with_filter(options.filter.to_filter())
       .with_renderer(renderer)
       .with_writer(Box::new(FileSystemWriter::new(output)));
 
     let execution_plan = pipeline.plan(open_api)?;
+    for warning in &execution_plan.parse_warnings {
+      eprintln!("Warning: {warning}");
+    }
 
     if !options.no_manifest {
       process_manifest(
@@ -228,6 +234,16 @@ pub fn run_aptx_vue_query(args: &[String], open_api: &OpenAPIObject) {
   );
 }
 
+/// Run aptx:webhooks command
+pub fn run_aptx_webhooks(args: &[String], open_api: &OpenAPIObject) {
+  run_aptx_codegen(
+    args,
+    open_api,
+    "aptx:webhooks",
+    Box::new(AptxWebhooksRenderer),
+  );
+}
+
 #[cfg(test)]
 mod tests {
   use super::{build_model_import_config, manifest_entry_name};
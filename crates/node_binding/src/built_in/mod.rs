@@ -8,6 +8,8 @@ pub mod model_gen;
 pub mod model_ir;
 pub mod output_lock;
 pub mod python_commands;
+pub mod spec_commands;
+pub mod spec_filter_args;
 
 /// 注册内置的命令
 pub fn register_built_in_command(command: &CommandRegistry) {
@@ -81,6 +83,14 @@ pub fn register_built_in_command(command: &CommandRegistry) {
     Box::new(aptx_commands::run_aptx_vue_query),
   );
 
+  command.register_command_with_descriptor(
+    CommandDescriptor {
+      name: "aptx:webhooks".to_string(),
+      ..Default::default()
+    },
+    Box::new(aptx_commands::run_aptx_webhooks),
+  );
+
   // Register python namespace commands
   command.register_command_with_descriptor(
     CommandDescriptor {
@@ -114,5 +124,46 @@ pub fn register_built_in_command(command: &CommandRegistry) {
     Box::new(python_commands::run_python_barrel),
   );
 
+  // Register spec namespace commands
+  command.register_command_with_descriptor(
+    CommandDescriptor {
+      name: "spec:bundle".to_string(),
+      ..Default::default()
+    },
+    Box::new(spec_commands::run_spec_bundle),
+  );
+
+  command.register_command_with_descriptor(
+    CommandDescriptor {
+      name: "spec:lint".to_string(),
+      ..Default::default()
+    },
+    Box::new(spec_commands::run_spec_lint),
+  );
+
+  command.register_command_with_descriptor(
+    CommandDescriptor {
+      name: "spec:normalize".to_string(),
+      ..Default::default()
+    },
+    Box::new(spec_commands::run_spec_normalize),
+  );
+
+  command.register_command_with_descriptor(
+    CommandDescriptor {
+      name: "spec:diff".to_string(),
+      ..Default::default()
+    },
+    Box::new(spec_commands::run_spec_diff),
+  );
+
+  command.register_command_with_descriptor(
+    CommandDescriptor {
+      name: "spec:overlay".to_string(),
+      ..Default::default()
+    },
+    Box::new(spec_commands::run_spec_overlay),
+  );
+
   frontend_plugin_materal::init_plugin(command);
 }
@@ -6,10 +6,12 @@ use swagger_gen::manifest::{generate_reports, update_manifest, ManifestTracker};
 use swagger_gen::model_pipeline::{
   generate_model_files, generate_model_files_with_existing, ModelRenderStyle,
 };
+use swagger_gen::pipeline::collect_parse_warnings;
 use swagger_tk::model::OpenAPIObject;
 
 use super::model_enum_plan::load_existing_enums_from_model_files;
 use super::output_lock::lock_output_root;
+use super::spec_filter_args::SpecFilterArgs;
 
 #[derive(Debug, Clone, Parser)]
 pub struct ModelGenOps {
@@ -36,6 +38,9 @@ pub struct ModelGenOps {
   /// Preview mode: generate report without updating manifest
   #[arg(long, default_value = "false")]
   dry_run: bool,
+
+  #[command(flatten)]
+  filter: SpecFilterArgs,
 }
 
 pub fn run_model_gen(args: &[String], open_api: &OpenAPIObject) {
@@ -48,6 +53,11 @@ pub fn run_model_gen(args: &[String], open_api: &OpenAPIObject) {
   ensure_path(output);
   let style = ModelRenderStyle::parse(&options.style).unwrap();
   let only_names = options.name.unwrap_or_default();
+  let filtered = options.filter.apply(open_api).unwrap();
+  let open_api = filtered.as_ref().unwrap_or(open_api);
+  for warning in collect_parse_warnings(open_api) {
+    eprintln!("Warning: {warning}");
+  }
 
   // Create tracker
   let mut tracker = ManifestTracker::new("models");
@@ -5,10 +5,15 @@ use clap::Parser;
 use swagger_gen::model_pipeline::build_model_ir_snapshot_json;
 use swagger_tk::model::OpenAPIObject;
 
+use super::spec_filter_args::SpecFilterArgs;
+
 #[derive(Debug, Clone, Parser)]
 pub struct ModelIrOps {
   #[arg(long)]
   output: String,
+
+  #[command(flatten)]
+  filter: SpecFilterArgs,
 }
 
 pub fn export_model_ir_snapshot(args: &[String], open_api: &OpenAPIObject) {
@@ -22,6 +27,7 @@ pub fn export_model_ir_snapshot(args: &[String], open_api: &OpenAPIObject) {
     ensure_path(parent);
   }
 
-  let json = build_model_ir_snapshot_json(open_api).unwrap();
+  let filtered = options.filter.apply(open_api).unwrap();
+  let json = build_model_ir_snapshot_json(filtered.as_ref().unwrap_or(open_api)).unwrap();
   fs::write(output, json).unwrap();
 }
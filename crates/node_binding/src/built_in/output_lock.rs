@@ -20,6 +20,7 @@ pub fn lock_output_root(output: &Path) -> Result<OutputLockGuard, String> {
   let path = output.join(OUTPUT_LOCK_FILE_NAME);
   let file = OpenOptions::new()
     .create(true)
+    .truncate(false)
     .read(true)
     .write(true)
     .open(&path)
@@ -17,6 +17,7 @@ use swagger_gen_python::{
 use swagger_tk::model::OpenAPIObject;
 
 use super::output_lock::lock_output_root;
+use super::spec_filter_args::SpecFilterArgs;
 
 /// Common options for Python codegen commands
 #[derive(Debug, Clone, Parser)]
@@ -39,6 +40,9 @@ pub struct PythonCodegenOps {
 
   #[arg(long, default_value = "false")]
   dry_run: bool,
+
+  #[command(flatten)]
+  filter: SpecFilterArgs,
 }
 
 /// Options for the python:barrel command
@@ -141,10 +145,14 @@ fn run_python_codegen(
     let pipeline = CodegenPipeline::default()
       .with_model_import(model_import)
       .with_output_root(Some(output.to_string_lossy().to_string()))
+      .with_filter(options.filter.to_filter())
       .with_renderer(renderer)
       .with_writer(Box::new(FileSystemWriter::new(output)));
 
     let execution_plan = pipeline.plan(open_api)?;
+    for warning in &execution_plan.parse_warnings {
+      eprintln!("Warning: {warning}");
+    }
 
     if !options.no_manifest {
       process_manifest(
@@ -179,6 +187,7 @@ pub fn run_python_model(args: &[String], open_api: &OpenAPIObject) {
   use aptx_frontend_tk_binding_plugin::utils::ensure_path;
   use std::fs;
   use swagger_gen::model_pipeline::parse_openapi_to_model_ir;
+  use swagger_gen::pipeline::collect_parse_warnings;
   use swagger_gen_python::render_pydantic_models;
 
   let result = (|| -> Result<(), String> {
@@ -192,6 +201,11 @@ pub fn run_python_model(args: &[String], open_api: &OpenAPIObject) {
     let _output_lock = lock_output_root(output)?;
     ensure_path(output);
 
+    let filtered = options.filter.apply(open_api)?;
+    let open_api = filtered.as_ref().unwrap_or(open_api);
+    for warning in collect_parse_warnings(open_api) {
+      eprintln!("Warning: {warning}");
+    }
     let ir = parse_openapi_to_model_ir(open_api)
       .map_err(|e| format!("Failed to parse OpenAPI to model IR: {e}"))?;
 
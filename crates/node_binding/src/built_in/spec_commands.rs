@@ -0,0 +1,291 @@
+//! spec:* commands that operate on the OpenAPI document itself.
+
+use std::{fs, path::Path};
+
+use aptx_frontend_tk_binding_plugin::utils::ensure_path;
+use clap::{Parser, ValueEnum};
+use swagger_tk::{
+  diff::{diff_open_api, has_breaking_changes, ChangeSeverity, SpecChange},
+  lint::{has_lint_errors, lint_open_api, LintSeverity},
+  loader::{
+    bundle_spec, load_open_api, load_spec_value, normalize_spec, stringify_spec, SpecFormat,
+  },
+  model::OpenAPIObject,
+  overlay::{apply_overlay, load_overlay},
+};
+
+/// Options for the spec:bundle command
+#[derive(Debug, Clone, Parser)]
+pub struct SpecBundleOps {
+  /// Root spec file whose relative `$ref`s are resolved
+  #[arg(short, long)]
+  input: String,
+
+  /// Output file; `.yaml` / `.yml` writes YAML, anything else writes JSON
+  #[arg(short, long)]
+  output: String,
+}
+
+/// Run spec:bundle command - resolve external `$ref`s and write a single-file spec
+pub fn run_spec_bundle(args: &[String], _open_api: &OpenAPIObject) {
+  let result = (|| -> Result<(), String> {
+    let args: Vec<String> = std::iter::once("--".to_string())
+      .chain(args.iter().cloned())
+      .collect();
+    let options =
+      SpecBundleOps::try_parse_from(args).map_err(|e| format!("Invalid arguments: {e}"))?;
+
+    let bundled = bundle_spec(Path::new(&options.input)).map_err(|e| e.to_string())?;
+    write_spec(Path::new(&options.output), &bundled)?;
+    println!("Bundled spec written to: {}", options.output);
+    Ok(())
+  })();
+
+  if let Err(e) = result {
+    panic!("spec:bundle failed: {e}");
+  }
+}
+
+/// Report format for spec:lint
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LintFormat {
+  Text,
+  Json,
+}
+
+/// Options for the spec:lint command
+#[derive(Debug, Clone, Parser)]
+pub struct SpecLintOps {
+  /// Report format
+  #[arg(long, value_enum, default_value = "text")]
+  format: LintFormat,
+
+  /// Write the report to a file instead of stdout
+  #[arg(short, long)]
+  output: Option<String>,
+}
+
+/// Run spec:lint command - report spec problems that degrade generated code.
+/// Fails when any diagnostic has `error` severity.
+pub fn run_spec_lint(args: &[String], open_api: &OpenAPIObject) {
+  let result = (|| -> Result<(), String> {
+    let args: Vec<String> = std::iter::once("--".to_string())
+      .chain(args.iter().cloned())
+      .collect();
+    let options =
+      SpecLintOps::try_parse_from(args).map_err(|e| format!("Invalid arguments: {e}"))?;
+
+    let diagnostics = lint_open_api(open_api);
+    let report = match options.format {
+      LintFormat::Json => serde_json::to_string_pretty(&diagnostics)
+        .map_err(|e| format!("Failed to serialize lint report: {e}"))?,
+      LintFormat::Text => {
+        let count = |severity| {
+          diagnostics
+            .iter()
+            .filter(|item| item.severity == severity)
+            .count()
+        };
+        let mut lines = diagnostics
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>();
+        lines.push(format!(
+          "{} error(s), {} warning(s), {} info",
+          count(LintSeverity::Error),
+          count(LintSeverity::Warning),
+          count(LintSeverity::Info)
+        ));
+        lines.join("\n")
+      }
+    };
+
+    write_report(options.output.as_deref(), "Lint", &report)?;
+
+    if has_lint_errors(&diagnostics) {
+      return Err("spec has lint errors".to_string());
+    }
+    Ok(())
+  })();
+
+  if let Err(e) = result {
+    panic!("spec:lint failed: {e}");
+  }
+}
+
+/// Options for the spec:normalize command
+#[derive(Debug, Clone, Parser)]
+pub struct SpecNormalizeOps {
+  /// Output file, `.yaml` / `.yml` writes YAML, otherwise JSON
+  #[arg(short, long)]
+  output: String,
+}
+
+/// Run spec:normalize command - re-serialize the loaded spec with sorted keys
+/// and de-duplicated lists so that diffs between versions stay readable
+pub fn run_spec_normalize(args: &[String], open_api: &OpenAPIObject) {
+  let result = (|| -> Result<(), String> {
+    let args: Vec<String> = std::iter::once("--".to_string())
+      .chain(args.iter().cloned())
+      .collect();
+    let options =
+      SpecNormalizeOps::try_parse_from(args).map_err(|e| format!("Invalid arguments: {e}"))?;
+
+    let normalized = normalize_spec(open_api)?;
+    write_spec(Path::new(&options.output), &normalized)?;
+    println!("Normalized spec written to: {}", options.output);
+    Ok(())
+  })();
+
+  if let Err(e) = result {
+    panic!("spec:normalize failed: {e}");
+  }
+}
+
+/// Options for the spec:overlay command
+#[derive(Debug, Clone, Parser)]
+pub struct SpecOverlayOps {
+  /// Spec file to patch; external `$ref`s and merge manifests are resolved first
+  #[arg(short, long)]
+  input: String,
+
+  /// Overlay 1.0 document, repeat to apply several in order
+  #[arg(long, required = true)]
+  overlay: Vec<String>,
+
+  /// Output file, `.yaml` / `.yml` writes YAML, otherwise JSON
+  #[arg(short, long)]
+  output: String,
+}
+
+/// Run spec:overlay command - apply Overlay documents to a spec and write the result
+pub fn run_spec_overlay(args: &[String], _open_api: &OpenAPIObject) {
+  let result = (|| -> Result<(), String> {
+    let args: Vec<String> = std::iter::once("--".to_string())
+      .chain(args.iter().cloned())
+      .collect();
+    let options =
+      SpecOverlayOps::try_parse_from(args).map_err(|e| format!("Invalid arguments: {e}"))?;
+
+    let mut spec = load_spec_value(Path::new(&options.input)).map_err(|e| e.to_string())?;
+    for overlay_path in &options.overlay {
+      let overlay = load_overlay(Path::new(overlay_path)).map_err(|e| e.to_string())?;
+      let warnings =
+        apply_overlay(&mut spec, &overlay).map_err(|e| format!("{overlay_path}: {e}"))?;
+      for warning in warnings {
+        eprintln!("Warning: {overlay_path}: {warning}");
+      }
+    }
+    write_spec(Path::new(&options.output), &spec)?;
+    println!("Overlaid spec written to: {}", options.output);
+    Ok(())
+  })();
+
+  if let Err(e) = result {
+    panic!("spec:overlay failed: {e}");
+  }
+}
+
+/// Report format for spec:diff
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DiffFormat {
+  Markdown,
+  Json,
+}
+
+/// Options for the spec:diff command
+#[derive(Debug, Clone, Parser)]
+pub struct SpecDiffOps {
+  /// Previous version of the spec to compare `-i` against
+  #[arg(long)]
+  base: String,
+
+  /// Report format
+  #[arg(long, value_enum, default_value = "markdown")]
+  format: DiffFormat,
+
+  /// Write the report to a file instead of stdout
+  #[arg(short, long)]
+  output: Option<String>,
+}
+
+/// Run spec:diff command - compare the spec against `--base` and classify each change.
+/// Fails when any change is breaking.
+pub fn run_spec_diff(args: &[String], open_api: &OpenAPIObject) {
+  let result = (|| -> Result<(), String> {
+    let args: Vec<String> = std::iter::once("--".to_string())
+      .chain(args.iter().cloned())
+      .collect();
+    let options =
+      SpecDiffOps::try_parse_from(args).map_err(|e| format!("Invalid arguments: {e}"))?;
+
+    let base = load_open_api(Path::new(&options.base)).map_err(|e| e.to_string())?;
+    let changes = diff_open_api(&base, open_api);
+    let report = match options.format {
+      DiffFormat::Json => serde_json::to_string_pretty(&changes)
+        .map_err(|e| format!("Failed to serialize diff report: {e}"))?,
+      DiffFormat::Markdown => render_diff_markdown(&changes),
+    };
+    write_report(options.output.as_deref(), "Diff", &report)?;
+
+    if has_breaking_changes(&changes) {
+      return Err("spec has breaking changes".to_string());
+    }
+    Ok(())
+  })();
+
+  if let Err(e) = result {
+    panic!("spec:diff failed: {e}");
+  }
+}
+
+fn render_diff_markdown(changes: &[SpecChange]) -> String {
+  let section = |title: &str, severity: ChangeSeverity| {
+    let items = changes
+      .iter()
+      .filter(|item| item.severity == severity)
+      .map(|item| format!("- `{}` `{}`: {}", item.rule, item.pointer, item.message))
+      .collect::<Vec<_>>();
+    if items.is_empty() {
+      return None;
+    }
+    Some(format!(
+      "## {title} ({})\n\n{}",
+      items.len(),
+      items.join("\n")
+    ))
+  };
+
+  let mut sections = vec!["# API changes".to_string()];
+  sections.extend(section("Breaking", ChangeSeverity::Breaking));
+  sections.extend(section("Non-breaking", ChangeSeverity::NonBreaking));
+  if changes.is_empty() {
+    sections.push("No changes.".to_string());
+  }
+  sections.join("\n\n")
+}
+
+fn write_report(output: Option<&str>, label: &str, report: &str) -> Result<(), String> {
+  match output {
+    Some(output) => {
+      let output = Path::new(output);
+      if let Some(parent) = output.parent() {
+        ensure_path(parent);
+      }
+      fs::write(output, format!("{report}\n"))
+        .map_err(|e| format!("Failed to write {}: {e}", output.display()))?;
+      println!("{label} report written to: {}", output.display());
+    }
+    None => println!("{report}"),
+  }
+  Ok(())
+}
+
+fn write_spec(output: &Path, value: &serde_json::Value) -> Result<(), String> {
+  if let Some(parent) = output.parent() {
+    ensure_path(parent);
+  }
+  let format = SpecFormat::from_path(output).unwrap_or(SpecFormat::Json);
+  let content = stringify_spec(value, format)?;
+  fs::write(output, content).map_err(|e| format!("Failed to write {}: {e}", output.display()))
+}
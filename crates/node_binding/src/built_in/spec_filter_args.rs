@@ -0,0 +1,74 @@
+//! Endpoint filter options shared by codegen commands.
+
+use clap::Args;
+use swagger_tk::{
+  filter::{filter_open_api, SpecFilter},
+  model::OpenAPIObject,
+};
+
+/// Include/exclude filters. Every flag can be repeated or take a comma-separated list.
+#[derive(Debug, Clone, Default, Args)]
+pub struct SpecFilterArgs {
+  /// Only generate endpoints with one of these tags
+  #[arg(long, value_delimiter = ',')]
+  include_tag: Vec<String>,
+
+  /// Skip endpoints with any of these tags
+  #[arg(long, value_delimiter = ',')]
+  exclude_tag: Vec<String>,
+
+  /// Only generate endpoints whose path matches one of these globs (`*`, `**`, `?`)
+  #[arg(long, value_delimiter = ',')]
+  include_path: Vec<String>,
+
+  /// Skip endpoints whose path matches any of these globs
+  #[arg(long, value_delimiter = ',')]
+  exclude_path: Vec<String>,
+
+  /// Only generate endpoints with one of these HTTP methods
+  #[arg(long, value_delimiter = ',')]
+  include_method: Vec<String>,
+
+  /// Skip endpoints with any of these HTTP methods
+  #[arg(long, value_delimiter = ',')]
+  exclude_method: Vec<String>,
+
+  /// Only generate endpoints with one of these operationIds
+  #[arg(long, value_delimiter = ',')]
+  include_operation_id: Vec<String>,
+
+  /// Skip endpoints with any of these operationIds
+  #[arg(long, value_delimiter = ',')]
+  exclude_operation_id: Vec<String>,
+
+  /// Skip endpoints marked with `x-internal: true`
+  #[arg(long, default_value = "false")]
+  exclude_internal: bool,
+}
+
+impl SpecFilterArgs {
+  /// Build the filter, or `None` when no flag is set
+  pub fn to_filter(&self) -> Option<SpecFilter> {
+    let filter = SpecFilter {
+      include_tags: self.include_tag.clone(),
+      exclude_tags: self.exclude_tag.clone(),
+      include_paths: self.include_path.clone(),
+      exclude_paths: self.exclude_path.clone(),
+      include_methods: self.include_method.clone(),
+      exclude_methods: self.exclude_method.clone(),
+      include_operation_ids: self.include_operation_id.clone(),
+      exclude_operation_ids: self.exclude_operation_id.clone(),
+      exclude_internal: self.exclude_internal,
+    };
+    (!filter.is_empty()).then_some(filter)
+  }
+
+  /// Filter the spec for commands that read it without a `CodegenPipeline`.
+  /// Returns `None` when no flag is set.
+  pub fn apply(&self, open_api: &OpenAPIObject) -> Result<Option<OpenAPIObject>, String> {
+    self
+      .to_filter()
+      .map(|filter| filter_open_api(open_api, &filter))
+      .transpose()
+  }
+}
@@ -1,11 +1,17 @@
 #![deny(clippy::all)]
 
-use std::{env::current_dir, path::Path, str::FromStr};
+use std::{
+  env::current_dir,
+  path::{Path, PathBuf},
+};
 
 use bootstrap::init_command_factory;
 use built_in::register_built_in_command;
 use napi::Error;
-use swagger_tk::model::OpenAPIObject;
+use swagger_tk::{
+  loader::{load_open_api, load_open_api_with_overlays},
+  model::OpenAPIObject,
+};
 
 mod bootstrap;
 mod built_in;
@@ -25,21 +31,22 @@ pub struct RunCliOptions {
 #[napi]
 pub fn run_cli(options: RunCliOptions) -> napi::Result<()> {
   // Parse OpenAPI only when input is provided
-  let open_api = if let Some(input_path) = &options.input {
-    let path = Path::new(input_path);
-    let abs_path = if path.is_absolute() {
-      path.to_path_buf()
-    } else {
-      current_dir().unwrap().join(input_path)
-    };
-    let text =
-      std::fs::read_to_string(&abs_path).map_err(|err| Error::from_reason(err.to_string()))?;
-    OpenAPIObject::from_str(&text).map_err(|err| Error::from_reason(err.to_string()))?
+  let (open_api, command_options) = if let Some(input_path) = &options.input {
+    // Overlays patch the input before it is parsed, so they work with every command
+    let (overlays, command_options) =
+      take_overlay_args(&options.options).map_err(Error::from_reason)?;
+    let (open_api, warnings) = load_open_api_with_overlays(&resolve_path(input_path), &overlays)
+      .map_err(|err| Error::from_reason(err.to_string()))?;
+    for warning in warnings {
+      eprintln!("Warning: {warning}");
+    }
+    (open_api, command_options)
   } else {
     // Create a minimal valid OpenAPIObject for commands that don't need it
-    OpenAPIObject {
+    let open_api = OpenAPIObject {
       openapi: "3.0.0".to_string(),
       info: None,
+      json_schema_dialect: None,
       servers: None,
       paths: None,
       webhooks: None,
@@ -47,7 +54,10 @@ pub fn run_cli(options: RunCliOptions) -> napi::Result<()> {
       security: None,
       tags: None,
       external_docs: None,
-    }
+      extensions: Default::default(),
+      unknown_fields: Default::default(),
+    };
+    (open_api, options.options.clone())
   };
 
   let command_factory =
@@ -56,26 +66,76 @@ pub fn run_cli(options: RunCliOptions) -> napi::Result<()> {
 
   command_factory
     .command
-    .execute_command(&options.command, &options.options, &open_api)
+    .execute_command(&options.command, &command_options, &open_api)
     .map_err(Error::from_reason)?;
   Ok(())
 }
 
 #[napi]
 pub fn get_ir(input_path: String) -> napi::Result<serde_json::Value> {
-  let path = Path::new(&input_path);
-  let abs_path = if path.is_absolute() {
-    path.to_path_buf()
-  } else {
-    current_dir().unwrap().join(input_path)
-  };
-  let text = std::fs::read_to_string(&abs_path)
-    .map_err(|err| Error::from_reason(format!("Failed to read OpenAPI file: {}", err)))?;
-  let open_api = OpenAPIObject::from_str(&text)
-    .map_err(|err| Error::from_reason(format!("OpenAPI parse error: {}", err)))?;
-  let json_str = swagger_gen::pipeline::build_ir_snapshot_json(&open_api)
-    .map_err(|err| Error::from_reason(err))?;
+  let open_api =
+    load_open_api(&resolve_path(&input_path)).map_err(|err| Error::from_reason(err.to_string()))?;
+  let json_str =
+    swagger_gen::pipeline::build_ir_snapshot_json(&open_api).map_err(Error::from_reason)?;
   let value: serde_json::Value = serde_json::from_str(&json_str)
     .map_err(|err| Error::from_reason(format!("JSON deserialization error: {}", err)))?;
   Ok(value)
 }
+
+fn resolve_path(input_path: &str) -> PathBuf {
+  let path = Path::new(input_path);
+  if path.is_absolute() {
+    path.to_path_buf()
+  } else {
+    current_dir().unwrap().join(input_path)
+  }
+}
+
+/// Split `--overlay <file>` / `--overlay=<file>` (repeatable) out of the command options
+fn take_overlay_args(options: &[String]) -> Result<(Vec<PathBuf>, Vec<String>), String> {
+  let mut overlays = Vec::new();
+  let mut rest = Vec::new();
+  let mut iter = options.iter();
+  while let Some(option) = iter.next() {
+    if option == "--overlay" {
+      let value = iter.next().ok_or("--overlay requires a path")?;
+      overlays.push(resolve_path(value));
+    } else if let Some(value) = option.strip_prefix("--overlay=") {
+      overlays.push(resolve_path(value));
+    } else {
+      rest.push(option.clone());
+    }
+  }
+  Ok((overlays, rest))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_take_overlay_args() {
+    let options = [
+      "--output",
+      "./api",
+      "--overlay",
+      "a.yaml",
+      "--overlay=/tmp/b.yaml",
+    ]
+    .map(String::from);
+    let (overlays, rest) = take_overlay_args(&options).unwrap();
+    assert_eq!(rest, ["--output", "./api"]);
+    assert_eq!(overlays.len(), 2);
+    assert!(overlays[0].ends_with("a.yaml"));
+    assert_eq!(overlays[1], PathBuf::from("/tmp/b.yaml"));
+  }
+
+  #[test]
+  fn test_take_overlay_args_requires_a_path() {
+    let options = ["--output", "./api", "--overlay"].map(String::from);
+    assert_eq!(
+      take_overlay_args(&options).unwrap_err(),
+      "--overlay requires a path"
+    );
+  }
+}
@@ -15,7 +15,7 @@ impl<'a> JsApiContextHelper<'a> {
     pub fn get_parameters_string(&self, add_type: bool) -> Option<String> {
         self.api_context.func_parameters.as_ref().map(|v| {
             let mut data = v.clone();
-            data.sort_by(|a, b| b.required.cmp(&a.required));
+            data.sort_by_key(|item| std::cmp::Reverse(item.required));
             data.iter()
                 .map(|p| {
                     if add_type {
@@ -2,8 +2,9 @@ use inflector::cases::pascalcase::to_pascal_case;
 use regex::Regex;
 
 use swagger_tk::model::{
-    OperationObject, OperationObjectParameters, OperationObjectRequestBody, ParameterObjectIn,
-    PathItemObject, ResponsesValue,
+    OpenAPIObject, OperationObject, OperationObjectParameters, OperationObjectRequestBody,
+    ParameterObject, ParameterObjectIn, PathItemObject, PathItemParameters, ReferenceObject,
+    ResponsesValue,
 };
 
 use crate::utils::{ReferenceObjectExtension, SchemaEnumExtension};
@@ -28,13 +29,22 @@ pub struct ApiContext<'a> {
     /// 具体接口定义的原始数据
     pub operation: &'a OperationObject,
 
+    /// 完整的文档，用于解析 components 中的引用
+    pub open_api: &'a OpenAPIObject,
+
     /// 函数接口参数列表
     /// 该数据来源于params及requestBody
     pub func_parameters: Option<Vec<FuncParameter>>,
 
-    /// 返回值类型
+    /// 返回值类型，多个成功响应的类型不同时为联合类型
     pub response_type: Option<String>,
 
+    /// 成功响应（2xx）列表
+    pub success_responses: Vec<StatusResponse>,
+
+    /// 错误响应（4xx、5xx 及 default）列表
+    pub error_responses: Vec<StatusResponse>,
+
     /// 请求参数列表  
     /// @example  
     /// ```js
@@ -93,10 +103,22 @@ pub struct FuncParameter {
 
     /// 默认值
     /// 根据是否有默认值，决定后面是否跟上“= \<default\>”
-    pub default: Option<String>,
+    pub default: Option<serde_json::Value>,
 
     /// 在请求参数中是否过滤
     pub r#in: Option<ParameterObjectIn>,
+
+    /// 示例值
+    pub example: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatusResponse {
+    /// 状态码，如 `201`、`2XX`
+    pub status_code: String,
+
+    /// 响应体类型，没有响应体时为 void
+    pub r#type: String,
 }
 
 #[derive(Debug)]
@@ -114,15 +136,21 @@ impl<'a> ApiContext<'a> {
         method: &'a str,
         path_item: &'a PathItemObject,
         operation: &'a OperationObject,
+        open_api: &'a OpenAPIObject,
     ) -> Self {
+        let success_responses =
+            collect_status_responses(operation.get_success_responses(), open_api);
         let mut result = Self {
             url,
             method,
             path_item,
             operation,
+            open_api,
             func_name: get_func_name(url, method, operation),
             func_parameters: None,
-            response_type: get_raw_response_type(operation),
+            response_type: get_raw_response_type(&success_responses),
+            success_responses,
+            error_responses: collect_status_responses(operation.get_error_responses(), open_api),
             query_params_list: None,
             path_params_list: None,
             cookie_params_list: None,
@@ -140,7 +168,8 @@ impl<'a> ApiContext<'a> {
     }
 
     fn init_ajax_data(&mut self) {
-        let mut params_data = get_func_parameters_object(self.operation);
+        let mut params_data =
+            get_func_parameters_object(self.operation, self.path_item, self.open_api);
         params_data.ensure_name_not_empty();
         match (params_data.parameters, params_data.request_body) {
             // 没有 请求参数 及 请求体
@@ -244,47 +273,69 @@ fn remove_repeat_words(list: &[String]) -> Vec<String> {
 }
 
 /// 获取函数参数列表
-fn get_func_parameters_object(operation: &OperationObject) -> FuncParameterObject {
-    let parameters = operation.parameters.as_ref().map(|v| {
-        v.iter()
-            .map(|p| match p {
-                OperationObjectParameters::Parameter(p) => FuncParameter {
-                    name: p.name.clone(),
-                    r#type: p.schema.as_ref().unwrap().get_ts_type(),
-                    required: p.required.unwrap_or(false),
-                    default: None,
-                    r#in: Some(p.r#in.clone()),
-                },
-                OperationObjectParameters::Reference(r) => FuncParameter {
+/// 路径级参数对所有操作生效，与操作级参数同名且位置相同时以操作级参数为准
+fn get_func_parameters_object(
+    operation: &OperationObject,
+    path_item: &PathItemObject,
+    open_api: &OpenAPIObject,
+) -> FuncParameterObject {
+    let operation_parameters = operation
+        .parameters
+        .iter()
+        .flatten()
+        .map(|p| match (p.resolve(open_api), p) {
+            (Some(p), _) => parameter_to_func_parameter(p),
+            (None, OperationObjectParameters::Reference(r)) => unresolved_func_parameter(r),
+            (None, OperationObjectParameters::Parameter(p)) => parameter_to_func_parameter(p),
+        })
+        .collect::<Vec<_>>();
+    let path_item_parameters = path_item
+        .parameters
+        .iter()
+        .flatten()
+        .map(|p| match (p.resolve(open_api), p) {
+            (Some(p), _) => parameter_to_func_parameter(p),
+            (None, PathItemParameters::Reference(r)) => unresolved_func_parameter(r),
+            (None, PathItemParameters::Parameter(p)) => parameter_to_func_parameter(p),
+        })
+        .filter(|p| {
+            !operation_parameters
+                .iter()
+                .any(|x| x.name == p.name && x.r#in == p.r#in)
+        })
+        .collect::<Vec<_>>();
+    let parameters = (operation.parameters.is_some() || !path_item_parameters.is_empty())
+        .then(|| [path_item_parameters, operation_parameters].concat());
+
+    let request_body =
+        operation
+            .request_body
+            .as_ref()
+            .and_then(|x| match (x.resolve(open_api), x) {
+                (Some(v), _) => {
+                    let data = v.get_preferred_content();
+                    data.map(|(_, media_type)| FuncParameter {
+                        name: String::new(),
+                        r#type: media_type
+                            .schema
+                            .as_ref()
+                            .map_or_else(|| "unknown".to_string(), |schema| schema.get_ts_type()),
+                        required: v.required.unwrap_or(false),
+                        default: None,
+                        r#in: None,
+                        example: media_type.get_example().cloned(),
+                    })
+                }
+                (None, OperationObjectRequestBody::Reference(v)) => Some(FuncParameter {
                     name: String::new(),
-                    r#type: r.get_type_name(),
+                    r#type: v.get_type_name(),
                     required: true,
                     default: None,
                     r#in: None,
-                },
-            })
-            .collect::<Vec<FuncParameter>>()
-    });
-
-    let request_body = operation.request_body.as_ref().and_then(|x| match x {
-        OperationObjectRequestBody::RequestBody(v) => {
-            let data = v.content.iter().take(1).next();
-            data.map(|(_, media_type)| FuncParameter {
-                name: String::new(),
-                r#type: media_type.schema.get_ts_type(),
-                required: v.required.unwrap_or(false),
-                default: None,
-                r#in: None,
-            })
-        }
-        OperationObjectRequestBody::Reference(v) => Some(FuncParameter {
-            name: String::new(),
-            r#type: v.get_type_name(),
-            required: true,
-            default: None,
-            r#in: None,
-        }),
-    });
+                    example: None,
+                }),
+                (None, OperationObjectRequestBody::RequestBody(_)) => None,
+            });
 
     FuncParameterObject {
         parameters,
@@ -292,6 +343,33 @@ fn get_func_parameters_object(operation: &OperationObject) -> FuncParameterObjec
     }
 }
 
+fn parameter_to_func_parameter(p: &ParameterObject) -> FuncParameter {
+    FuncParameter {
+        name: p.name.clone(),
+        r#type: p
+            .schema
+            .as_ref()
+            .map(|schema| schema.get_ts_type())
+            .unwrap_or_else(|| "unknown".to_string()),
+        required: p.required.unwrap_or(false),
+        default: p.get_default().cloned(),
+        r#in: Some(p.r#in.clone()),
+        example: p.get_example().cloned(),
+    }
+}
+
+/// 无法解析的引用保留原有行为：使用引用名作为类型，名称稍后自动生成
+fn unresolved_func_parameter(r: &ReferenceObject) -> FuncParameter {
+    FuncParameter {
+        name: String::new(),
+        r#type: r.get_type_name(),
+        required: true,
+        default: None,
+        r#in: None,
+        example: None,
+    }
+}
+
 #[derive(Debug)]
 pub struct FuncParameterObject {
     parameters: Option<Vec<FuncParameter>>,
@@ -318,18 +396,49 @@ impl FuncParameterObject {
     }
 }
 
-/// 获取原始返回值类型
-fn get_raw_response_type(operation: &OperationObject) -> Option<String> {
-    operation.responses.as_ref().and_then(|v| {
-        v.get("200")
-            .or_else(|| v.get("default"))
-            .and_then(|d| match d {
-                ResponsesValue::Response(v) => v
-                    .content
-                    .as_ref()
-                    .and_then(|x| x.iter().next())
-                    .map(|(_, data)| data.schema.get_ts_type()),
-                ResponsesValue::Reference(v) => Some(v.get_type_name()),
-            })
-    })
+/// 获取响应的状态码及类型，二进制内容的类型为 `Blob`
+fn collect_status_responses(
+    responses: Vec<(&str, &ResponsesValue)>,
+    open_api: &OpenAPIObject,
+) -> Vec<StatusResponse> {
+    responses
+        .into_iter()
+        .map(|(status_code, response)| StatusResponse {
+            status_code: status_code.to_string(),
+            r#type: match (response.resolve(open_api), response) {
+                (Some(v), _) => {
+                    v.content
+                        .as_ref()
+                        .and_then(|x| x.iter().next())
+                        .map(|(media_type, data)| {
+                            if data.is_binary(media_type) {
+                                "Blob".to_string()
+                            } else {
+                                data.schema.as_ref().map_or_else(
+                                    || "unknown".to_string(),
+                                    |schema| schema.get_ts_type(),
+                                )
+                            }
+                        })
+                }
+                (None, ResponsesValue::Reference(v)) => Some(v.get_type_name()),
+                (None, ResponsesValue::Response(_)) => None,
+            }
+            .unwrap_or_else(|| "void".to_string()),
+        })
+        .collect()
+}
+
+/// 获取原始返回值类型，不同的成功响应类型合并为联合类型，全部没有响应体时返回 None
+fn get_raw_response_type(success_responses: &[StatusResponse]) -> Option<String> {
+    let mut types = Vec::<&str>::new();
+    for response in success_responses {
+        if !types.contains(&response.r#type.as_str()) {
+            types.push(&response.r#type);
+        }
+    }
+    if types.iter().all(|item| *item == "void") {
+        return None;
+    }
+    Some(types.join(" | "))
 }
@@ -17,17 +17,10 @@ pub trait GenApi {
             let path_item = paths
                 .get(url)
                 .ok_or(format!("can't find path data: {url}"))?;
-            for &(method, operation) in &[
-                ("get", &path_item.get),
-                ("put", &path_item.put),
-                ("post", &path_item.post),
-                ("delete", &path_item.delete),
-            ] {
-                if let Some(operation) = operation {
-                    let api_context = ApiContext::new(url, method, path_item, operation);
-                    let rt = self.gen_api(&api_context);
-                    rt?
-                }
+            for (method, operation) in path_item.get_operations() {
+                let api_context = ApiContext::new(url, method, path_item, operation, data);
+                let rt = self.gen_api(&api_context);
+                rt?
             }
         }
         self.gen_name_content_map();
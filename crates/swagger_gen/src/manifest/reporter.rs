@@ -102,7 +102,7 @@ impl DeletionReport {
             for entry in &self.deleted {
                 md.push_str(&format!("| `{}` | `{}` |\n", entry.name, entry.path));
             }
-            md.push_str("\n");
+            md.push('\n');
         }
 
         // 新增的文件
@@ -111,7 +111,7 @@ impl DeletionReport {
             for name in &self.added {
                 md.push_str(&format!("- `{}`\n", name));
             }
-            md.push_str("\n");
+            md.push('\n');
         }
 
         // LLM 建议
@@ -57,9 +57,18 @@ pub struct ModelNode {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ModelKind {
-    Interface { properties: Vec<ModelProperty> },
-    Enum { members: Vec<ModelEnumMember> },
-    Alias { target: ModelType, nullable: bool },
+    Interface {
+        properties: Vec<ModelProperty>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        extends: Vec<String>,
+    },
+    Enum {
+        members: Vec<ModelEnumMember>,
+    },
+    Alias {
+        target: ModelType,
+        nullable: bool,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,6 +78,12 @@ pub struct ModelProperty {
     pub required: bool,
     pub nullable: bool,
     pub r#type: ModelType,
+    /// Schema `default`, kept as raw JSON so each renderer can emit its own literal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+    /// Schema `example` (or the first of `examples`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,12 +149,42 @@ pub enum ModelType {
     Number,
     Boolean,
     Object,
-    Ref { name: String },
-    Array { item: Box<ModelType> },
-    Union { variants: Vec<ModelType> },
-    Literal { value: ModelLiteral },
+    /// Anonymous object with its own fields, e.g. the sibling `properties` of an `allOf`
+    InlineObject {
+        properties: Vec<ModelProperty>,
+    },
+    Ref {
+        name: String,
+    },
+    Array {
+        item: Box<ModelType>,
+    },
+    Union {
+        variants: Vec<ModelType>,
+    },
+    Intersection {
+        variants: Vec<ModelType>,
+    },
+    Discriminated {
+        property_name: String,
+        variants: Vec<ModelDiscriminatedVariant>,
+    },
+    Literal {
+        value: ModelLiteral,
+    },
+    Unknown,
+}
+
+/// A union member selected by the discriminator property holding one of `tags`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDiscriminatedVariant {
+    pub tags: Vec<String>,
+    pub r#type: ModelType,
 }
 
+/// Literal value of an enum member or literal type. Numeric values hold the text of
+/// the spec's `serde_json::Number`: integers keep full i64/u64 precision (e.g. ids
+/// beyond 2^53) and floats keep their shortest round-trip form such as `100.0`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ModelLiteral {
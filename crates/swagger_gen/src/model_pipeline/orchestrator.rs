@@ -217,7 +217,7 @@ fn apply_patch_to_enum_members(
 }
 
 fn parse_patch_member_value(raw: &str) -> ModelLiteral {
-    if raw.parse::<i64>().is_ok() {
+    if raw.parse::<i64>().is_ok() || raw.parse::<u64>().is_ok() {
         return ModelLiteral::Integer {
             value: raw.to_string(),
         };
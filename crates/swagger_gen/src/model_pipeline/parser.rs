@@ -1,13 +1,16 @@
+use std::collections::BTreeMap;
+
+use serde_json::Number;
 use swagger_tk::{
-    getter::get_all_schema,
-    model::{OpenAPIObject, SchemaEnum},
+    getter::{get_all_schema, get_all_schema_defs, get_schema_by_name},
+    model::{DiscriminatorObject, OpenAPIObject, SchemaAllOf, SchemaEnum},
 };
 
 use crate::utils::{ReferenceObjectExtension, SchemaEnumExtension};
 
 use super::model::{
-    IntegerFormat, IntegerSpec, ModelEnumMember, ModelIr, ModelKind, ModelLiteral, ModelNode,
-    ModelProperty, ModelType, NumberFormat, NumberSpec, ScalarType,
+    IntegerFormat, IntegerSpec, ModelDiscriminatedVariant, ModelEnumMember, ModelIr, ModelKind,
+    ModelLiteral, ModelNode, ModelProperty, ModelType, NumberFormat, NumberSpec, ScalarType,
 };
 
 pub fn build_model_ir(open_api: &OpenAPIObject) -> Result<ModelIr, String> {
@@ -22,38 +25,177 @@ pub fn build_model_ir(open_api: &OpenAPIObject) -> Result<ModelIr, String> {
             .ok_or_else(|| format!("can't find {name} schema"))?;
         models.push(schema_to_model_node(&name, schema, open_api));
     }
+
+    // `$defs` are referenced by their last pointer segment, so they become top-level models
+    for (name, schema) in get_all_schema_defs(open_api)? {
+        if schemas.contains_key(name) {
+            continue;
+        }
+        models.push(schema_to_model_node(name, schema, open_api));
+    }
+
+    apply_discriminator_tags(&mut models, schemas);
     Ok(ModelIr { models })
 }
 
+/// Narrows the discriminator property of every tagged variant to its literal tag(s),
+/// so both the TypeScript union and the pydantic discriminator can tell members apart.
+/// Tags come from discriminated unions in the IR and from schemas that declare a
+/// discriminator: its mapping targets and the schemas inheriting it through `allOf`.
+fn apply_discriminator_tags(models: &mut [ModelNode], schemas: &BTreeMap<String, SchemaEnum>) {
+    let mut assignments = BTreeMap::<String, (String, Vec<String>)>::new();
+    let mut assign = |model_name: &str, property_name: &str, tags: Vec<String>| {
+        let entry = assignments
+            .entry(model_name.to_string())
+            .or_insert_with(|| (property_name.to_string(), Vec::new()));
+        if entry.0 == property_name {
+            entry.1.extend(tags);
+        }
+    };
+
+    for model in models.iter() {
+        for model_type in model_node_types(model) {
+            collect_discriminated_variants(model_type, &mut |property_name, variant| {
+                if let ModelType::Ref { name } = &variant.r#type {
+                    assign(name, property_name, variant.tags.clone());
+                }
+            });
+        }
+    }
+
+    let mut base_names = schemas.keys().collect::<Vec<_>>();
+    base_names.sort();
+    for base_name in base_names {
+        let Some(discriminator) = schemas
+            .get(base_name)
+            .and_then(SchemaEnum::get_discriminator)
+        else {
+            continue;
+        };
+        let mut children = discriminator
+            .get_mapping_schema_names()
+            .into_iter()
+            .filter(|name| name != base_name)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let mut child_names = schemas.keys().collect::<Vec<_>>();
+        child_names.sort();
+        for child_name in child_names {
+            let Some(SchemaEnum::AllOf(child)) = schemas.get(child_name) else {
+                continue;
+            };
+            let inherits = child.all_of.iter().any(|member| {
+                matches!(member, SchemaEnum::Ref(reference) if &reference.get_type_name() == base_name)
+            });
+            if inherits && !children.contains(child_name) {
+                children.push(child_name.clone());
+            }
+        }
+        for child in children {
+            assign(
+                &child,
+                &discriminator.property_name,
+                discriminator.get_tags(&child),
+            );
+        }
+    }
+
+    for model in models.iter_mut() {
+        let Some((property_name, tags)) = assignments.get_mut(&model.name) else {
+            continue;
+        };
+        let ModelKind::Interface { properties, .. } = &mut model.kind else {
+            continue;
+        };
+        tags.sort();
+        tags.dedup();
+        let tag_type = compose_model_type(
+            tags.iter()
+                .map(|tag| string_literal_model_type(tag))
+                .collect(),
+            |variants| ModelType::Union { variants },
+        );
+        match properties
+            .iter_mut()
+            .find(|property| &property.name == property_name)
+        {
+            Some(property) => {
+                property.required = true;
+                property.nullable = false;
+                property.r#type = tag_type;
+            }
+            None => {
+                properties.push(ModelProperty {
+                    name: property_name.clone(),
+                    description: None,
+                    required: true,
+                    nullable: false,
+                    r#type: tag_type,
+                    default: None,
+                    example: None,
+                });
+                properties.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+    }
+}
+
+fn model_node_types(model: &ModelNode) -> Vec<&ModelType> {
+    match &model.kind {
+        ModelKind::Interface { properties, .. } => {
+            properties.iter().map(|property| &property.r#type).collect()
+        }
+        ModelKind::Alias { target, .. } => vec![target],
+        ModelKind::Enum { .. } => Vec::new(),
+    }
+}
+
+fn collect_discriminated_variants(
+    model_type: &ModelType,
+    visit: &mut impl FnMut(&str, &ModelDiscriminatedVariant),
+) {
+    match model_type {
+        ModelType::Discriminated {
+            property_name,
+            variants,
+        } => {
+            for variant in variants {
+                visit(property_name, variant);
+            }
+        }
+        ModelType::Array { item } => collect_discriminated_variants(item, visit),
+        ModelType::InlineObject { properties } => {
+            for property in properties {
+                collect_discriminated_variants(&property.r#type, visit);
+            }
+        }
+        ModelType::Union { variants } | ModelType::Intersection { variants } => {
+            for variant in variants {
+                collect_discriminated_variants(variant, visit);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn schema_to_model_node(name: &str, schema: &SchemaEnum, open_api: &OpenAPIObject) -> ModelNode {
-    ModelNode {
+    let mut node = ModelNode {
         name: name.to_string(),
         description: schema.get_description().cloned(),
         kind: match schema {
-            SchemaEnum::Object(v) => {
-                let required = v.required.as_ref();
-                let mut properties = v
-                    .properties
-                    .as_ref()
-                    .map(|p| {
-                        let mut keys = p.keys().collect::<Vec<_>>();
-                        keys.sort();
-                        keys.into_iter()
-                            .map(|key| {
-                                let child = p.get(key).expect("schema key must exist");
-                                ModelProperty {
-                                    name: key.to_string(),
-                                    description: child.get_description().cloned(),
-                                    required: required.is_some_and(|items| items.contains(key)),
-                                    nullable: child.can_be_null(open_api),
-                                    r#type: schema_to_model_type(child, open_api),
-                                }
-                            })
-                            .collect::<Vec<_>>()
-                    })
-                    .unwrap_or_default();
-                properties.sort_by(|a, b| a.name.cmp(&b.name));
-                ModelKind::Interface { properties }
+            SchemaEnum::Object(v) => ModelKind::Interface {
+                properties: object_properties(
+                    v.properties.as_ref(),
+                    v.required.as_deref().unwrap_or_default(),
+                    open_api,
+                ),
+                extends: Vec::new(),
+            },
+            SchemaEnum::AllOf(v) => {
+                all_of_to_model_kind(v, open_api).unwrap_or_else(|| ModelKind::Alias {
+                    target: schema_to_model_type(schema, open_api),
+                    nullable: schema.can_be_null(open_api),
+                })
             }
             SchemaEnum::String(v) => {
                 if let Some(items) = v.r#enum.as_ref() {
@@ -106,7 +248,123 @@ fn schema_to_model_node(name: &str, schema: &SchemaEnum, open_api: &OpenAPIObjec
                 nullable: schema.can_be_null(open_api),
             },
         },
+    };
+    if let ModelKind::Enum { members } = &mut node.kind {
+        apply_enum_extensions(members, schema);
     }
+    node
+}
+
+/// Uses `x-enumNames` / `x-enum-varnames` for member names and `x-enumDescriptions` /
+/// `x-enum-descriptions` for comments when they match the number of enum values.
+fn apply_enum_extensions(members: &mut [ModelEnumMember], schema: &SchemaEnum) {
+    let Some(extensions) = schema.get_extensions() else {
+        return;
+    };
+    if let Some(names) = extensions
+        .enum_names()
+        .filter(|names| names.len() == members.len())
+    {
+        for (member, name) in members.iter_mut().zip(names) {
+            member.name = name.to_string();
+        }
+    }
+    if let Some(descriptions) = extensions
+        .enum_descriptions()
+        .filter(|descriptions| descriptions.len() == members.len())
+    {
+        for (member, description) in members.iter_mut().zip(descriptions) {
+            member.comment = Some(description.to_string()).filter(|item| !item.is_empty());
+        }
+    }
+}
+
+fn object_properties(
+    properties: Option<&BTreeMap<String, SchemaEnum>>,
+    required: &[String],
+    open_api: &OpenAPIObject,
+) -> Vec<ModelProperty> {
+    let Some(properties) = properties else {
+        return Vec::new();
+    };
+    let mut keys = properties.keys().collect::<Vec<_>>();
+    keys.sort();
+    keys.into_iter()
+        .map(|key| {
+            let child = properties.get(key).expect("schema key must exist");
+            ModelProperty {
+                name: key.to_string(),
+                description: child.get_description().cloned(),
+                required: required.contains(key),
+                nullable: child.can_be_null(open_api),
+                r#type: schema_to_model_type(child, open_api),
+                default: child.get_default().cloned(),
+                example: child.get_example().cloned(),
+            }
+        })
+        .collect()
+}
+
+/// Flattens `allOf` into an interface: `$ref` members pointing at interfaces become
+/// `extends`, inline object members (and sibling `properties`) are merged. Returns
+/// `None` when a member can't be expressed that way (e.g. a ref to an enum or an
+/// array), so the caller falls back to an alias or intersection.
+fn all_of_to_model_kind(schema: &SchemaAllOf, open_api: &OpenAPIObject) -> Option<ModelKind> {
+    let mut extends = Vec::new();
+    let mut required = schema.required.clone().unwrap_or_default();
+    let mut properties = BTreeMap::new();
+    if let Some(items) = schema.properties.as_ref() {
+        properties.extend(
+            items
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+    for member in &schema.all_of {
+        match member {
+            SchemaEnum::Ref(reference)
+                if is_interface_ref(&reference.get_type_name(), open_api) =>
+            {
+                extends.push(reference.get_type_name())
+            }
+            SchemaEnum::Object(object) => {
+                if let Some(items) = object.properties.as_ref() {
+                    properties.extend(
+                        items
+                            .iter()
+                            .map(|(key, value)| (key.clone(), value.clone())),
+                    );
+                }
+                required.extend(object.required.clone().unwrap_or_default());
+            }
+            _ => return None,
+        }
+    }
+    Some(ModelKind::Interface {
+        properties: object_properties(Some(&properties), &required, open_api),
+        extends,
+    })
+}
+
+/// Whether the named component renders as an interface and can therefore be extended:
+/// an object schema, or an `allOf` whose members are all interfaces themselves.
+fn is_interface_ref(name: &str, open_api: &OpenAPIObject) -> bool {
+    fn check(name: &str, open_api: &OpenAPIObject, visited: &mut Vec<String>) -> bool {
+        if visited.iter().any(|item| item == name) {
+            return false;
+        }
+        visited.push(name.to_string());
+        match get_schema_by_name(open_api, name) {
+            Some(SchemaEnum::Object(_)) => true,
+            Some(SchemaEnum::AllOf(schema)) => schema.all_of.iter().all(|member| match member {
+                SchemaEnum::Ref(reference) => check(&reference.get_type_name(), open_api, visited),
+                SchemaEnum::Object(_) => true,
+                _ => false,
+            }),
+            _ => false,
+        }
+    }
+    check(name, open_api, &mut Vec::new())
 }
 
 fn schema_to_model_type(schema: &SchemaEnum, open_api: &OpenAPIObject) -> ModelType {
@@ -152,6 +410,88 @@ fn schema_to_model_type(schema: &SchemaEnum, open_api: &OpenAPIObject) -> ModelT
         SchemaEnum::Array(v) => ModelType::Array {
             item: Box::new(schema_to_model_type(&v.items, open_api)),
         },
+        SchemaEnum::AllOf(v) => {
+            // Object members and sibling `properties` keep their fields, a bare
+            // `object` would drop them from the intersection
+            let mut variants = v
+                .all_of
+                .iter()
+                .map(|item| match item {
+                    SchemaEnum::Object(object) if object.properties.is_some() => {
+                        ModelType::InlineObject {
+                            properties: object_properties(
+                                object.properties.as_ref(),
+                                object.required.as_deref().unwrap_or_default(),
+                                open_api,
+                            ),
+                        }
+                    }
+                    _ => schema_to_model_type(item, open_api),
+                })
+                .collect::<Vec<_>>();
+            if v.properties.is_some() {
+                variants.push(ModelType::InlineObject {
+                    properties: object_properties(
+                        v.properties.as_ref(),
+                        v.required.as_deref().unwrap_or_default(),
+                        open_api,
+                    ),
+                });
+            }
+            compose_model_type(variants, |variants| ModelType::Intersection { variants })
+        }
+        SchemaEnum::OneOf(v) => union_to_model_type(&v.one_of, v.discriminator.as_ref(), open_api),
+        SchemaEnum::AnyOf(v) => union_to_model_type(&v.any_of, v.discriminator.as_ref(), open_api),
+        SchemaEnum::Not(_) | SchemaEnum::Any(_) => ModelType::Unknown,
+    }
+}
+
+/// oneOf/anyOf become a discriminated union when every member is a `$ref`
+/// (tags are derived from the mapping or the schema name), otherwise a plain union.
+fn union_to_model_type(
+    items: &[SchemaEnum],
+    discriminator: Option<&DiscriminatorObject>,
+    open_api: &OpenAPIObject,
+) -> ModelType {
+    if let Some(discriminator) = discriminator {
+        let variants = items
+            .iter()
+            .map(|item| match item {
+                SchemaEnum::Ref(reference) => {
+                    let name = reference.get_type_name();
+                    Some(ModelDiscriminatedVariant {
+                        tags: discriminator.get_tags(&name),
+                        r#type: ModelType::Ref { name },
+                    })
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        if let Some(variants) = variants.filter(|variants| !variants.is_empty()) {
+            return ModelType::Discriminated {
+                property_name: discriminator.property_name.clone(),
+                variants,
+            };
+        }
+    }
+    compose_model_type(
+        items
+            .iter()
+            .map(|item| schema_to_model_type(item, open_api))
+            .collect(),
+        |variants| ModelType::Union { variants },
+    )
+}
+
+/// A composition with a single member is just that member
+fn compose_model_type(
+    mut variants: Vec<ModelType>,
+    build: impl FnOnce(Vec<ModelType>) -> ModelType,
+) -> ModelType {
+    match variants.len() {
+        0 => ModelType::Unknown,
+        1 => variants.remove(0),
+        _ => build(variants),
     }
 }
 
@@ -183,7 +523,7 @@ fn string_literal_model_type(value: &str) -> ModelType {
     }
 }
 
-fn integer_literal_model_type(value: &i32) -> ModelType {
+fn integer_literal_model_type(value: &Number) -> ModelType {
     ModelType::Literal {
         value: ModelLiteral::Integer {
             value: value.to_string(),
@@ -191,7 +531,7 @@ fn integer_literal_model_type(value: &i32) -> ModelType {
     }
 }
 
-fn number_literal_model_type(value: &f32, format: NumberFormat) -> ModelType {
+fn number_literal_model_type(value: &Number, format: NumberFormat) -> ModelType {
     ModelType::Literal {
         value: ModelLiteral::Number {
             value: value.to_string(),
@@ -200,7 +540,7 @@ fn number_literal_model_type(value: &f32, format: NumberFormat) -> ModelType {
     }
 }
 
-fn integer_enum_members(values: &[i32]) -> Vec<ModelEnumMember> {
+fn integer_enum_members(values: &[Number]) -> Vec<ModelEnumMember> {
     values
         .iter()
         .enumerate()
@@ -214,7 +554,7 @@ fn integer_enum_members(values: &[i32]) -> Vec<ModelEnumMember> {
         .collect::<Vec<_>>()
 }
 
-fn number_enum_members(values: &[f32], format: Option<&str>) -> Vec<ModelEnumMember> {
+fn number_enum_members(values: &[Number], format: Option<&str>) -> Vec<ModelEnumMember> {
     let number_format = parse_number_format(format);
     values
         .iter()
@@ -253,7 +593,8 @@ mod tests {
     use std::str::FromStr;
 
     use crate::model_pipeline::{
-        IntegerFormat, IntegerSpec, ModelLiteral, ModelType, NumberFormat, NumberSpec, ScalarType,
+        IntegerFormat, IntegerSpec, ModelLiteral, ModelRenderStyle, ModelType, NumberFormat,
+        NumberSpec, ScalarType, generate_model_files,
     };
     use swagger_tk::model::OpenAPIObject;
 
@@ -361,6 +702,145 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn names_enum_members_from_extensions() {
+        let open_api = OpenAPIObject::from_str(
+            r#"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "enum-names-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Status": {
+        "type": "integer",
+        "enum": [0, 1],
+        "x-enumNames": ["Draft", "Published"],
+        "x-enum-descriptions": ["Not visible", "Visible to everyone"]
+      },
+      "Mismatched": { "type": "integer", "enum": [0, 1], "x-enum-varnames": ["Only"] }
+    }
+  }
+}
+"#,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let members = |name: &str| {
+            let model = ir.models.iter().find(|model| model.name == name).unwrap();
+            let ModelKind::Enum { members } = &model.kind else {
+                panic!("{name} should be an enum");
+            };
+            members
+                .iter()
+                .map(|member| (member.name.clone(), member.comment.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            members("Status"),
+            vec![
+                ("Draft".to_string(), Some("Not visible".to_string())),
+                (
+                    "Published".to_string(),
+                    Some("Visible to everyone".to_string())
+                ),
+            ]
+        );
+        assert_eq!(
+            members("Mismatched"),
+            vec![("Value1".to_string(), None), ("Value2".to_string(), None)]
+        );
+    }
+
+    #[test]
+    fn carries_property_defaults_and_examples() {
+        let open_api = OpenAPIObject::from_str(
+            r#"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "default-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Query": {
+        "type": "object",
+        "properties": {
+          "size": { "type": "integer", "default": 9007199254740993, "examples": [20] },
+          "filter": { "type": "object", "default": { "active": true } }
+        }
+      }
+    }
+  }
+}
+"#,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let ModelKind::Interface { properties, .. } = &ir.models[0].kind else {
+            panic!("Query should be an interface");
+        };
+        let size = properties.iter().find(|p| p.name == "size").unwrap();
+        assert_eq!(size.default, Some(serde_json::json!(9007199254740993u64)));
+        assert_eq!(size.example, Some(serde_json::json!(20)));
+        let filter = properties.iter().find(|p| p.name == "filter").unwrap();
+        assert_eq!(filter.default, Some(serde_json::json!({ "active": true })));
+    }
+
+    #[test]
+    fn keeps_int64_and_decimal_enum_values_exact() {
+        let open_api = OpenAPIObject::from_str(
+            r#"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "precision-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Flags": { "type": "integer", "format": "int64", "enum": [1, 4294967296, 9007199254740993, 18446744073709551615] },
+      "Prices": { "type": "number", "format": "decimal", "enum": [19.99, 0.1, 100, 100.0, 1.2345678901234568e16, 1.10, 12345678901234567890.123456789] }
+    }
+  }
+}
+"#,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let values = |name: &str| {
+            let model = ir.models.iter().find(|model| model.name == name).unwrap();
+            let ModelKind::Enum { members } = &model.kind else {
+                panic!("{name} should be an enum");
+            };
+            members
+                .iter()
+                .map(|member| match &member.value {
+                    ModelLiteral::Integer { value } | ModelLiteral::Number { value, .. } => {
+                        value.clone()
+                    }
+                    ModelLiteral::String { value } => panic!("unexpected string {value}"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            values("Flags"),
+            [
+                "1",
+                "4294967296",
+                "9007199254740993",
+                "18446744073709551615"
+            ]
+        );
+        let prices = values("Prices");
+        assert_eq!(prices[..4], ["19.99", "0.1", "100", "100.0"]);
+        // serde_json prints the exponent as `e16` or `e+16` depending on its version
+        assert_eq!(prices[4].parse::<f64>(), Ok(1.2345678901234568e16));
+        assert_eq!(prices[5..], ["1.10", "12345678901234567890.123456789"]);
+    }
+
     #[test]
     fn parses_numeric_enums_into_distinct_literal_kinds() {
         let open_api = OpenAPIObject::from_str(
@@ -462,7 +942,7 @@ mod tests {
             .iter()
             .find(|model| model.name == "Envelope")
             .expect("Envelope model should exist");
-        let ModelKind::Interface { properties } = &envelope.kind else {
+        let ModelKind::Interface { properties, .. } = &envelope.kind else {
             panic!("Envelope should be an interface");
         };
 
@@ -542,4 +1022,390 @@ mod tests {
                 )
         ));
     }
+
+    #[test]
+    fn parses_openapi_31_type_arrays_const_and_defs() {
+        let open_api = OpenAPIObject::from_str(
+            r##"
+{
+  "openapi": "3.1.0",
+  "jsonSchemaDialect": "https://spec.openapis.org/oas/3.1/dialect/base",
+  "info": { "title": "json-schema-2020-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Shape": {
+        "type": "object",
+        "required": ["kind"],
+        "properties": {
+          "kind": { "const": "circle" },
+          "label": { "type": ["string", "null"], "examples": ["a", "b"] },
+          "point": { "$ref": "#/$defs/Point" }
+        },
+        "$defs": {
+          "Point": { "type": "object", "properties": { "x": { "type": "number" } } }
+        }
+      }
+    }
+  }
+}
+"##,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        assert!(ir.models.iter().any(|model| model.name == "Point"));
+
+        let shape = ir
+            .models
+            .iter()
+            .find(|model| model.name == "Shape")
+            .expect("Shape model should exist");
+        let ModelKind::Interface { properties, .. } = &shape.kind else {
+            panic!("Shape should be an interface");
+        };
+
+        let label = properties
+            .iter()
+            .find(|property| property.name == "label")
+            .expect("label property should exist");
+        assert!(label.nullable);
+        assert!(matches!(
+            label.r#type,
+            ModelType::Scalar(ScalarType::String)
+        ));
+
+        let kind = properties
+            .iter()
+            .find(|property| property.name == "kind")
+            .expect("kind property should exist");
+        assert!(matches!(
+            &kind.r#type,
+            ModelType::Union { variants }
+                if matches!(
+                    variants.as_slice(),
+                    [ModelType::Literal { value: ModelLiteral::String { value } }] if value == "circle"
+                )
+        ));
+
+        let point = properties
+            .iter()
+            .find(|property| property.name == "point")
+            .expect("point property should exist");
+        assert!(matches!(&point.r#type, ModelType::Ref { name } if name == "Point"));
+    }
+
+    #[test]
+    fn parses_composition_schemas_into_extends_and_unions() {
+        let open_api = OpenAPIObject::from_str(
+            r##"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "composition-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Animal": {
+        "type": "object",
+        "required": ["name"],
+        "properties": { "name": { "type": "string" } }
+      },
+      "Dog": {
+        "allOf": [
+          { "$ref": "#/components/schemas/Animal" },
+          {
+            "type": "object",
+            "required": ["bark"],
+            "properties": { "bark": { "type": "boolean" } }
+          }
+        ]
+      },
+      "Pet": {
+        "oneOf": [
+          { "$ref": "#/components/schemas/Dog" },
+          { "$ref": "#/components/schemas/Animal" }
+        ]
+      },
+      "Owner": {
+        "type": "object",
+        "properties": {
+          "pet": {
+            "allOf": [{ "$ref": "#/components/schemas/Pet" }],
+            "nullable": true
+          },
+          "tag": { "type": ["string", "integer"] },
+          "anything": { "not": { "type": "string" } }
+        }
+      }
+    }
+  }
+}
+"##,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let find = |name: &str| {
+            ir.models
+                .iter()
+                .find(|model| model.name == name)
+                .unwrap_or_else(|| panic!("{name} model should exist"))
+        };
+
+        let ModelKind::Interface {
+            properties,
+            extends,
+        } = &find("Dog").kind
+        else {
+            panic!("Dog should be an interface");
+        };
+        assert_eq!(extends, &vec!["Animal".to_string()]);
+        assert!(
+            properties
+                .iter()
+                .any(|property| property.name == "bark" && property.required)
+        );
+
+        assert!(matches!(
+            &find("Pet").kind,
+            ModelKind::Alias {
+                target: ModelType::Union { variants },
+                ..
+            } if variants.len() == 2
+        ));
+
+        let ModelKind::Interface { properties, .. } = &find("Owner").kind else {
+            panic!("Owner should be an interface");
+        };
+        let pet = properties
+            .iter()
+            .find(|property| property.name == "pet")
+            .expect("pet property should exist");
+        assert!(pet.nullable);
+        assert!(matches!(&pet.r#type, ModelType::Ref { name } if name == "Pet"));
+
+        let tag = properties
+            .iter()
+            .find(|property| property.name == "tag")
+            .expect("tag property should exist");
+        assert!(matches!(&tag.r#type, ModelType::Union { variants } if variants.len() == 2));
+
+        let anything = properties
+            .iter()
+            .find(|property| property.name == "anything")
+            .expect("anything property should exist");
+        assert!(matches!(anything.r#type, ModelType::Unknown));
+    }
+
+    #[test]
+    fn keeps_all_of_refs_to_non_interfaces_out_of_extends() {
+        let open_api = OpenAPIObject::from_str(
+            r##"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "all-of-ref-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Status": { "type": "string", "enum": ["active", "disabled"] },
+      "Ids": { "type": "array", "items": { "type": "string" } },
+      "Base": { "type": "object", "properties": { "id": { "type": "string" } } },
+      "Named": {
+        "allOf": [
+          { "$ref": "#/components/schemas/Base" },
+          { "type": "object", "properties": { "name": { "type": "string" } } }
+        ]
+      },
+      "Pet": { "allOf": [{ "$ref": "#/components/schemas/Status" }] },
+      "Batch": {
+        "allOf": [
+          { "$ref": "#/components/schemas/Ids" },
+          { "type": "object", "properties": { "size": { "type": "integer" } } }
+        ]
+      },
+      "Tagged": { "allOf": [{ "$ref": "#/components/schemas/Named" }] },
+      "Counted": {
+        "allOf": [{ "$ref": "#/components/schemas/Ids" }],
+        "required": ["count"],
+        "properties": { "count": { "type": "integer" } }
+      }
+    }
+  }
+}
+"##,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let find = |name: &str| {
+            ir.models
+                .iter()
+                .find(|model| model.name == name)
+                .unwrap_or_else(|| panic!("{name} model should exist"))
+        };
+
+        assert!(matches!(
+            &find("Pet").kind,
+            ModelKind::Alias { target: ModelType::Ref { name }, .. } if name == "Status"
+        ));
+        assert!(matches!(
+            &find("Batch").kind,
+            ModelKind::Alias { target: ModelType::Intersection { variants }, .. }
+                if matches!(&variants[0], ModelType::Ref { name } if name == "Ids")
+                    && matches!(&variants[1], ModelType::InlineObject { properties }
+                        if properties[0].name == "size")
+        ));
+        // Sibling properties of an allOf stay on the intersection
+        assert!(matches!(
+            &find("Counted").kind,
+            ModelKind::Alias { target: ModelType::Intersection { variants }, .. }
+                if matches!(&variants[1], ModelType::InlineObject { properties }
+                    if properties[0].name == "count" && properties[0].required)
+        ));
+        let files = generate_model_files(
+            &open_api,
+            ModelRenderStyle::Module,
+            &["Counted".to_string()],
+        )
+        .expect("render model files fail");
+        assert!(files["Counted.ts"].contains("import(\"./Ids\").Ids & { count: number }"));
+        // An allOf interface can still be extended
+        assert!(matches!(
+            &find("Tagged").kind,
+            ModelKind::Interface { extends, .. } if extends == &vec!["Named".to_string()]
+        ));
+    }
+
+    #[test]
+    fn parses_discriminator_into_tagged_union_and_literal_tags() {
+        let open_api = OpenAPIObject::from_str(
+            r##"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "discriminator-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Event": {
+        "type": "object",
+        "required": ["eventType"],
+        "properties": { "eventType": { "type": "string" } },
+        "discriminator": { "propertyName": "eventType" }
+      },
+      "Created": {
+        "allOf": [
+          { "$ref": "#/components/schemas/Event" },
+          { "type": "object", "properties": { "id": { "type": "string" } } }
+        ]
+      },
+      "Notification": {
+        "oneOf": [
+          { "$ref": "#/components/schemas/Mail" },
+          { "$ref": "#/components/schemas/Sms" }
+        ],
+        "discriminator": {
+          "propertyName": "channel",
+          "mapping": { "mail": "#/components/schemas/Mail", "text": "Sms" }
+        }
+      },
+      "Mail": {
+        "type": "object",
+        "properties": { "channel": { "type": "string" }, "subject": { "type": "string" } }
+      },
+      "Sms": {
+        "type": "object",
+        "properties": { "phone": { "type": "string" } }
+      }
+    }
+  }
+}
+"##,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let find = |name: &str| {
+            ir.models
+                .iter()
+                .find(|model| model.name == name)
+                .unwrap_or_else(|| panic!("{name} model should exist"))
+        };
+        let tag_of = |model: &str, property_name: &str| -> Option<String> {
+            let ModelKind::Interface { properties, .. } = &find(model).kind else {
+                return None;
+            };
+            properties
+                .iter()
+                .find(|property| property.name == property_name && property.required)
+                .and_then(|property| match &property.r#type {
+                    ModelType::Literal {
+                        value: ModelLiteral::String { value },
+                    } => Some(value.clone()),
+                    _ => None,
+                })
+        };
+
+        assert!(matches!(
+            &find("Notification").kind,
+            ModelKind::Alias {
+                target: ModelType::Discriminated { property_name, variants },
+                ..
+            } if property_name == "channel"
+                && variants.len() == 2
+                && variants[1].tags == vec!["text".to_string()]
+        ));
+        assert_eq!(tag_of("Mail", "channel").as_deref(), Some("mail"));
+        // The tag property is added when the variant doesn't declare it
+        assert_eq!(tag_of("Sms", "channel").as_deref(), Some("text"));
+        // Base discriminator without mapping uses the child schema name
+        assert_eq!(tag_of("Created", "eventType").as_deref(), Some("Created"));
+        assert_eq!(tag_of("Event", "eventType"), None);
+    }
+
+    #[test]
+    fn tags_mapped_schemas_of_one_of_discriminator_with_inline_members() {
+        let open_api = OpenAPIObject::from_str(
+            r##"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "discriminator-test", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Contact": {
+        "oneOf": [
+          { "$ref": "#/components/schemas/Fax" },
+          { "type": "object", "properties": { "kind": { "type": "string" } } }
+        ],
+        "discriminator": {
+          "propertyName": "kind",
+          "mapping": { "fax": "#/components/schemas/Fax" }
+        }
+      },
+      "Fax": {
+        "type": "object",
+        "properties": { "kind": { "type": "string" }, "number": { "type": "string" } }
+      }
+    }
+  }
+}
+"##,
+        )
+        .expect("parse openapi object fail");
+
+        let ir = build_model_ir(&open_api).expect("build model ir fail");
+        let fax = ir.models.iter().find(|model| model.name == "Fax").unwrap();
+        let ModelKind::Interface { properties, .. } = &fax.kind else {
+            panic!("Fax should be an interface");
+        };
+        let kind = properties
+            .iter()
+            .find(|property| property.name == "kind")
+            .unwrap();
+        assert!(kind.required);
+        assert!(matches!(
+            &kind.r#type,
+            ModelType::Literal { value: ModelLiteral::String { value } } if value == "fax"
+        ));
+    }
 }
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 
 use crate::utils::format_ts_code;
 
-use super::model::{ModelIr, ModelKind, ModelLiteral, ModelRenderStyle, ModelType, ScalarType};
+use super::model::{
+    ModelIr, ModelKind, ModelLiteral, ModelProperty, ModelRenderStyle, ModelType, ScalarType,
+};
 
 pub fn render_model_files(
     ir: &ModelIr,
@@ -29,33 +31,43 @@ pub fn render_model_files(
         }
 
         let source = match &model.kind {
-            ModelKind::Interface { properties } => {
-                let rows = properties
-                    .iter()
-                    .map(|property| {
-                        let description = property
-                            .description
-                            .as_ref()
-                            .map(|text| format!("\n/** {text} */\n"))
-                            .unwrap_or_default();
-                        let optional_symbol = if property.required { ":" } else { "?:" };
-                        let ts_type =
-                            render_type(&property.r#type, style, &model.name, property.nullable);
-                        format!("{description}{}{optional_symbol}{ts_type}", property.name)
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
+            ModelKind::Interface {
+                properties,
+                extends,
+            } => {
+                let rows = render_properties(properties, &model.name);
                 let description = model
                     .description
                     .as_ref()
                     .map(|text| format!("/** {text} */\n"))
                     .unwrap_or_default();
-                match style {
-                    ModelRenderStyle::Declaration => {
-                        format!("{description}declare interface {} {{{rows}}}", model.name)
+                if extends.is_empty() {
+                    match style {
+                        ModelRenderStyle::Declaration => {
+                            format!("{description}declare interface {} {{{rows}}}", model.name)
+                        }
+                        ModelRenderStyle::Module => {
+                            format!("{description}export interface {} {{{rows}}}", model.name)
+                        }
                     }
-                    ModelRenderStyle::Module => {
-                        format!("{description}export interface {} {{{rows}}}", model.name)
+                } else {
+                    // `extends` can't reference `import("...")` types, so inheritance is an intersection
+                    let parents = extends
+                        .iter()
+                        .map(|name| {
+                            render_type(&ModelType::Ref { name: name.clone() }, &model.name, false)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" & ");
+                    match style {
+                        ModelRenderStyle::Declaration => format!(
+                            "{description}declare type {} = {parents} & {{{rows}}}",
+                            model.name
+                        ),
+                        ModelRenderStyle::Module => format!(
+                            "{description}export type {} = {parents} & {{{rows}}}",
+                            model.name
+                        ),
                     }
                 }
             }
@@ -89,7 +101,7 @@ pub fn render_model_files(
                 )
             }
             ModelKind::Alias { target, nullable } => {
-                let ts_type = render_type(target, style, &model.name, *nullable);
+                let ts_type = render_type(target, &model.name, *nullable);
                 let description = model
                     .description
                     .as_ref()
@@ -118,12 +130,24 @@ pub fn render_model_files(
     Ok(files)
 }
 
-fn render_type(
-    model_type: &ModelType,
-    style: ModelRenderStyle,
-    current_model_name: &str,
-    nullable: bool,
-) -> String {
+fn render_properties(properties: &[ModelProperty], current_model_name: &str) -> String {
+    properties
+        .iter()
+        .map(|property| {
+            let description = property
+                .description
+                .as_ref()
+                .map(|text| format!("\n/** {text} */\n"))
+                .unwrap_or_default();
+            let optional_symbol = if property.required { ":" } else { "?:" };
+            let ts_type = render_type(&property.r#type, current_model_name, property.nullable);
+            format!("{description}{}{optional_symbol}{ts_type}", property.name)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_type(model_type: &ModelType, current_model_name: &str, nullable: bool) -> String {
     let base = match model_type {
         ModelType::Scalar(ScalarType::String) => "string".to_string(),
         ModelType::Scalar(ScalarType::Boolean) => "boolean".to_string(),
@@ -133,6 +157,9 @@ fn render_type(
         ModelType::Number => "number".to_string(),
         ModelType::Boolean => "boolean".to_string(),
         ModelType::Object => "object".to_string(),
+        ModelType::InlineObject { properties } => {
+            format!("{{{}}}", render_properties(properties, current_model_name))
+        }
         ModelType::Ref { name } => {
             // Both Module and Declaration styles need import for cross-references
             if name != current_model_name {
@@ -143,14 +170,24 @@ fn render_type(
             }
         }
         ModelType::Array { item } => {
-            let child = render_type(item, style, current_model_name, false);
+            let child = render_type(item, current_model_name, false);
             format!("Array<{child}>")
         }
         ModelType::Union { variants } => variants
             .iter()
-            .map(|item| render_type(item, style, current_model_name, false))
+            .map(|item| render_type(item, current_model_name, false))
             .collect::<Vec<_>>()
             .join(" | "),
+        ModelType::Intersection { variants } => variants
+            .iter()
+            .map(|item| match item {
+                ModelType::Union { .. } | ModelType::Discriminated { .. } => {
+                    format!("({})", render_type(item, current_model_name, false))
+                }
+                _ => render_type(item, current_model_name, false),
+            })
+            .collect::<Vec<_>>()
+            .join(" & "),
         ModelType::Literal { value } => match value {
             ModelLiteral::String { value } => {
                 serde_json::to_string(value).unwrap_or_else(|_| format!("\"{value}\""))
@@ -158,6 +195,13 @@ fn render_type(
             ModelLiteral::Integer { value } => value.to_string(),
             ModelLiteral::Number { value, .. } => value.to_string(),
         },
+        // Variant models carry their literal tag property, so a plain union discriminates
+        ModelType::Discriminated { variants, .. } => variants
+            .iter()
+            .map(|variant| render_type(&variant.r#type, current_model_name, false))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        ModelType::Unknown => "unknown".to_string(),
     };
 
     if nullable {
@@ -170,7 +214,8 @@ fn render_type(
 #[cfg(test)]
 mod tests {
     use super::super::model::{
-        IntegerSpec, ModelEnumMember, ModelNode, ModelProperty, NumberFormat, NumberSpec,
+        IntegerSpec, ModelDiscriminatedVariant, ModelEnumMember, ModelNode, ModelProperty,
+        NumberFormat, NumberSpec,
     };
     use super::*;
 
@@ -197,6 +242,7 @@ mod tests {
             name: "Sample".to_string(),
             description: None,
             kind: ModelKind::Interface {
+                extends: Vec::new(),
                 properties: vec![
                     ModelProperty {
                         name: "intValue".to_string(),
@@ -206,6 +252,8 @@ mod tests {
                         r#type: ModelType::Scalar(ScalarType::Integer(IntegerSpec {
                             format: Default::default(),
                         })),
+                        default: None,
+                        example: None,
                     },
                     ModelProperty {
                         name: "numValue".to_string(),
@@ -215,6 +263,8 @@ mod tests {
                         r#type: ModelType::Scalar(ScalarType::Number(NumberSpec {
                             format: Default::default(),
                         })),
+                        default: None,
+                        example: None,
                     },
                 ],
             },
@@ -232,6 +282,7 @@ mod tests {
             name: "ScalarKinds".to_string(),
             description: None,
             kind: ModelKind::Interface {
+                extends: Vec::new(),
                 properties: vec![
                     ModelProperty {
                         name: "textValue".to_string(),
@@ -239,6 +290,8 @@ mod tests {
                         required: true,
                         nullable: false,
                         r#type: ModelType::Scalar(ScalarType::String),
+                        default: None,
+                        example: None,
                     },
                     ModelProperty {
                         name: "flagValue".to_string(),
@@ -246,6 +299,8 @@ mod tests {
                         required: true,
                         nullable: false,
                         r#type: ModelType::Scalar(ScalarType::Boolean),
+                        default: None,
+                        example: None,
                     },
                 ],
             },
@@ -284,4 +339,75 @@ mod tests {
         assert!(rendered.contains("IntLiteral = 42"));
         assert!(rendered.contains("NumLiteral = 3.14"));
     }
+
+    #[test]
+    fn renders_extends_as_intersection_with_parent_import() {
+        let rendered = render_single_model(ModelNode {
+            name: "Dog".to_string(),
+            description: None,
+            kind: ModelKind::Interface {
+                extends: vec!["Animal".to_string()],
+                properties: vec![ModelProperty {
+                    name: "bark".to_string(),
+                    description: None,
+                    required: true,
+                    nullable: false,
+                    r#type: ModelType::Intersection {
+                        variants: vec![
+                            ModelType::Ref {
+                                name: "Sound".to_string(),
+                            },
+                            ModelType::Union {
+                                variants: vec![ModelType::String, ModelType::Unknown],
+                            },
+                        ],
+                    },
+                    default: None,
+                    example: None,
+                }],
+            },
+        });
+
+        assert!(rendered.contains("export type Dog = import(\"./Animal\").Animal & {"));
+        assert!(rendered.contains("import(\"./Sound\").Sound & (string | unknown)"));
+    }
+
+    #[test]
+    fn parenthesizes_discriminated_unions_inside_intersections() {
+        let variant = |name: &str| ModelDiscriminatedVariant {
+            tags: vec![name.to_lowercase()],
+            r#type: ModelType::Ref {
+                name: name.to_string(),
+            },
+        };
+        let rendered = render_single_model(ModelNode {
+            name: "Event".to_string(),
+            description: None,
+            kind: ModelKind::Alias {
+                target: ModelType::Intersection {
+                    variants: vec![
+                        ModelType::Discriminated {
+                            property_name: "kind".to_string(),
+                            variants: vec![variant("Click"), variant("Scroll")],
+                        },
+                        ModelType::InlineObject {
+                            properties: vec![ModelProperty {
+                                name: "at".to_string(),
+                                description: None,
+                                required: true,
+                                nullable: false,
+                                r#type: ModelType::String,
+                                default: None,
+                                example: None,
+                            }],
+                        },
+                    ],
+                },
+                nullable: false,
+            },
+        });
+
+        assert!(rendered.contains("(import(\"./Click\").Click | import(\"./Scroll\").Scroll) & {"));
+        assert!(rendered.contains("at: string"));
+    }
 }
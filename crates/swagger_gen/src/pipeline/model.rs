@@ -9,6 +9,9 @@ pub struct GeneratorInput {
     pub client_import: Option<ClientImportConfig>,
     /// Output root directory for generated files (used for calculating relative import paths)
     pub output_root: Option<String>,
+    /// Requests the API sends to the client: top-level `webhooks` and operation `callbacks`.
+    #[serde(default)]
+    pub webhooks: Vec<WebhookItem>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,7 +39,7 @@ pub struct ProjectContext {
     pub retry_ownership: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EndpointItem {
     pub namespace: Vec<String>,
     pub operation_name: String,
@@ -46,20 +49,191 @@ pub struct EndpointItem {
     pub method: String,
     pub path: String,
     pub input_type_name: String,
+    /// Union of the distinct 2xx response body types, `void` when none has a body.
     pub output_type_name: String,
+    /// Every declared success response (`2xx` codes and `2XX` ranges) in status order.
+    #[serde(default)]
+    pub success_responses: Vec<EndpointResponse>,
+    /// Declared error responses (`4xx`/`5xx` codes and ranges, then `default`).
+    #[serde(default)]
+    pub error_responses: Vec<EndpointResponse>,
+    /// The endpoint downloads a file: every success response with a body is
+    /// binary (`application/octet-stream`, `application/pdf`, `format: binary`, ...)
+    /// and `output_type_name` is `Blob`.
+    #[serde(default)]
+    pub binary_response: bool,
     pub request_body_field: Option<String>,
+    /// Media type the request body is sent as (JSON when declared, otherwise
+    /// the first one). `None` when the endpoint has no body.
+    #[serde(default)]
+    pub request_content_type: Option<String>,
+    /// Every media type declared for the request body.
+    #[serde(default)]
+    pub request_content_types: Vec<String>,
     #[serde(default)]
     pub query_params: Vec<EndpointParameter>,
     pub query_fields: Vec<String>,
     #[serde(default)]
     pub path_params: Vec<EndpointParameter>,
     pub path_fields: Vec<String>,
+    /// Parameters sent as request headers (`in: header`).
+    #[serde(default)]
+    pub header_params: Vec<EndpointParameter>,
+    /// Parameters sent in the `Cookie` header (`in: cookie`).
+    #[serde(default)]
+    pub cookie_params: Vec<EndpointParameter>,
     pub has_request_options: bool,
     pub deprecated: bool,
     /// Meta fields for endpoint configuration.
     /// Used for both internal classification (keys starting with "__") and
     /// for generating RequestSpec meta fields (keys not starting with "__").
     pub meta: IndexMap<String, String>,
+    /// Effective security requirements (operation-level overrides global).
+    /// `None` means the spec declares nothing; an empty list means the
+    /// endpoint explicitly opts out of authentication (`security: []`).
+    /// Each entry is one alternative whose schemes must all be satisfied.
+    #[serde(default)]
+    pub security: Option<Vec<EndpointSecurityRequirement>>,
+    /// Servers that override the client's base URL for this endpoint
+    /// (operation-level `servers`, then path-item `servers`). Empty when the
+    /// endpoint uses the document-level servers.
+    #[serde(default)]
+    pub servers: Vec<EndpointServer>,
+    /// Example request body from the spec (`example`, named `examples` or schema example).
+    #[serde(default)]
+    pub request_example: Option<serde_json::Value>,
+    /// Example body of the response the generated function returns.
+    #[serde(default)]
+    pub response_example: Option<serde_json::Value>,
+}
+
+impl EndpointItem {
+    /// Whether the spec explicitly marks this endpoint as not requiring authentication.
+    pub fn is_auth_optional(&self) -> bool {
+        self.security.as_ref().is_some_and(|requirements| {
+            requirements.is_empty()
+                || requirements
+                    .iter()
+                    .any(|requirement| requirement.schemes.is_empty())
+        })
+    }
+
+    /// How the request body has to be encoded, based on `request_content_type`.
+    pub fn request_body_kind(&self) -> RequestBodyKind {
+        self.request_content_type
+            .as_deref()
+            .map(RequestBodyKind::from_media_type)
+            .unwrap_or(RequestBodyKind::Json)
+    }
+
+    /// `Content-Type` header the request declares for its body. `None` for JSON,
+    /// the client default, and for multipart, whose boundary the encoder sets.
+    pub fn request_content_type_header(&self) -> Option<&str> {
+        self.request_body_field.as_ref()?;
+        match self.request_body_kind() {
+            RequestBodyKind::Json | RequestBodyKind::Multipart => None,
+            _ => self.request_content_type.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestBodyKind {
+    /// `application/json`, `*+json` and wildcards.
+    Json,
+    /// `multipart/*`, sent as form data with files.
+    Multipart,
+    /// `application/x-www-form-urlencoded`.
+    FormUrlEncoded,
+    /// `text/*` and XML, sent as a string.
+    Text,
+    /// Any other media type (`application/octet-stream`, `image/png`, ...), sent as raw bytes.
+    Binary,
+}
+
+impl RequestBodyKind {
+    pub fn from_media_type(media_type: &str) -> Self {
+        let essence = media_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match essence.as_str() {
+            "application/json" | "*/*" | "application/*" | "" => Self::Json,
+            "application/x-www-form-urlencoded" => Self::FormUrlEncoded,
+            "application/xml" => Self::Text,
+            _ if essence.ends_with("+json") => Self::Json,
+            _ if essence.ends_with("+xml") || essence.starts_with("text/") => Self::Text,
+            _ if essence.starts_with("multipart/") => Self::Multipart,
+            _ => Self::Binary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookKind {
+    /// Declared under the document-level `webhooks` map.
+    Webhook,
+    /// Declared under an operation's `callbacks` map.
+    Callback,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookItem {
+    pub kind: WebhookKind,
+    /// Unique dispatch key: the webhook name, or `{sourceOperation}.{callbackName}`
+    /// for callbacks. A method suffix is added when one key has several operations.
+    pub event: String,
+    pub namespace: Vec<String>,
+    /// camelCase identifier used to name the generated handler type.
+    pub operation_name: String,
+    pub summary: Option<String>,
+    pub method: String,
+    /// Webhook name, or the callback runtime expression (e.g. `{$request.body#/callbackUrl}`).
+    pub path: String,
+    /// Type of the request body the API sends, `void` when there is none.
+    pub payload_type_name: String,
+    /// Type of the response the handler is expected to return.
+    pub response_type_name: String,
+    /// Export name of the endpoint that registers the callback (`None` for webhooks).
+    #[serde(default)]
+    pub source_operation: Option<String>,
+    pub deprecated: bool,
+    #[serde(default)]
+    pub payload_example: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointSecurityRequirement {
+    pub schemes: Vec<EndpointSecurityScheme>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointSecurityScheme {
+    pub name: String,
+    /// Scheme type from `components.securitySchemes` (`apiKey`, `http`, `oauth2`, ...),
+    /// `None` when the scheme is not declared.
+    pub scheme_type: Option<String>,
+    /// HTTP auth scheme such as `bearer` or `basic` (only for `http` schemes).
+    pub http_scheme: Option<String>,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointResponse {
+    /// Status code as declared, e.g. `201`, `4XX` or `default`.
+    pub status_code: String,
+    /// Body type, `void` for responses without content (e.g. `204`).
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointServer {
+    /// Server URL with variables replaced by their default values.
+    pub url: String,
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +241,11 @@ pub struct EndpointParameter {
     pub name: String,
     pub type_name: String,
     pub required: bool,
+    /// Schema `default` of the parameter as raw JSON.
+    #[serde(default)]
+    pub default: Option<serde_json::Value>,
+    #[serde(default)]
+    pub example: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +265,8 @@ pub struct RendererExecution {
 pub struct ExecutionPlan {
     pub endpoint_count: usize,
     pub transform_steps: Vec<String>,
+    /// Spec-level warnings from parsing, shared by every renderer
+    pub parse_warnings: Vec<String>,
     pub renderer_reports: Vec<RendererExecution>,
     pub planned_files: Vec<PlannedFile>,
     pub skipped_files: usize,
@@ -1,5 +1,8 @@
 use std::time::Instant;
-use swagger_tk::model::OpenAPIObject;
+use swagger_tk::{
+    filter::{SpecFilter, filter_open_api},
+    model::OpenAPIObject,
+};
 
 use super::{
     layout::{IdentityLayout, LayoutStrategy},
@@ -7,7 +10,7 @@ use super::{
         ClientImportConfig, ExecutionMetrics, ExecutionPlan, GeneratorInput, ModelImportConfig,
         RendererExecution,
     },
-    parser::{OpenApiParser, Parser},
+    parser::{OpenApiParser, Parser, collect_parse_warnings},
     renderer::{NoopRenderer, Renderer},
     transform::{DefaultQueryMutationPass, NormalizeEndpointPass, TransformPass},
     writer::{DryRunWriter, Writer},
@@ -23,6 +26,8 @@ pub struct CodegenPipeline {
     model_import: Option<ModelImportConfig>,
     /// Output root directory (used for calculating relative import paths)
     output_root: Option<String>,
+    /// Endpoint filter applied to the spec before parsing
+    filter: Option<SpecFilter>,
 }
 
 impl Default for CodegenPipeline {
@@ -39,6 +44,7 @@ impl Default for CodegenPipeline {
             client_import: None,
             model_import: None,
             output_root: None,
+            filter: None,
         }
     }
 }
@@ -79,6 +85,13 @@ impl CodegenPipeline {
         self
     }
 
+    /// Only generate the endpoints matched by `filter`.
+    /// Components no longer referenced by the remaining endpoints are pruned.
+    pub fn with_filter(mut self, filter: Option<SpecFilter>) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Add a custom transform pass to the pipeline.
     /// Transform passes are applied in order, so later passes can override
     /// the results of earlier passes.
@@ -90,13 +103,25 @@ impl CodegenPipeline {
 
 impl CodegenPipeline {
     pub fn parse(&self, open_api: &OpenAPIObject) -> Result<GeneratorInput, String> {
-        self.parser.parse(open_api)
+        let filtered = self.apply_filter(open_api)?;
+        self.parser.parse(filtered.as_ref().unwrap_or(open_api))
+    }
+
+    /// Returns the filtered spec, or `None` when no filter is configured.
+    fn apply_filter(&self, open_api: &OpenAPIObject) -> Result<Option<OpenAPIObject>, String> {
+        match &self.filter {
+            Some(filter) if !filter.is_empty() => filter_open_api(open_api, filter).map(Some),
+            _ => Ok(None),
+        }
     }
 
     pub fn plan(&self, open_api: &OpenAPIObject) -> Result<ExecutionPlan, String> {
         let total_start = Instant::now();
         let parse_start = Instant::now();
+        let filtered = self.apply_filter(open_api)?;
+        let open_api = filtered.as_ref().unwrap_or(open_api);
         let mut input = self.parser.parse(open_api)?;
+        let parse_warnings = collect_parse_warnings(open_api);
         let parse_ms = parse_start.elapsed().as_millis();
 
         // Apply client_import configuration
@@ -147,6 +172,7 @@ impl CodegenPipeline {
         Ok(ExecutionPlan {
             endpoint_count: input.endpoints.len(),
             transform_steps,
+            parse_warnings,
             renderer_reports,
             planned_files: write_plan.files_to_write,
             skipped_files: write_plan.skipped_files,
@@ -162,7 +188,7 @@ impl CodegenPipeline {
     }
 
     pub fn ir_snapshot_json(&self, open_api: &OpenAPIObject) -> Result<String, String> {
-        let mut input = self.parser.parse(open_api)?;
+        let mut input = self.parse(open_api)?;
         for pass in &self.transforms {
             pass.apply(&mut input)?;
         }
@@ -2,12 +2,19 @@ use indexmap::IndexMap;
 use inflector::cases::{
     camelcase::to_camel_case, kebabcase::to_kebab_case, pascalcase::to_pascal_case,
 };
-use std::collections::HashSet;
-use swagger_tk::model::{OpenAPIObject, OperationObject, ParameterObjectIn, PathItemObject};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use swagger_tk::{
+    getter::{get_operation_security, get_security_scheme, get_untyped_schemas},
+    model::{OpenAPIObject, OperationObject, ParameterObjectIn, PathItemObject},
+};
 
-use crate::core::{ApiContext, FuncParameter};
+use crate::core::{ApiContext, FuncParameter, StatusResponse};
 
-use super::model::{EndpointItem, EndpointParameter, GeneratorInput, ProjectContext};
+use super::model::{
+    EndpointItem, EndpointParameter, EndpointResponse, EndpointSecurityRequirement,
+    EndpointSecurityScheme, EndpointServer, GeneratorInput, ProjectContext, RequestBodyKind,
+    WebhookItem, WebhookKind,
+};
 
 pub trait Parser {
     fn parse(&self, open_api: &OpenAPIObject) -> Result<GeneratorInput, String>;
@@ -19,10 +26,14 @@ pub struct OpenApiParser;
 impl Parser for OpenApiParser {
     fn parse(&self, open_api: &OpenAPIObject) -> Result<GeneratorInput, String> {
         let mut endpoints = Vec::new();
-        let paths = open_api
-            .paths
-            .as_ref()
-            .ok_or_else(|| "paths not found".to_string())?;
+        let mut operations = Vec::new();
+        let empty_paths = BTreeMap::new();
+        let paths = match (open_api.paths.as_ref(), open_api.webhooks.as_ref()) {
+            (Some(paths), _) => paths,
+            // OpenAPI 3.1 documents may declare webhooks only
+            (None, Some(_)) => &empty_paths,
+            (None, None) => return Err("paths not found".to_string()),
+        };
         let mut path_keys = paths.keys().collect::<Vec<_>>();
         path_keys.sort();
 
@@ -30,13 +41,20 @@ impl Parser for OpenApiParser {
             let path_item = paths
                 .get(path)
                 .ok_or_else(|| format!("can't find path data: {path}"))?;
-            for (method, operation) in collect_operations(path_item) {
-                let endpoint = build_endpoint(path, method, path_item, operation);
+            for (method, operation) in path_item.get_operations() {
+                let endpoint = build_endpoint(path, method, path_item, operation, open_api);
                 endpoints.push(endpoint);
+                operations.push(operation);
             }
         }
         apply_endpoint_names(&mut endpoints);
 
+        let mut webhooks = collect_webhooks(open_api);
+        for (endpoint, operation) in endpoints.iter().zip(operations) {
+            webhooks.extend(collect_callbacks(endpoint, operation, open_api));
+        }
+        apply_webhook_names(&mut webhooks);
+
         Ok(GeneratorInput {
             project: ProjectContext {
                 package_name: open_api
@@ -52,28 +70,22 @@ impl Parser for OpenApiParser {
             model_import: None,  // Will be set by configuration later
             client_import: None, // Will be set by configuration later
             output_root: None,   // Will be set by pipeline later
+            webhooks,
         })
     }
 }
 
-fn collect_operations(path_item: &PathItemObject) -> Vec<(&'static str, &OperationObject)> {
-    let mut result = Vec::new();
-    if let Some(op) = path_item.get.as_ref() {
-        result.push(("GET", op));
-    }
-    if let Some(op) = path_item.post.as_ref() {
-        result.push(("POST", op));
-    }
-    if let Some(op) = path_item.put.as_ref() {
-        result.push(("PUT", op));
-    }
-    if let Some(op) = path_item.patch.as_ref() {
-        result.push(("PATCH", op));
-    }
-    if let Some(op) = path_item.delete.as_ref() {
-        result.push(("DELETE", op));
-    }
-    result
+/// Collect non-fatal issues found while reading the spec, e.g. schemas whose
+/// type could not be inferred and are rendered as `unknown`.
+pub fn collect_parse_warnings(open_api: &OpenAPIObject) -> Vec<String> {
+    get_untyped_schemas(open_api)
+        .into_iter()
+        .map(|pointer| {
+            format!(
+                "schema `{pointer}` has no usable `type` and none could be inferred; treated as any"
+            )
+        })
+        .collect()
 }
 
 fn build_endpoint(
@@ -81,26 +93,34 @@ fn build_endpoint(
     method: &str,
     path_item: &PathItemObject,
     operation: &OperationObject,
+    open_api: &OpenAPIObject,
 ) -> EndpointItem {
-    let method_lower = method.to_lowercase();
-    let context = ApiContext::new(path, &method_lower, path_item, operation);
+    let context = ApiContext::new(path, method, path_item, operation, open_api);
     let query_params = collect_endpoint_params(&context, ParameterObjectIn::Query);
     let path_params = collect_endpoint_params(&context, ParameterObjectIn::Path);
-    let namespace = operation
-        .tags
-        .as_ref()
-        .and_then(|tags| tags.first())
-        .map(|tag| {
-            tag.split('/')
-                .filter(|item| !item.trim().is_empty())
-                .map(to_kebab_case)
-                .collect::<Vec<_>>()
-        })
-        .filter(|list| !list.is_empty())
-        .unwrap_or_else(|| vec!["default".to_string()]);
+    let header_params = collect_endpoint_params(&context, ParameterObjectIn::Header);
+    let cookie_params = collect_endpoint_params(&context, ParameterObjectIn::Cookie);
+    let namespace =
+        get_operation_namespace(operation).unwrap_or_else(|| vec!["default".to_string()]);
 
-    let input_type_name = build_input_type_name(&context);
+    let request_body = operation
+        .request_body
+        .as_ref()
+        .and_then(|body| body.resolve(open_api));
+    let request_content_type = request_body
+        .and_then(|body| body.get_preferred_content())
+        .map(|(media_type, _)| media_type.to_string());
+    let request_content_types = request_body
+        .map(|body| body.content.keys().cloned().collect())
+        .unwrap_or_default();
+
+    let input_type_name = adapt_body_type(
+        build_input_type_name(&context),
+        request_content_type.as_deref(),
+    );
+    let request_example = collect_request_example(&context);
     let operation_name = derive_operation_name(&context.func_name, method, &namespace);
+    let success_responses = to_endpoint_responses(context.success_responses);
 
     EndpointItem {
         namespace,
@@ -108,21 +128,270 @@ fn build_endpoint(
         export_name: String::new(),
         builder_name: String::new(),
         summary: operation.summary.clone(),
-        method: method.to_string(),
+        method: method.to_uppercase(),
         path: path.to_string(),
         input_type_name,
         output_type_name: context.response_type.unwrap_or_else(|| "void".to_string()),
+        binary_response: is_binary_response(&success_responses),
+        success_responses,
+        error_responses: to_endpoint_responses(context.error_responses),
         request_body_field: context.request_body_name,
+        request_content_type,
+        request_content_types,
         query_fields: query_params.iter().map(|item| item.name.clone()).collect(),
         query_params,
         path_fields: path_params.iter().map(|item| item.name.clone()).collect(),
         path_params,
+        header_params,
+        cookie_params,
         has_request_options: true,
         deprecated: operation.deprecated.unwrap_or(false),
         meta: IndexMap::new(),
+        security: collect_endpoint_security(operation, open_api),
+        servers: collect_endpoint_servers(path_item, operation),
+        request_example,
+        response_example: collect_response_example(operation, open_api),
     }
 }
 
+/// Webhooks declared at document level, sorted by name.
+fn collect_webhooks(open_api: &OpenAPIObject) -> Vec<WebhookItem> {
+    let Some(webhooks) = open_api.webhooks.as_ref() else {
+        return vec![];
+    };
+    webhooks
+        .iter()
+        .filter_map(|(name, webhook)| Some((name, webhook.resolve(open_api)?)))
+        .flat_map(|(name, path_item)| {
+            path_item
+                .get_operations()
+                .into_iter()
+                .map(move |(method, operation)| {
+                    let mut item =
+                        build_webhook(name, method, path_item, operation, open_api, None);
+                    item.event = name.clone();
+                    item.operation_name = normalize_identifier(to_camel_case(name));
+                    item
+                })
+        })
+        .collect()
+}
+
+/// Callbacks registered by an endpoint, keyed by `{exportName}.{callbackName}`.
+fn collect_callbacks(
+    endpoint: &EndpointItem,
+    operation: &OperationObject,
+    open_api: &OpenAPIObject,
+) -> Vec<WebhookItem> {
+    let mut result = Vec::new();
+    for (callback_name, callback) in operation.callbacks.iter().flatten() {
+        let Some(expressions) = callback.resolve(open_api) else {
+            continue;
+        };
+        for (expression, value) in expressions {
+            let Some(path_item) = value.resolve(open_api) else {
+                continue;
+            };
+            for (method, callback_operation) in path_item.get_operations() {
+                let mut item = build_webhook(
+                    expression,
+                    method,
+                    path_item,
+                    callback_operation,
+                    open_api,
+                    Some(endpoint),
+                );
+                item.event = format!("{}.{callback_name}", endpoint.export_name);
+                item.operation_name = normalize_identifier(format!(
+                    "{}{}",
+                    endpoint.export_name,
+                    to_pascal_case(callback_name)
+                ));
+                result.push(item);
+            }
+        }
+    }
+    result
+}
+
+fn build_webhook(
+    path: &str,
+    method: &str,
+    path_item: &PathItemObject,
+    operation: &OperationObject,
+    open_api: &OpenAPIObject,
+    source: Option<&EndpointItem>,
+) -> WebhookItem {
+    // Webhook names and callback runtime expressions are not URL templates,
+    // only the operation itself is needed to resolve payload and response types
+    let context = ApiContext::new("", method, path_item, operation, open_api);
+    let payload_type_name = context
+        .func_parameters
+        .as_ref()
+        .and_then(|parameters| parameters.iter().find(|item| item.r#in.is_none()))
+        .map(|parameter| parameter.r#type.clone())
+        .filter(|type_name| !type_name.trim().is_empty())
+        .unwrap_or_else(|| "void".to_string());
+    let namespace = match (get_operation_namespace(operation), source) {
+        (Some(namespace), _) => namespace,
+        (None, Some(endpoint)) => endpoint.namespace.clone(),
+        (None, None) => vec!["default".to_string()],
+    };
+
+    WebhookItem {
+        kind: if source.is_some() {
+            WebhookKind::Callback
+        } else {
+            WebhookKind::Webhook
+        },
+        event: String::new(),
+        namespace,
+        operation_name: String::new(),
+        summary: operation.summary.clone(),
+        method: method.to_uppercase(),
+        path: path.to_string(),
+        payload_type_name,
+        response_type_name: context
+            .response_type
+            .clone()
+            .unwrap_or_else(|| "void".to_string()),
+        source_operation: source.map(|endpoint| endpoint.export_name.clone()),
+        deprecated: operation.deprecated.unwrap_or(false),
+        payload_example: collect_request_example(&context),
+    }
+}
+
+/// Make webhook events and handler names unique: keys shared by several
+/// operations get a method suffix, anything still clashing gets a serial.
+fn apply_webhook_names(webhooks: &mut [WebhookItem]) {
+    let mut counts = HashMap::<String, usize>::new();
+    for item in webhooks.iter() {
+        *counts.entry(item.event.clone()).or_default() += 1;
+    }
+
+    let mut used = HashSet::<String>::new();
+    for item in webhooks.iter_mut() {
+        if counts[&item.event] > 1 {
+            let method = item.method.to_lowercase();
+            item.event = format!("{}.{method}", item.event);
+            item.operation_name = format!("{}{}", item.operation_name, to_pascal_case(&method));
+        }
+        if used.contains(&item.event) {
+            let mut serial = 2usize;
+            while used.contains(&format!("{}_{serial}", item.event)) {
+                serial += 1;
+            }
+            item.event = format!("{}_{serial}", item.event);
+            item.operation_name = format!("{}{serial}", item.operation_name);
+        }
+        used.insert(item.event.clone());
+    }
+}
+
+/// Namespace derived from the first tag, split on `/` (`None` without tags).
+fn get_operation_namespace(operation: &OperationObject) -> Option<Vec<String>> {
+    operation
+        .tags
+        .as_ref()
+        .and_then(|tags| tags.first())
+        .map(|tag| {
+            tag.split('/')
+                .filter(|item| !item.trim().is_empty())
+                .map(to_kebab_case)
+                .collect::<Vec<_>>()
+        })
+        .filter(|list| !list.is_empty())
+}
+
+fn collect_endpoint_security(
+    operation: &OperationObject,
+    open_api: &OpenAPIObject,
+) -> Option<Vec<EndpointSecurityRequirement>> {
+    let requirements = get_operation_security(open_api, operation)?;
+    Some(
+        requirements
+            .iter()
+            .map(|requirement| {
+                let mut schemes = requirement
+                    .iter()
+                    .map(|(name, scopes)| {
+                        let scheme = get_security_scheme(open_api, name);
+                        EndpointSecurityScheme {
+                            name: name.clone(),
+                            scheme_type: scheme.map(|item| item.r#type.as_str().to_string()),
+                            http_scheme: scheme.and_then(|item| item.scheme.clone()),
+                            scopes: scopes.clone(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                schemes.sort_by(|a, b| a.name.cmp(&b.name));
+                EndpointSecurityRequirement { schemes }
+            })
+            .collect(),
+    )
+}
+
+/// Servers declared on the operation or, failing that, on its path item.
+/// Document-level servers are left to the client's base URL.
+fn collect_endpoint_servers(
+    path_item: &PathItemObject,
+    operation: &OperationObject,
+) -> Vec<EndpointServer> {
+    path_item
+        .get_operation_servers(operation)
+        .map(|servers| {
+            servers
+                .iter()
+                .map(|server| EndpointServer {
+                    url: server.resolve_url(),
+                    description: server.description.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Example of the request body parameter, if the operation has one.
+fn collect_request_example(context: &ApiContext) -> Option<serde_json::Value> {
+    context
+        .func_parameters
+        .as_ref()?
+        .iter()
+        .find(|parameter| parameter.r#in.is_none())
+        .and_then(|parameter| parameter.example.clone())
+}
+
+fn to_endpoint_responses(responses: Vec<StatusResponse>) -> Vec<EndpointResponse> {
+    responses
+        .into_iter()
+        .map(|response| EndpointResponse {
+            status_code: response.status_code,
+            type_name: response.r#type,
+        })
+        .collect()
+}
+
+/// A file download: some success response is `Blob` and the rest have no body.
+fn is_binary_response(responses: &[EndpointResponse]) -> bool {
+    responses.iter().any(|item| item.type_name == "Blob")
+        && responses
+            .iter()
+            .all(|item| matches!(item.type_name.as_str(), "Blob" | "void"))
+}
+
+/// Example body of the first success response that declares one.
+fn collect_response_example(
+    operation: &OperationObject,
+    open_api: &OpenAPIObject,
+) -> Option<serde_json::Value> {
+    operation
+        .get_success_responses()
+        .into_iter()
+        .filter_map(|(_, response)| response.resolve(open_api)?.content.as_ref())
+        .find_map(|content| content.values().next()?.get_example())
+        .cloned()
+}
+
 fn collect_endpoint_params(
     context: &ApiContext,
     target: ParameterObjectIn,
@@ -149,6 +418,8 @@ fn collect_endpoint_params(
                 name: parameter.name.clone(),
                 type_name: parameter.r#type.clone(),
                 required: parameter.required,
+                default: parameter.default.clone(),
+                example: parameter.example.clone(),
             })
         })
         .collect()
@@ -181,6 +452,22 @@ fn build_input_type_name(context: &ApiContext) -> String {
     render_inline_input_type(&body_params)
 }
 
+/// Binary bodies are sent as `Blob` and text bodies as `string`, whatever
+/// loose schema (`type: string, format: binary` or none) the spec gives them.
+fn adapt_body_type(type_name: String, media_type: Option<&str>) -> String {
+    let Some(media_type) = media_type else {
+        return type_name;
+    };
+    match (
+        RequestBodyKind::from_media_type(media_type),
+        type_name.as_str(),
+    ) {
+        (RequestBodyKind::Binary, "string" | "unknown" | "object") => "Blob".to_string(),
+        (RequestBodyKind::Text, "unknown" | "object") => "string".to_string(),
+        _ => type_name,
+    }
+}
+
 fn render_inline_input_type(parameters: &[FuncParameter]) -> String {
     let fields = parameters
         .iter()
@@ -415,21 +702,11 @@ mod tests {
         EndpointItem {
             namespace: namespace.into_iter().map(str::to_string).collect(),
             operation_name: operation_name.to_string(),
-            export_name: String::new(),
-            builder_name: String::new(),
-            summary: None,
             method: method.to_string(),
             path: "/demo".to_string(),
             input_type_name: "void".to_string(),
             output_type_name: "void".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }
     }
 
@@ -32,6 +32,10 @@ impl SchemaEnumExtension for SchemaEnum {
                 let child_type = schema.items.as_ref().get_ts_type();
                 format!("Array<{}>", child_type)
             }
+            SchemaEnum::AllOf(schema) => join_ts_types(&schema.all_of, " & "),
+            SchemaEnum::OneOf(schema) => join_ts_types(&schema.one_of, " | "),
+            SchemaEnum::AnyOf(schema) => join_ts_types(&schema.any_of, " | "),
+            SchemaEnum::Not(_) | SchemaEnum::Any(_) => "unknown".to_string(),
         }
     }
 
@@ -45,6 +49,15 @@ impl SchemaEnumExtension for SchemaEnum {
             SchemaEnum::Number(v) => v.nullable.unwrap_or_default(),
             SchemaEnum::Boolean(v) => v.nullable.unwrap_or_default(),
             SchemaEnum::Array(v) => v.nullable.unwrap_or_default(),
+            SchemaEnum::AllOf(v) => v.nullable.unwrap_or_default(),
+            SchemaEnum::OneOf(v) => {
+                v.nullable.unwrap_or_default() || v.one_of.iter().any(|x| x.can_be_null(open_api))
+            }
+            SchemaEnum::AnyOf(v) => {
+                v.nullable.unwrap_or_default() || v.any_of.iter().any(|x| x.can_be_null(open_api))
+            }
+            SchemaEnum::Not(v) => v.nullable.unwrap_or_default(),
+            SchemaEnum::Any(v) => v.nullable.unwrap_or_default(),
         }
     }
 
@@ -98,3 +111,15 @@ impl SchemaEnumExtension for SchemaEnum {
         }
     }
 }
+
+fn join_ts_types(items: &[SchemaEnum], separator: &str) -> String {
+    match items {
+        [] => "unknown".to_string(),
+        [item] => item.get_ts_type(),
+        _ => items
+            .iter()
+            .map(|item| format!("({})", item.get_ts_type()))
+            .collect::<Vec<_>>()
+            .join(separator),
+    }
+}
@@ -1,8 +1,9 @@
 use std::str::FromStr;
 use swagger_gen::pipeline::{
-    build_dry_run_plan, build_ir_snapshot_json, build_report_json, parse_openapi_to_ir,
+    CodegenPipeline, RequestBodyKind, WebhookKind, build_dry_run_plan, build_ir_snapshot_json,
+    build_report_json, parse_openapi_to_ir,
 };
-use swagger_tk::model::OpenAPIObject;
+use swagger_tk::{filter::SpecFilter, model::OpenAPIObject};
 
 /// Mock OpenAPI spec with endpoints for pipeline testing
 const MOCK_OPENAPI: &str = r###"
@@ -276,6 +277,674 @@ fn build_report_json_test() {
     assert!(report.contains("metrics"));
 }
 
+#[test]
+fn build_dry_run_plan_reports_untyped_schema_warnings() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Payload": {
+        "properties": { "data": { "description": "anything" } }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let plan = build_dry_run_plan(&open_api).expect("build dry run plan fail");
+    let warnings = &plan.parse_warnings;
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("#/components/schemas/Payload/properties/data"));
+    assert!(
+        plan.renderer_reports
+            .iter()
+            .all(|report| report.warnings.is_empty())
+    );
+}
+
+#[test]
+fn parse_openapi_to_ir_resolves_component_refs() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users/{id}": {
+      "parameters": [{ "$ref": "#/components/parameters/UserId" }],
+      "put": {
+        "parameters": [{ "$ref": "#/components/parameters/Notify" }],
+        "requestBody": { "$ref": "#/components/requestBodies/UserBody" },
+        "responses": { "200": { "$ref": "#/components/responses/UserResult" } }
+      }
+    }
+  },
+  "components": {
+    "parameters": {
+      "UserId": { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+      "Notify": { "name": "notify", "in": "query", "schema": { "type": "boolean" } }
+    },
+    "requestBodies": {
+      "UserBody": {
+        "required": true,
+        "content": { "application/json": { "schema": { "$ref": "#/components/schemas/User" } } }
+      }
+    },
+    "responses": {
+      "UserResult": {
+        "description": "Success",
+        "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Result" } } }
+      }
+    },
+    "schemas": {
+      "User": { "type": "object", "properties": { "name": { "type": "string" } } },
+      "Result": { "type": "object", "properties": { "ok": { "type": "boolean" } } }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+    let ep = find_endpoint(&ir, "PUT", "/users/{id}").expect("PUT /users/{id}");
+
+    assert_eq!(ep.path_fields, vec!["id"]);
+    assert_eq!(ep.query_fields, vec!["notify"]);
+    assert_eq!(ep.query_params[0].type_name, "boolean");
+    assert_eq!(ep.input_type_name, "User");
+    assert_eq!(ep.output_type_name, "Result");
+}
+
+#[test]
+fn parse_openapi_to_ir_carries_security_requirements() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "security": [{ "bearerAuth": [] }],
+  "paths": {
+    "/auth/login": {
+      "post": { "security": [], "responses": { "200": { "description": "Success" } } }
+    },
+    "/users": {
+      "get": { "responses": { "200": { "description": "Success" } } },
+      "post": {
+        "security": [{ "oauth": ["users:write"] }],
+        "responses": { "200": { "description": "Success" } }
+      }
+    }
+  },
+  "components": {
+    "securitySchemes": {
+      "bearerAuth": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" },
+      "oauth": {
+        "type": "oauth2",
+        "flows": {
+          "clientCredentials": { "tokenUrl": "/token", "scopes": { "users:write": "write users" } }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let login = find_endpoint(&ir, "POST", "/auth/login").expect("POST /auth/login");
+    assert_eq!(login.security.as_ref().map(Vec::len), Some(0));
+    assert!(login.is_auth_optional());
+
+    let list = find_endpoint(&ir, "GET", "/users").expect("GET /users");
+    let scheme = &list.security.as_ref().unwrap()[0].schemes[0];
+    assert_eq!(scheme.name, "bearerAuth");
+    assert_eq!(scheme.scheme_type.as_deref(), Some("http"));
+    assert_eq!(scheme.http_scheme.as_deref(), Some("bearer"));
+    assert!(!list.is_auth_optional());
+
+    let create = find_endpoint(&ir, "POST", "/users").expect("POST /users");
+    let scheme = &create.security.as_ref().unwrap()[0].schemes[0];
+    assert_eq!(scheme.scheme_type.as_deref(), Some("oauth2"));
+    assert_eq!(scheme.scopes, vec!["users:write"]);
+}
+
+#[test]
+fn parse_openapi_to_ir_carries_defaults_and_examples() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users": {
+      "post": {
+        "parameters": [
+          { "name": "page", "in": "query", "schema": { "type": "integer", "default": 1 }, "example": 2 }
+        ],
+        "requestBody": {
+          "content": {
+            "application/json": {
+              "schema": { "type": "object", "properties": { "name": { "type": "string" } } },
+              "examples": { "basic": { "value": { "name": "Tom" } } }
+            }
+          }
+        },
+        "responses": {
+          "200": {
+            "description": "Success",
+            "content": {
+              "application/json": { "schema": { "type": "string", "example": "ok" } }
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let create = find_endpoint(&ir, "POST", "/users").expect("POST /users");
+    let page = &create.query_params[0];
+    assert_eq!(page.default, Some(serde_json::json!(1)));
+    assert_eq!(page.example, Some(serde_json::json!(2)));
+    assert_eq!(
+        create.request_example,
+        Some(serde_json::json!({ "name": "Tom" }))
+    );
+    assert_eq!(create.response_example, Some(serde_json::json!("ok")));
+}
+
+#[test]
+fn codegen_pipeline_with_filter_keeps_matched_endpoints() {
+    let open_api_object = get_mock_openapi();
+    let pipeline = CodegenPipeline::default().with_filter(Some(SpecFilter {
+        include_paths: vec!["/users/**".to_string()],
+        exclude_methods: vec!["POST".to_string()],
+        ..Default::default()
+    }));
+    let ir = pipeline
+        .parse(&open_api_object)
+        .expect("parse openapi to ir fail");
+
+    let mut paths = ir
+        .endpoints
+        .iter()
+        .map(|e| format!("{} {}", e.method, e.path))
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, ["GET /users", "GET /users/{id}"]);
+
+    let plan = pipeline.plan(&open_api_object).expect("build plan fail");
+    assert_eq!(plan.endpoint_count, 2);
+}
+
+#[test]
+fn parse_openapi_to_ir_collects_webhooks_and_callbacks() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/pets": {
+      "post": {
+        "tags": ["Pet"],
+        "operationId": "addPet",
+        "responses": { "200": { "description": "Success" } },
+        "callbacks": {
+          "onDone": { "$ref": "#/components/callbacks/PetDone" }
+        }
+      }
+    }
+  },
+  "webhooks": {
+    "newPet": {
+      "post": {
+        "summary": "Pet created",
+        "requestBody": { "content": { "application/json": {
+          "schema": { "$ref": "#/components/schemas/Pet" }
+        } } },
+        "responses": { "200": { "description": "Success" } }
+      },
+      "put": {
+        "requestBody": { "content": { "application/json": {
+          "schema": { "$ref": "#/components/schemas/Pet" }
+        } } },
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "petRemoved": { "$ref": "#/components/pathItems/PetRemoved" }
+  },
+  "components": {
+    "schemas": {
+      "Pet": { "type": "object", "properties": { "name": { "type": "string" } } }
+    },
+    "pathItems": {
+      "PetRemoved": {
+        "post": {
+          "requestBody": { "content": { "application/json": {
+            "schema": { "$ref": "#/components/schemas/Pet" }
+          } } },
+          "responses": { "200": { "description": "Success" } }
+        }
+      }
+    },
+    "callbacks": {
+      "PetDone": {
+        "{$request.body#/callbackUrl}": {
+          "post": {
+            "requestBody": { "content": { "application/json": {
+              "schema": { "type": "object", "properties": { "ok": { "type": "boolean" } } }
+            } } },
+            "responses": { "200": { "description": "Success" } }
+          }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let events = ir
+        .webhooks
+        .iter()
+        .map(|item| item.event.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            "newPet.post",
+            "newPet.put",
+            "petRemoved",
+            "petAddPet.onDone"
+        ]
+    );
+
+    let new_pet = &ir.webhooks[0];
+    assert_eq!(new_pet.kind, WebhookKind::Webhook);
+    assert_eq!(new_pet.operation_name, "newPetPost");
+    assert_eq!(new_pet.payload_type_name, "Pet");
+    assert_eq!(new_pet.response_type_name, "void");
+    assert_eq!(new_pet.summary.as_deref(), Some("Pet created"));
+    assert_eq!(ir.webhooks[2].payload_type_name, "Pet");
+
+    let callback = &ir.webhooks[3];
+    assert_eq!(callback.kind, WebhookKind::Callback);
+    assert_eq!(callback.path, "{$request.body#/callbackUrl}");
+    assert_eq!(callback.source_operation.as_deref(), Some("petAddPet"));
+    assert_eq!(callback.namespace, ["pet"]);
+    assert_eq!(callback.operation_name, "petAddPetOnDone");
+}
+
+#[test]
+fn parse_openapi_to_ir_covers_all_methods_and_servers() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "servers": [{ "url": "https://api.example.com" }],
+  "paths": {
+    "/files/{id}": {
+      "servers": [
+        {
+          "url": "https://{region}.files.example.com/v1",
+          "description": "File storage",
+          "variables": { "region": { "default": "eu", "enum": ["eu", "us"] } }
+        }
+      ],
+      "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
+      "head": { "responses": { "200": { "description": "Success" } } },
+      "options": { "responses": { "204": { "description": "Success" } } },
+      "trace": { "responses": { "200": { "description": "Success" } } },
+      "patch": {
+        "servers": [{ "url": "https://upload.example.com" }],
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "/users": {
+      "get": { "responses": { "200": { "description": "Success" } } }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let methods = ir
+        .endpoints
+        .iter()
+        .filter(|e| e.path == "/files/{id}")
+        .map(|e| e.method.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(methods, ["PATCH", "HEAD", "OPTIONS", "TRACE"]);
+
+    let head = find_endpoint(&ir, "HEAD", "/files/{id}").expect("HEAD /files/{id}");
+    assert_eq!(head.path_fields, ["id"]);
+    assert_eq!(head.servers.len(), 1);
+    assert_eq!(head.servers[0].url, "https://eu.files.example.com/v1");
+    assert_eq!(head.servers[0].description.as_deref(), Some("File storage"));
+
+    let patch = find_endpoint(&ir, "PATCH", "/files/{id}").expect("PATCH /files/{id}");
+    assert_eq!(patch.servers[0].url, "https://upload.example.com");
+
+    let list = find_endpoint(&ir, "GET", "/users").expect("GET /users");
+    assert!(list.servers.is_empty());
+
+    let mut names = ir
+        .endpoints
+        .iter()
+        .map(|e| e.export_name.as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), ir.endpoints.len());
+}
+
+#[test]
+fn parse_openapi_to_ir_collects_header_and_cookie_params() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users/{id}": {
+      "parameters": [
+        { "name": "X-Tenant-Id", "in": "header", "required": true, "schema": { "type": "string" } }
+      ],
+      "put": {
+        "parameters": [
+          { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+          { "name": "If-Match", "in": "header", "schema": { "type": "string" } },
+          { "name": "session", "in": "cookie", "schema": { "type": "string" } }
+        ],
+        "responses": { "200": { "description": "Success" } }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let ep = find_endpoint(&ir, "PUT", "/users/{id}").expect("PUT /users/{id}");
+    let headers = ep
+        .header_params
+        .iter()
+        .map(|item| (item.name.as_str(), item.required))
+        .collect::<Vec<_>>();
+    assert_eq!(headers, [("X-Tenant-Id", true), ("If-Match", false)]);
+    assert_eq!(ep.cookie_params.len(), 1);
+    assert_eq!(ep.cookie_params[0].name, "session");
+    assert_eq!(ep.path_fields, ["id"]);
+    assert!(ep.query_params.is_empty());
+}
+
+#[test]
+fn parse_openapi_to_ir_resolves_success_and_error_responses() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users": {
+      "post": {
+        "responses": {
+          "201": {
+            "description": "Created",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/User" }, "example": { "id": 1 } } }
+          },
+          "default": {
+            "description": "Error",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } }
+          }
+        }
+      },
+      "delete": {
+        "responses": {
+          "204": { "description": "Deleted" },
+          "default": {
+            "description": "Error",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } }
+          }
+        }
+      },
+      "put": {
+        "responses": {
+          "200": {
+            "description": "Updated",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/User" } } }
+          },
+          "202": {
+            "description": "Accepted",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Job" } } }
+          },
+          "2XX": { "description": "Other success" }
+        }
+      },
+      "get": {
+        "responses": {
+          "default": {
+            "description": "Success",
+            "content": { "application/json": { "schema": { "type": "array", "items": { "$ref": "#/components/schemas/User" } } } }
+          }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": { "type": "object", "properties": { "id": { "type": "integer" } } },
+      "Job": { "type": "object", "properties": { "id": { "type": "string" } } },
+      "Error": { "type": "object", "properties": { "message": { "type": "string" } } }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let create = find_endpoint(&ir, "POST", "/users").expect("POST /users");
+    assert_eq!(create.output_type_name, "User");
+    assert_eq!(create.success_responses.len(), 1);
+    assert_eq!(create.success_responses[0].status_code, "201");
+    assert_eq!(
+        create.response_example,
+        Some(serde_json::json!({ "id": 1 }))
+    );
+    assert_eq!(create.error_responses.len(), 1);
+    assert_eq!(create.error_responses[0].status_code, "default");
+    assert_eq!(create.error_responses[0].type_name, "Error");
+
+    let delete = find_endpoint(&ir, "DELETE", "/users").expect("DELETE /users");
+    assert_eq!(delete.output_type_name, "void");
+    assert_eq!(delete.success_responses[0].type_name, "void");
+
+    let update = find_endpoint(&ir, "PUT", "/users").expect("PUT /users");
+    assert_eq!(update.output_type_name, "User | Job | void");
+    let codes = update
+        .success_responses
+        .iter()
+        .map(|item| item.status_code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, ["200", "202", "2XX"]);
+
+    let list = find_endpoint(&ir, "GET", "/users").expect("GET /users");
+    assert_eq!(list.output_type_name, "Array<User>");
+    assert!(list.error_responses.is_empty());
+}
+
+#[test]
+fn parse_openapi_to_ir_records_request_content_types() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/files": {
+      "post": {
+        "requestBody": {
+          "content": {
+            "multipart/form-data": { "schema": { "$ref": "#/components/schemas/UploadForm" } }
+          }
+        },
+        "responses": { "200": { "description": "Success" } }
+      },
+      "put": {
+        "requestBody": {
+          "content": {
+            "application/octet-stream": { "schema": { "type": "string", "format": "binary" } }
+          }
+        },
+        "responses": { "200": { "description": "Success" } }
+      },
+      "patch": {
+        "requestBody": {
+          "content": {
+            "application/x-www-form-urlencoded": { "schema": { "$ref": "#/components/schemas/UploadForm" } },
+            "application/json": { "schema": { "$ref": "#/components/schemas/UploadForm" } }
+          }
+        },
+        "responses": { "200": { "description": "Success" } }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "UploadForm": { "type": "object", "properties": { "name": { "type": "string" } } }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let upload = find_endpoint(&ir, "POST", "/files").expect("POST /files");
+    assert_eq!(upload.input_type_name, "UploadForm");
+    assert_eq!(
+        upload.request_content_type.as_deref(),
+        Some("multipart/form-data")
+    );
+    assert_eq!(upload.request_body_kind(), RequestBodyKind::Multipart);
+
+    let replace = find_endpoint(&ir, "PUT", "/files").expect("PUT /files");
+    assert_eq!(replace.input_type_name, "Blob");
+    assert_eq!(replace.request_body_kind(), RequestBodyKind::Binary);
+
+    let patch = find_endpoint(&ir, "PATCH", "/files").expect("PATCH /files");
+    assert_eq!(
+        patch.request_content_type.as_deref(),
+        Some("application/json")
+    );
+    assert_eq!(
+        patch.request_content_types,
+        ["application/json", "application/x-www-form-urlencoded"]
+    );
+    assert_eq!(patch.request_body_kind(), RequestBodyKind::Json);
+}
+
+#[test]
+fn parse_openapi_to_ir_marks_binary_responses() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/files/{id}": {
+      "get": {
+        "parameters": [
+          { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }
+        ],
+        "responses": {
+          "200": {
+            "description": "File",
+            "content": { "application/octet-stream": { "schema": { "type": "string", "format": "binary" } } }
+          }
+        }
+      }
+    },
+    "/archives": {
+      "get": {
+        "responses": {
+          "200": { "description": "Archive", "content": { "application/octet-stream": {} } }
+        }
+      }
+    },
+    "/reports": {
+      "get": {
+        "responses": {
+          "200": {
+            "description": "Report",
+            "content": { "application/pdf": { "schema": { "type": "string" } } }
+          },
+          "204": { "description": "No report" }
+        }
+      },
+      "post": {
+        "responses": {
+          "200": {
+            "description": "Export",
+            "content": { "application/json": { "schema": { "type": "string", "format": "binary" } } }
+          }
+        }
+      },
+      "put": {
+        "responses": {
+          "200": {
+            "description": "Name",
+            "content": { "application/json": { "schema": { "type": "string" } } }
+          }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let ir = parse_openapi_to_ir(&open_api).expect("parse openapi to ir fail");
+
+    let file = find_endpoint(&ir, "GET", "/files/{id}").expect("GET /files/{id}");
+    assert!(file.binary_response);
+    assert_eq!(file.output_type_name, "Blob");
+
+    // Binary response without a schema
+    let archive = find_endpoint(&ir, "GET", "/archives").expect("GET /archives");
+    assert!(archive.binary_response);
+    assert_eq!(archive.output_type_name, "Blob");
+
+    let report = find_endpoint(&ir, "GET", "/reports").expect("GET /reports");
+    assert!(report.binary_response);
+    assert_eq!(report.output_type_name, "Blob | void");
+
+    let export = find_endpoint(&ir, "POST", "/reports").expect("POST /reports");
+    assert!(export.binary_response);
+
+    let name = find_endpoint(&ir, "PUT", "/reports").expect("PUT /reports");
+    assert!(!name.binary_response);
+    assert_eq!(name.output_type_name, "string");
+}
+
 /// Find an endpoint by (method, path) in the parsed IR
 fn find_endpoint<'a>(
     ir: &'a swagger_gen::pipeline::GeneratorInput,
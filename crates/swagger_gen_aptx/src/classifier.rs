@@ -11,7 +11,7 @@ use swagger_gen::pipeline::{EndpointItem, GeneratorInput, TransformPass};
 ///
 /// ## Classification Rules
 ///
-/// 1. **GET requests** → Query
+/// 1. **GET, HEAD and OPTIONS requests** → Query
 /// 2. **POST requests with query-semantic names** → Query
 ///    - Names starting with `get` followed by uppercase letter (e.g., `getList`, `getTree`, `getPagedList`)
 ///    - Names starting with `query` (e.g., `queryUsers`, `queryByFilter`)
@@ -47,8 +47,11 @@ impl TransformPass for AptxQueryMutationPass {
 
 /// Determines if an endpoint should be classified as a query (vs mutation).
 fn classify_endpoint(endpoint: &EndpointItem) -> bool {
-    // GET is always a query
-    if endpoint.method.eq_ignore_ascii_case("GET") {
+    // GET and the other read-only methods are always queries
+    if ["GET", "HEAD", "OPTIONS"]
+        .iter()
+        .any(|method| endpoint.method.eq_ignore_ascii_case(method))
+    {
         return true;
     }
 
@@ -93,7 +96,6 @@ fn is_query_semantic_name(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indexmap::IndexMap;
 
     #[test]
     fn test_is_query_semantic_name() {
@@ -136,19 +138,11 @@ mod tests {
             operation_name: operation_name.to_string(),
             export_name,
             builder_name: format!("buildTest{}Spec", to_pascal_case(operation_name)),
-            summary: None,
             method: method.to_string(),
             path: "/test".to_string(),
             input_type_name: "void".to_string(),
             output_type_name: "void".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }
     }
 
@@ -174,6 +168,17 @@ mod tests {
         let get_endpoint = create_test_endpoint("GET", "getUser");
         assert!(classify_endpoint(&get_endpoint));
 
+        // HEAD and OPTIONS are read-only as well
+        assert!(classify_endpoint(&create_test_endpoint("HEAD", "headUser")));
+        assert!(classify_endpoint(&create_test_endpoint(
+            "OPTIONS",
+            "optionsUser"
+        )));
+        assert!(!classify_endpoint(&create_test_endpoint(
+            "PATCH",
+            "patchUser"
+        )));
+
         // POST with query name should be query
         let post_get_list = create_test_endpoint("POST", "getList");
         assert!(classify_endpoint(&post_get_list));
@@ -230,6 +235,7 @@ mod tests {
             model_import: None,
             client_import: None,
             output_root: None,
+            webhooks: vec![],
         };
 
         pass.apply(&mut input).unwrap();
@@ -5,11 +5,15 @@
 use crate::META_SKIP_AUTH_REFRESH;
 use crate::{
     ResolvedTsName, get_client_call, get_client_import_lines, normalize_type_ref,
-    render_type_import_block, render_type_import_line, resolve_file_import_path,
-    resolve_final_ts_names, resolve_model_import_base, should_use_package_import,
+    render_type_import_block, resolve_file_import_path, resolve_final_ts_names,
+    resolve_model_import_base, should_use_package_import,
 };
 
-use swagger_gen::pipeline::{EndpointItem, GeneratorInput, PlannedFile, RenderOutput, Renderer};
+use inflector::cases::{camelcase::to_camel_case, pascalcase::to_pascal_case};
+use swagger_gen::pipeline::{
+    EndpointItem, EndpointParameter, GeneratorInput, PlannedFile, RenderOutput, Renderer,
+    RequestBodyKind,
+};
 
 /// Functions renderer for @aptx/api-client
 ///
@@ -60,6 +64,13 @@ impl Renderer for AptxFunctionsRenderer {
             });
         }
 
+        if let Some(content) = render_request_body_helpers(&input.endpoints) {
+            files.push(PlannedFile {
+                path: REQUEST_BODY_HELPER_PATH.to_string(),
+                content,
+            });
+        }
+
         Ok(RenderOutput {
             files,
             warnings: vec![],
@@ -85,26 +96,39 @@ fn render_spec_file(
 ) -> String {
     let builder = resolved_name.builder_name.clone();
     let input_type = normalize_type_ref(&endpoint.input_type_name);
-    let input_import = render_type_import_line(&input_type, model_import_base, use_package);
+    let input_import = render_type_import_block(
+        &input_import_types(endpoint)
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>(),
+        model_import_base,
+        use_package,
+    );
     let is_void_input = input_type == "void";
-    let signature = if is_void_input {
-        String::new()
-    } else {
-        format!("input: {input_type}")
-    };
+    let header_inputs = collect_header_inputs(endpoint);
+    let signature = (!is_void_input)
+        .then(|| format!("input: {input_type}"))
+        .into_iter()
+        .chain(render_headers_param(&header_inputs))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let body_kind = endpoint.request_body_kind();
     let payload_field = endpoint
         .request_body_field
         .as_ref()
+        .filter(|_| !is_void_input)
         .map(|field| {
-            if is_void_input {
-                String::new()
-            } else if endpoint.query_fields.is_empty() && endpoint.path_fields.is_empty() {
-                "    body: input,\n".to_string()
+            let value = if endpoint.query_fields.is_empty() && endpoint.path_fields.is_empty() {
+                "input".to_string()
             } else {
-                format!("    body: input.{field},\n")
-            }
+                format!("input.{field}")
+            };
+            format!("    body: {},\n", encode_body(&value, body_kind))
         })
         .unwrap_or_default();
+    let body_helper = (!payload_field.is_empty())
+        .then(|| body_helper_name(body_kind))
+        .flatten();
     let query_lines = if endpoint.query_fields.is_empty() || is_void_input {
         String::new()
     } else {
@@ -117,6 +141,9 @@ fn render_spec_file(
         format!("    query: {{ {keys} }},\n")
     };
 
+    let (headers_prelude, headers_field) =
+        render_headers_field(&header_inputs, endpoint.request_content_type_header());
+
     // Check if endpoint has skip_auth_refresh meta
     let has_skip_auth_refresh =
         endpoint.meta.get(META_SKIP_AUTH_REFRESH) == Some(&"true".to_string());
@@ -146,6 +173,13 @@ fn render_spec_file(
         String::new()
     };
 
+    // File downloads are read as a Blob instead of being decoded as JSON
+    let response_type_field = if endpoint.binary_response {
+        "    responseType: \"blob\",\n"
+    } else {
+        ""
+    };
+
     // Build imports
     let mut imports = Vec::new();
 
@@ -154,6 +188,16 @@ fn render_spec_file(
         imports.push(input_import);
     }
 
+    if let Some(helper) = body_helper {
+        let helper_import_path = resolve_file_import_path(
+            &get_spec_file_path(endpoint, resolved_name),
+            REQUEST_BODY_HELPER_PATH,
+        );
+        imports.push(format!(
+            "import {{ {helper} }} from \"{helper_import_path}\";"
+        ));
+    }
+
     // Add RequestSpec import (and SKIP_AUTH_REFRESH_META_KEY if needed)
     if has_skip_auth_refresh {
         imports.push(
@@ -171,7 +215,7 @@ fn render_spec_file(
     };
 
     format!(
-        "{prefix}export function {builder}({signature}): RequestSpec {{\n  return {{\n    method: \"{method}\",\n    path: \"{path}\",\n{query_lines}{payload_field}{meta_field}  }};\n}}\n",
+        "{prefix}export function {builder}({signature}): RequestSpec {{\n{headers_prelude}  return {{\n    method: \"{method}\",\n    path: \"{path}\",\n{query_lines}{headers_field}{payload_field}{response_type_field}{meta_field}  }};\n}}\n",
         signature = signature,
         method = endpoint.method,
         path = endpoint.path
@@ -190,48 +234,375 @@ fn render_function_file(
     let input_type = normalize_type_ref(&endpoint.input_type_name);
     let output_type = normalize_type_ref(&endpoint.output_type_name);
     let is_void_input = input_type == "void";
-    let input_signature = if is_void_input {
-        String::new()
-    } else {
-        format!("  input: {input_type},\n")
-    };
-    let builder_call = if is_void_input {
-        format!("{builder}()")
-    } else {
-        format!("{builder}(input)")
-    };
+    let headers_param = render_headers_param(&collect_header_inputs(endpoint));
+    let input_signature = (!is_void_input)
+        .then(|| format!("input: {input_type}"))
+        .into_iter()
+        .chain(headers_param.clone())
+        .map(|param| format!("  {param},\n"))
+        .collect::<String>();
+    let builder_args = (!is_void_input)
+        .then_some("input")
+        .into_iter()
+        .chain(headers_param.is_some().then_some("headers"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let builder_call = format!("{builder}({builder_args})");
+    let import_types = input_import_types(endpoint);
+    let error_types = endpoint
+        .error_responses
+        .iter()
+        .map(|response| normalize_type_ref(&response.type_name))
+        .collect::<Vec<_>>();
     let type_imports = render_type_import_block(
-        &[input_type.as_str(), output_type.as_str()],
+        &import_types
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(output_type.as_str()))
+            .chain(error_types.iter().map(String::as_str))
+            .collect::<Vec<_>>(),
         model_import_base,
         use_package,
     );
+    let error_type = render_error_type(endpoint, &resolved_name.export_name);
     let spec_file_path = get_spec_file_path(endpoint, resolved_name);
     let spec_import_path = resolve_file_import_path(current_file_path, &spec_file_path);
     let client_import_lines = get_client_import_lines(client_import);
     let client_call = get_client_call(client_import);
+    let execute = format!("{client_call}.execute<{output_type}>({builder_call}, options)");
+    // Declared error responses are rethrown as the typed error union
+    let (async_keyword, body) = if error_type.is_empty() {
+        ("", format!("  return {execute};\n"))
+    } else {
+        (
+            "async ",
+            format!(
+                "  try {{\n    return await {execute};\n  }} catch (error) {{\n    throw to{}Error(error) ?? error;\n  }}\n",
+                to_pascal_case(&resolved_name.export_name)
+            ),
+        )
+    };
     let type_import_block = if type_imports.is_empty() {
         "\n".to_string()
     } else {
         format!("{type_imports}\n")
     };
     format!(
-        "{client_import_lines}\nimport {{ {builder} }} from \"{spec_import_path}\";\n{type_import_block}export function {operation_name}(\n{input_signature}  options?: PerCallOptions\n): Promise<{output_type}> {{\n  return {client_call}.execute<{output_type}>({builder_call}, options);\n}}\n",
+        "{client_import_lines}\nimport {{ {builder} }} from \"{spec_import_path}\";\n{type_import_block}export {async_keyword}function {operation_name}(\n{input_signature}  options?: PerCallOptions\n): Promise<{output_type}> {{\n{body}}}\n{error_type}",
         operation_name = resolved_name.export_name,
         output_type = output_type,
         type_import_block = type_import_block,
         client_import_lines = client_import_lines,
-        client_call = client_call,
         input_signature = input_signature,
-        builder_call = builder_call,
         spec_import_path = spec_import_path,
     )
 }
 
+/// Expression that turns the body value into what the request has to send.
+fn encode_body(value: &str, kind: RequestBodyKind) -> String {
+    match kind {
+        RequestBodyKind::Json | RequestBodyKind::Binary => value.to_string(),
+        RequestBodyKind::Multipart => format!("toFormData({value})"),
+        RequestBodyKind::FormUrlEncoded => format!("toURLSearchParams({value})"),
+        RequestBodyKind::Text => format!("String({value})"),
+    }
+}
+
+/// Shared module holding the body encoders used by `encode_body`.
+const REQUEST_BODY_HELPER_PATH: &str = "spec/requestBody.ts";
+
+/// Encoder from the shared helper module the body has to go through.
+fn body_helper_name(kind: RequestBodyKind) -> Option<&'static str> {
+    match kind {
+        RequestBodyKind::Multipart => Some("toFormData"),
+        RequestBodyKind::FormUrlEncoded => Some("toURLSearchParams"),
+        _ => None,
+    }
+}
+
+/// Content of the shared helper module, with only the encoders some endpoint uses.
+fn render_request_body_helpers(endpoints: &[EndpointItem]) -> Option<String> {
+    let used = |kind| {
+        endpoints.iter().any(|endpoint| {
+            endpoint.request_body_field.is_some()
+                && endpoint.input_type_name != "void"
+                && endpoint.request_body_kind() == kind
+        })
+    };
+    let mut helpers = Vec::new();
+    if used(RequestBodyKind::Multipart) {
+        helpers.push(
+            r#"export function toFormData(value: object): FormData {
+  const formData = new FormData();
+  for (const [key, item] of Object.entries(value)) {
+    for (const entry of Array.isArray(item) ? item : [item]) {
+      if (entry === undefined || entry === null) continue;
+      formData.append(
+        key,
+        entry instanceof Blob
+          ? entry
+          : typeof entry === "object"
+            ? JSON.stringify(entry)
+            : String(entry),
+      );
+    }
+  }
+  return formData;
+}
+"#,
+        );
+    }
+    if used(RequestBodyKind::FormUrlEncoded) {
+        helpers.push(
+            r#"export function toURLSearchParams(value: object): URLSearchParams {
+  const params = new URLSearchParams();
+  for (const [key, item] of Object.entries(value)) {
+    for (const entry of Array.isArray(item) ? item : [item]) {
+      if (entry !== undefined && entry !== null) params.append(key, String(entry));
+    }
+  }
+  return params;
+}
+"#,
+        );
+    }
+    (!helpers.is_empty()).then(|| helpers.join("\n"))
+}
+
+/// Discriminated union of the endpoint's error responses keyed by `response`
+/// (status code, range or `default`), plus the converter the function uses to
+/// rethrow client errors as that union.
+fn render_error_type(endpoint: &EndpointItem, export_name: &str) -> String {
+    let mut members = Vec::<String>::new();
+    let mut keys = Vec::<String>::new();
+    for response in &endpoint.error_responses {
+        // `4xx` and `4XX` are the same range, the key is always upper case
+        let key = if response.status_code == "default" {
+            response.status_code.clone()
+        } else {
+            response.status_code.to_ascii_uppercase()
+        };
+        let status = if key.bytes().all(|ch| ch.is_ascii_digit()) {
+            key.clone()
+        } else {
+            "number".to_string()
+        };
+        let body = match normalize_type_ref(&response.type_name).as_str() {
+            "void" => "undefined".to_string(),
+            type_name => type_name.to_string(),
+        };
+        let member = format!("  | {{ response: \"{key}\"; status: {status}; body: {body} }}");
+        if !members.contains(&member) {
+            members.push(member);
+            keys.push(format!("\"{key}\""));
+        }
+    }
+    if members.is_empty() {
+        return String::new();
+    }
+
+    let type_name = format!("{}Error", to_pascal_case(export_name));
+    format!(
+        r#"
+/**
+ * Error responses of `{export_name}`, discriminated by `response`: the declared
+ * status code, range (`4XX`) or `default`.
+ */
+export type {type_name} =
+{members};
+
+const {export_name}ErrorResponses: readonly string[] = [{keys}];
+
+/** Matches a client error carrying `status` and `body` to a declared error response. */
+export function to{type_name}(error: unknown): {type_name} | undefined {{
+  const status = (error as {{ status?: unknown }} | null)?.status;
+  if (typeof status !== "number") return undefined;
+  const response = [String(status), `${{Math.floor(status / 100)}}XX`, "default"].find((key) =>
+    {export_name}ErrorResponses.includes(key),
+  );
+  if (response === undefined) return undefined;
+  return {{ response, status, body: (error as {{ body?: unknown }}).body }} as {type_name};
+}}
+"#,
+        members = members.join("\n"),
+        keys = keys.join(", "),
+    )
+}
+
+/// A header or cookie parameter exposed as a property of the `headers` argument.
+struct HeaderInput<'a> {
+    property: String,
+    parameter: &'a EndpointParameter,
+    is_cookie: bool,
+}
+
+fn collect_header_inputs(endpoint: &EndpointItem) -> Vec<HeaderInput<'_>> {
+    let parameters = endpoint
+        .header_params
+        .iter()
+        .map(|parameter| (parameter, false))
+        .chain(
+            endpoint
+                .cookie_params
+                .iter()
+                .map(|parameter| (parameter, true)),
+        );
+    let mut inputs = Vec::<HeaderInput>::new();
+    for (parameter, is_cookie) in parameters {
+        // Names equal after camelCasing (`X-Id` / `x-id`, header / cookie) get a numeric suffix
+        let base = sanitize_property_name(&to_camel_case(&parameter.name));
+        let mut property = base.clone();
+        let mut index = 2;
+        while inputs.iter().any(|item| item.property == property) {
+            property = format!("{base}{index}");
+            index += 1;
+        }
+        inputs.push(HeaderInput {
+            property,
+            parameter,
+            is_cookie,
+        });
+    }
+    inputs
+}
+
+/// `headers` parameter of the spec builder, one property per header or cookie
+/// parameter. Optional when none of them is required.
+fn render_headers_param(header_inputs: &[HeaderInput]) -> Option<String> {
+    if header_inputs.is_empty() {
+        return None;
+    }
+
+    let fields = header_inputs
+        .iter()
+        .map(|item| {
+            format!(
+                "{}{} {}",
+                item.property,
+                if item.parameter.required { ":" } else { "?:" },
+                normalize_type_ref(&item.parameter.type_name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    let optional = if header_inputs.iter().any(|item| item.parameter.required) {
+        ""
+    } else {
+        "?"
+    };
+    Some(format!("headers{optional}: {{ {fields} }}"))
+}
+
+/// Variables type of a query/mutation definition and the `buildSpec` that
+/// passes them to the spec builder. Endpoints with header parameters take
+/// `{ input, headers }` so the body is never mixed with the headers.
+pub(crate) fn resolve_definition_input(endpoint: &EndpointItem, builder: &str) -> (String, String) {
+    let input_type = normalize_type_ref(&endpoint.input_type_name);
+    let is_void_input = input_type == "void";
+    let Some(headers_param) = render_headers_param(&collect_header_inputs(endpoint)) else {
+        return if is_void_input {
+            (input_type, format!("() => {builder}()"))
+        } else {
+            (input_type, builder.to_string())
+        };
+    };
+
+    if is_void_input {
+        (
+            format!("{{ {headers_param} }}"),
+            format!("({{ headers }}) => {builder}(headers)"),
+        )
+    } else {
+        (
+            format!("{{ input: {input_type}; {headers_param} }}"),
+            format!("({{ input, headers }}) => {builder}(input, headers)"),
+        )
+    }
+}
+
+/// Type expressions referenced by the input type, for model imports.
+pub(crate) fn input_import_types(endpoint: &EndpointItem) -> Vec<String> {
+    std::iter::once(normalize_type_ref(&endpoint.input_type_name))
+        .filter(|type_name| type_name != "void")
+        .chain(
+            collect_header_inputs(endpoint)
+                .iter()
+                .map(|item| normalize_type_ref(&item.parameter.type_name)),
+        )
+        .collect()
+}
+
+/// `headers` field of the spec and the statements computing it. Unset optional
+/// headers are left out, and the `Cookie` header is only sent when a cookie is set.
+fn render_headers_field(
+    header_inputs: &[HeaderInput],
+    content_type: Option<&str>,
+) -> (String, String) {
+    // `headers` is an optional parameter when no header is required
+    let source = if header_inputs.iter().any(|item| item.parameter.required) {
+        "headers."
+    } else {
+        "headers?."
+    };
+    let mut entries = header_inputs
+        .iter()
+        .filter(|item| !item.is_cookie)
+        .map(|item| {
+            let value = format!("{source}{}", item.property);
+            if item.parameter.required {
+                format!("\"{}\": {value}", item.parameter.name)
+            } else {
+                format!(
+                    "...({value} !== undefined ? {{ \"{}\": {value} }} : {{}})",
+                    item.parameter.name
+                )
+            }
+        })
+        .chain(content_type.map(|content_type| format!("\"Content-Type\": \"{content_type}\"")))
+        .collect::<Vec<_>>();
+
+    let cookies = header_inputs
+        .iter()
+        .filter(|item| item.is_cookie)
+        .map(|item| format!("[\"{}\", {source}{}]", item.parameter.name, item.property))
+        .collect::<Vec<_>>();
+    let prelude = if cookies.is_empty() {
+        String::new()
+    } else {
+        entries.push("...(cookie ? { Cookie: cookie } : {})".to_string());
+        format!(
+            "  const cookie = [{}].filter(([, value]) => value !== undefined).map(([name, value]) => `${{name}}=${{encodeURIComponent(String(value))}}`).join(\"; \");\n",
+            cookies.join(", ")
+        )
+    };
+
+    if entries.is_empty() {
+        (prelude, String::new())
+    } else {
+        (
+            prelude,
+            format!("    headers: {{ {} }},\n", entries.join(", ")),
+        )
+    }
+}
+
+fn sanitize_property_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .filter(|ch| ch.is_ascii_alphanumeric() || *ch == '_' || *ch == '$')
+        .collect::<String>();
+    if name.is_empty() || name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use indexmap::IndexMap;
-    use swagger_gen::pipeline::ProjectContext;
+    use swagger_gen::pipeline::{EndpointResponse, ProjectContext};
 
     fn make_generator_input(endpoints: Vec<EndpointItem>) -> GeneratorInput {
         GeneratorInput {
@@ -245,6 +616,7 @@ mod tests {
             model_import: None,
             client_import: None,
             output_root: None,
+            webhooks: vec![],
         }
     }
 
@@ -261,19 +633,12 @@ mod tests {
             operation_name: "getUser".to_string(),
             export_name: "usersGetUser".to_string(),
             builder_name: "buildUsersGetUserSpec".to_string(),
-            summary: None,
             method: "GET".to_string(),
             path: "/users/{id}".to_string(),
             input_type_name: "GetUserInput".to_string(),
             output_type_name: "User".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
             path_fields: vec!["id".to_string()],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         };
         let resolved_name = ResolvedTsName {
             file_stem: "getUser".to_string(),
@@ -293,19 +658,12 @@ mod tests {
             operation_name: "getUser".to_string(),
             export_name: "usersGetUser".to_string(),
             builder_name: "buildUsersGetUserSpec".to_string(),
-            summary: None,
             method: "GET".to_string(),
             path: "/users/{id}".to_string(),
             input_type_name: "GetUserInput".to_string(),
             output_type_name: "User".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
             path_fields: vec!["id".to_string()],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         };
         let resolved_name = ResolvedTsName {
             file_stem: "getUser".to_string(),
@@ -325,19 +683,11 @@ mod tests {
             operation_name: "add".to_string(),
             export_name: "assignmentAdd".to_string(),
             builder_name: "buildAssignmentAddSpec".to_string(),
-            summary: None,
             method: "POST".to_string(),
             path: "/assignment/add".to_string(),
             input_type_name: "AddInput".to_string(),
             output_type_name: "AddOutput".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         };
         let content = render_function_file(
             &endpoint,
@@ -362,19 +712,13 @@ mod tests {
             operation_name: "uploadImage".to_string(),
             export_name: "storedFileUploadImage".to_string(),
             builder_name: "buildStoredFileUploadImageSpec".to_string(),
-            summary: None,
             method: "POST".to_string(),
             path: "/stored-file/upload".to_string(),
             input_type_name: "{ StoreType: StoreType; body?: object }".to_string(),
             output_type_name: "GuidResultModel".to_string(),
             request_body_field: Some("body".to_string()),
-            query_params: vec![],
             query_fields: vec!["StoreType".to_string()],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         };
 
         let content = render_spec_file(
@@ -400,19 +744,13 @@ mod tests {
             operation_name: "refreshToken".to_string(),
             export_name: "userRefreshToken".to_string(),
             builder_name: "buildUserRefreshTokenSpec".to_string(),
-            summary: None,
             method: "POST".to_string(),
             path: "/MainAPI/User/RefreshToken".to_string(),
             input_type_name: "RefreshTokenInput".to_string(),
             output_type_name: "RefreshTokenOutput".to_string(),
             request_body_field: Some("body".to_string()),
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
             meta,
+            ..Default::default()
         };
 
         let content = render_spec_file(
@@ -443,19 +781,12 @@ mod tests {
             operation_name: "getUser".to_string(),
             export_name: "userGetUser".to_string(),
             builder_name: "buildUserGetUserSpec".to_string(),
-            summary: None,
             method: "GET".to_string(),
             path: "/user/{id}".to_string(),
             input_type_name: "void".to_string(),
             output_type_name: "User".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
             path_fields: vec!["id".to_string()],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         };
 
         let content = render_spec_file(
@@ -484,38 +815,22 @@ mod tests {
                 operation_name: "postAuthorityAPIActionAuthorityAdd".to_string(),
                 export_name: "actionAuthorityAdd".to_string(),
                 builder_name: "buildActionAuthorityAddSpec".to_string(),
-                summary: None,
                 method: "POST".to_string(),
                 path: "/AuthorityAPI/ActionAuthority/Add".to_string(),
                 input_type_name: "AddActionAuthorityRequestModel".to_string(),
                 output_type_name: "GuidResultModel".to_string(),
-                request_body_field: None,
-                query_params: vec![],
-                query_fields: vec![],
-                path_params: vec![],
-                path_fields: vec![],
-                has_request_options: false,
-                deprecated: false,
-                meta: IndexMap::new(),
+                ..Default::default()
             },
             EndpointItem {
                 namespace: vec!["role".to_string()],
                 operation_name: "postAuthorityAPIRoleAdd".to_string(),
                 export_name: "roleAdd".to_string(),
                 builder_name: "buildRoleAddSpec".to_string(),
-                summary: None,
                 method: "POST".to_string(),
                 path: "/AuthorityAPI/Role/Add".to_string(),
                 input_type_name: "AddRoleRequestModel".to_string(),
                 output_type_name: "GuidResultModel".to_string(),
-                request_body_field: None,
-                query_params: vec![],
-                query_fields: vec![],
-                path_params: vec![],
-                path_fields: vec![],
-                has_request_options: false,
-                deprecated: false,
-                meta: IndexMap::new(),
+                ..Default::default()
             },
         ]);
 
@@ -563,19 +878,11 @@ mod tests {
             operation_name: "postAuthorityAPIAnnouncementAdd".to_string(),
             export_name: "announcementAdd".to_string(),
             builder_name: "buildAnnouncementAddSpec".to_string(),
-            summary: None,
             method: "POST".to_string(),
             path: "/AuthorityAPI/Announcement/Add".to_string(),
             input_type_name: "AddAnnouncementRequestModel".to_string(),
             output_type_name: "GuidResultModel".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }]);
 
         let output = AptxFunctionsRenderer.render(&input).unwrap();
@@ -606,38 +913,22 @@ mod tests {
                 operation_name: "getAuthorityAPIUserGetLoginUserInfo".to_string(),
                 export_name: "userGetLoginUserInfo".to_string(),
                 builder_name: "buildUserGetLoginUserInfoSpec".to_string(),
-                summary: None,
                 method: "GET".to_string(),
                 path: "/AuthorityAPI/User/GetLoginUserInfo".to_string(),
                 input_type_name: "void".to_string(),
                 output_type_name: "LoginUserInfo".to_string(),
-                request_body_field: None,
-                query_params: vec![],
-                query_fields: vec![],
-                path_params: vec![],
-                path_fields: vec![],
-                has_request_options: false,
-                deprecated: false,
-                meta: IndexMap::new(),
+                ..Default::default()
             },
             EndpointItem {
                 namespace: vec!["user".to_string()],
                 operation_name: "getAuthorityAPIUserGetLoginUserPermissions".to_string(),
                 export_name: "userGetLoginUserPermissions".to_string(),
                 builder_name: "buildUserGetLoginUserPermissionsSpec".to_string(),
-                summary: None,
                 method: "GET".to_string(),
                 path: "/AuthorityAPI/User/GetLoginUserPermissions".to_string(),
                 input_type_name: "void".to_string(),
                 output_type_name: "LoginUserPermissions".to_string(),
-                request_body_field: None,
-                query_params: vec![],
-                query_fields: vec![],
-                path_params: vec![],
-                path_fields: vec![],
-                has_request_options: false,
-                deprecated: false,
-                meta: IndexMap::new(),
+                ..Default::default()
             },
         ]);
 
@@ -682,38 +973,22 @@ mod tests {
                 operation_name: "postAuthorityAPIUserAdd".to_string(),
                 export_name: "userAdd".to_string(),
                 builder_name: "buildUserAddSpec".to_string(),
-                summary: None,
                 method: "POST".to_string(),
                 path: "/AuthorityAPI/User/Add".to_string(),
                 input_type_name: "AddUserRequest".to_string(),
                 output_type_name: "User".to_string(),
-                request_body_field: None,
-                query_params: vec![],
-                query_fields: vec![],
-                path_params: vec![],
-                path_fields: vec![],
-                has_request_options: false,
-                deprecated: false,
-                meta: IndexMap::new(),
+                ..Default::default()
             },
             EndpointItem {
                 namespace: vec!["user".to_string()],
                 operation_name: "getAuthorityAPIUserAdd".to_string(),
                 export_name: "userAddGet".to_string(),
                 builder_name: "buildUserAddGetSpec".to_string(),
-                summary: None,
                 method: "GET".to_string(),
                 path: "/AuthorityAPI/User/Add".to_string(),
                 input_type_name: "void".to_string(),
                 output_type_name: "User".to_string(),
-                request_body_field: None,
-                query_params: vec![],
-                query_fields: vec![],
-                path_params: vec![],
-                path_fields: vec![],
-                has_request_options: false,
-                deprecated: false,
-                meta: IndexMap::new(),
+                ..Default::default()
             },
         ]);
 
@@ -731,4 +1006,337 @@ mod tests {
                 .any(|f| f.path == "functions/user/addGet.ts")
         );
     }
+
+    #[test]
+    fn test_render_spec_file_with_header_and_cookie_params() {
+        let header = |name: &str, required: bool| EndpointParameter {
+            name: name.to_string(),
+            type_name: "string".to_string(),
+            required,
+            default: None,
+            example: None,
+        };
+        let mut endpoint = EndpointItem {
+            namespace: vec!["user".to_string()],
+            operation_name: "add".to_string(),
+            export_name: "userAdd".to_string(),
+            builder_name: "buildUserAddSpec".to_string(),
+            method: "POST".to_string(),
+            path: "/user/add".to_string(),
+            input_type_name: "AddUserInput".to_string(),
+            output_type_name: "User".to_string(),
+            request_body_field: Some("body".to_string()),
+            header_params: vec![header("X-Tenant-Id", true)],
+            cookie_params: vec![header("session_id", false)],
+            ..Default::default()
+        };
+        let resolved_name = ResolvedTsName {
+            file_stem: "add".to_string(),
+            export_name: "add".to_string(),
+            builder_name: "buildAddSpec".to_string(),
+        };
+
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains(
+            "export function buildAddSpec(input: AddUserInput, headers: { xTenantId: string; sessionId?: string }): RequestSpec {"
+        ));
+        assert!(content.contains("    body: input,\n"));
+        assert!(content.contains("  const cookie = [[\"session_id\", headers.sessionId]].filter("));
+        assert!(content.contains(
+            "    headers: { \"X-Tenant-Id\": headers.xTenantId, ...(cookie ? { Cookie: cookie } : {}) },\n"
+        ));
+
+        let content = render_function_file(
+            &endpoint,
+            &resolved_name,
+            "functions/user/add.ts",
+            "../../../domains",
+            false,
+            &None,
+        );
+        assert!(content.contains(
+            "  input: AddUserInput,\n  headers: { xTenantId: string; sessionId?: string },\n  options?: PerCallOptions\n"
+        ));
+        assert!(content.contains("buildAddSpec(input, headers)"));
+
+        endpoint.input_type_name = "void".to_string();
+        endpoint.request_body_field = None;
+        endpoint.header_params[0].required = false;
+        endpoint.cookie_params.clear();
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains(
+            "export function buildAddSpec(headers?: { xTenantId?: string }): RequestSpec {"
+        ));
+        assert!(content.contains(
+            "    headers: { ...(headers?.xTenantId !== undefined ? { \"X-Tenant-Id\": headers?.xTenantId } : {}) },\n"
+        ));
+        let content = render_function_file(
+            &endpoint,
+            &resolved_name,
+            "functions/user/add.ts",
+            "../../../domains",
+            false,
+            &None,
+        );
+        assert!(content.contains("  headers?: { xTenantId?: string },\n"));
+        assert!(content.contains("buildAddSpec(headers)"));
+
+        // Names colliding after camelCasing get a suffix instead of a duplicate property
+        endpoint.header_params = vec![header("X-Id", true), header("x-id", false)];
+        endpoint.cookie_params = vec![header("xId", false)];
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains(
+            "export function buildAddSpec(headers: { xId: string; xId2?: string; xId3?: string }): RequestSpec {"
+        ));
+        assert!(content.contains(
+            "\"X-Id\": headers.xId, ...(headers.xId2 !== undefined ? { \"x-id\": headers.xId2 } : {})"
+        ));
+        assert!(content.contains("  const cookie = [[\"xId\", headers.xId3]]"));
+    }
+
+    #[test]
+    fn test_render_emits_one_shared_request_body_module() {
+        let upload = |name: &str| EndpointItem {
+            namespace: vec!["file".to_string()],
+            operation_name: name.to_string(),
+            export_name: name.to_string(),
+            builder_name: format!("build{name}Spec"),
+            method: "POST".to_string(),
+            path: format!("/file/{name}"),
+            input_type_name: "UploadInput".to_string(),
+            output_type_name: "void".to_string(),
+            request_body_field: Some("body".to_string()),
+            request_content_type: Some("multipart/form-data".to_string()),
+            ..Default::default()
+        };
+        let renderer = AptxFunctionsRenderer;
+        let output = renderer
+            .render(&make_generator_input(vec![
+                upload("upload"),
+                upload("replace"),
+            ]))
+            .unwrap();
+
+        let helpers = output
+            .files
+            .iter()
+            .filter(|file| file.path == "spec/requestBody.ts")
+            .collect::<Vec<_>>();
+        assert_eq!(helpers.len(), 1);
+        assert!(
+            helpers[0]
+                .content
+                .contains("export function toFormData(value: object): FormData {")
+        );
+        assert!(!helpers[0].content.contains("toURLSearchParams"));
+        assert!(
+            output
+                .files
+                .iter()
+                .filter(|file| file.path.starts_with("spec/file/"))
+                .all(|file| !file.content.contains("function toFormData"))
+        );
+
+        let output = renderer
+            .render(&make_generator_input(vec![EndpointItem {
+                request_content_type: None,
+                ..upload("save")
+            }]))
+            .unwrap();
+        assert!(
+            !output
+                .files
+                .iter()
+                .any(|file| file.path == "spec/requestBody.ts")
+        );
+    }
+
+    #[test]
+    fn test_render_spec_file_keeps_array_and_blob_bodies_with_headers() {
+        let mut endpoint = EndpointItem {
+            namespace: vec!["tag".to_string()],
+            operation_name: "save".to_string(),
+            export_name: "tagSave".to_string(),
+            builder_name: "buildTagSaveSpec".to_string(),
+            method: "PUT".to_string(),
+            path: "/tag/save".to_string(),
+            input_type_name: "Array<string>".to_string(),
+            output_type_name: "void".to_string(),
+            request_body_field: Some("body".to_string()),
+            header_params: vec![EndpointParameter {
+                name: "X-Tenant-Id".to_string(),
+                type_name: "string".to_string(),
+                required: true,
+                default: None,
+                example: None,
+            }],
+            ..Default::default()
+        };
+        let resolved_name = ResolvedTsName {
+            file_stem: "save".to_string(),
+            export_name: "save".to_string(),
+            builder_name: "buildSaveSpec".to_string(),
+        };
+
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains(
+            "export function buildSaveSpec(input: Array<string>, headers: { xTenantId: string }): RequestSpec {"
+        ));
+        assert!(content.contains("    body: input,\n"));
+        assert!(!content.contains("...body"));
+
+        endpoint.input_type_name = "Blob".to_string();
+        endpoint.request_content_type = Some("application/octet-stream".to_string());
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains(
+            "export function buildSaveSpec(input: Blob, headers: { xTenantId: string }): RequestSpec {"
+        ));
+        assert!(content.contains("    body: input,\n"));
+        assert!(!content.contains("...body"));
+    }
+
+    #[test]
+    fn test_render_spec_file_encodes_body_by_content_type() {
+        let mut endpoint = EndpointItem {
+            namespace: vec!["file".to_string()],
+            operation_name: "upload".to_string(),
+            export_name: "fileUpload".to_string(),
+            builder_name: "buildFileUploadSpec".to_string(),
+            method: "POST".to_string(),
+            path: "/file/upload".to_string(),
+            input_type_name: "UploadInput".to_string(),
+            output_type_name: "void".to_string(),
+            request_body_field: Some("body".to_string()),
+            request_content_type: Some("multipart/form-data".to_string()),
+            request_content_types: vec!["multipart/form-data".to_string()],
+            ..Default::default()
+        };
+        let resolved_name = ResolvedTsName {
+            file_stem: "upload".to_string(),
+            export_name: "upload".to_string(),
+            builder_name: "buildUploadSpec".to_string(),
+        };
+
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains("    body: toFormData(input),\n"));
+        assert!(!content.contains("Content-Type"));
+        assert!(content.contains("import { toFormData } from \"../requestBody\";"));
+        assert!(!content.contains("function toFormData"));
+
+        endpoint.request_content_type = Some("application/x-www-form-urlencoded".to_string());
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains("    body: toURLSearchParams(input),\n"));
+        assert!(content.contains("import { toURLSearchParams } from \"../requestBody\";"));
+
+        endpoint.request_content_type = Some("text/plain".to_string());
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains("    body: String(input),\n"));
+        assert!(content.contains("    headers: { \"Content-Type\": \"text/plain\" },\n"));
+
+        endpoint.request_content_type = Some("application/octet-stream".to_string());
+        endpoint.input_type_name = "Blob".to_string();
+        let content = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(content.contains("export function buildUploadSpec(input: Blob): RequestSpec {"));
+        assert!(content.contains("    body: input,\n"));
+        assert!(!content.contains("import type { Blob }"));
+        assert!(!content.contains("requestBody"));
+        assert!(
+            content.contains("    headers: { \"Content-Type\": \"application/octet-stream\" },\n")
+        );
+        assert!(!content.contains("responseType"));
+    }
+
+    #[test]
+    fn test_render_binary_response_as_blob() {
+        let endpoint = EndpointItem {
+            namespace: vec!["report".to_string()],
+            operation_name: "download".to_string(),
+            export_name: "reportDownload".to_string(),
+            builder_name: "buildReportDownloadSpec".to_string(),
+            method: "GET".to_string(),
+            path: "/report/download".to_string(),
+            input_type_name: "void".to_string(),
+            output_type_name: "Blob".to_string(),
+            binary_response: true,
+            ..Default::default()
+        };
+        let resolved_name = ResolvedTsName {
+            file_stem: "download".to_string(),
+            export_name: "reportDownload".to_string(),
+            builder_name: "buildReportDownloadSpec".to_string(),
+        };
+
+        let spec = render_spec_file(&endpoint, &resolved_name, "../../../domains", false);
+        assert!(spec.contains("    responseType: \"blob\",\n"));
+
+        let content = render_function_file(
+            &endpoint,
+            &resolved_name,
+            "functions/report/download.ts",
+            "../../../domains",
+            false,
+            &None,
+        );
+        assert!(content.contains("): Promise<Blob> {"));
+        assert!(content.contains(".execute<Blob>("));
+        assert!(!content.contains("import type { Blob }"));
+        assert!(!content.contains("executeRaw"));
+    }
+
+    #[test]
+    fn test_render_function_file_with_error_responses() {
+        let response = |status_code: &str, type_name: &str| EndpointResponse {
+            status_code: status_code.to_string(),
+            type_name: type_name.to_string(),
+        };
+        let endpoint = EndpointItem {
+            namespace: vec!["user".to_string()],
+            operation_name: "add".to_string(),
+            export_name: "userAdd".to_string(),
+            builder_name: "buildUserAddSpec".to_string(),
+            method: "POST".to_string(),
+            path: "/user/add".to_string(),
+            input_type_name: "AddUserInput".to_string(),
+            output_type_name: "User".to_string(),
+            error_responses: vec![
+                response("400", "ProblemDetails"),
+                response("404", "void"),
+                response("5XX", "ErrorResult"),
+                response("default", "ErrorResult"),
+            ],
+            request_body_field: Some("body".to_string()),
+            ..Default::default()
+        };
+
+        let content = render_function_file(
+            &endpoint,
+            &ResolvedTsName {
+                file_stem: "add".to_string(),
+                export_name: "userAdd".to_string(),
+                builder_name: "buildUserAddSpec".to_string(),
+            },
+            "functions/user/add.ts",
+            "../../../domains",
+            false,
+            &None,
+        );
+        assert!(
+            content.contains(
+                "import type { ProblemDetails } from \"../../../domains/ProblemDetails\";"
+            )
+        );
+        assert!(content.contains(
+            "export type UserAddError =\n  | { response: \"400\"; status: 400; body: ProblemDetails }\n  | { response: \"404\"; status: 404; body: undefined }\n  | { response: \"5XX\"; status: number; body: ErrorResult }\n  | { response: \"default\"; status: number; body: ErrorResult };\n"
+        ));
+        assert!(content.contains(
+            "const userAddErrorResponses: readonly string[] = [\"400\", \"404\", \"5XX\", \"default\"];"
+        ));
+        assert!(content.contains(
+            "export function toUserAddError(error: unknown): UserAddError | undefined {"
+        ));
+        assert!(content.contains("export async function userAdd(\n"));
+        assert!(content.contains(
+            "  try {\n    return await getApiClient().execute<User>(buildUserAddSpec(input), options);\n  } catch (error) {\n    throw toUserAddError(error) ?? error;\n  }\n"
+        ));
+    }
 }
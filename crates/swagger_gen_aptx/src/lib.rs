@@ -4,6 +4,7 @@
 //! - `AptxFunctionsRenderer`: Generates function-style API calls using @aptx/api-client
 //! - `AptxReactQueryRenderer`: Generates React Query hooks using @aptx/react-query
 //! - `AptxVueQueryRenderer`: Generates Vue Query composables using @aptx/vue-query
+//! - `AptxWebhooksRenderer`: Generates webhook/callback handler types and a dispatcher
 //! - `AptxQueryMutationPass`: Custom query/mutation classification for @aptx APIs
 //! - `AptxMetaPass`: Meta field configuration (e.g., skipAuthRefresh for refresh token endpoints)
 
@@ -30,10 +31,13 @@ mod query_base;
 mod react_query;
 mod ts_naming;
 mod vue_query;
+mod webhooks;
 
 pub use classifier::AptxQueryMutationPass;
 pub use functions::AptxFunctionsRenderer;
+pub(crate) use functions::{input_import_types, resolve_definition_input};
 pub use meta_pass::AptxMetaPass;
 pub use react_query::AptxReactQueryRenderer;
 pub(crate) use ts_naming::{ResolvedTsName, resolve_final_ts_names};
 pub use vue_query::AptxVueQueryRenderer;
+pub use webhooks::AptxWebhooksRenderer;
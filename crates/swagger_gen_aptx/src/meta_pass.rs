@@ -1,8 +1,8 @@
 //! Meta field configuration for @aptx code generation.
 //!
 //! This module provides `AptxMetaPass` which sets meta fields for specific endpoints
-//! based on naming patterns. For example, refresh token endpoints should skip the
-//! auth refresh middleware to avoid infinite loops.
+//! based on their security requirements and naming patterns. For example, refresh token
+//! endpoints should skip the auth refresh middleware to avoid infinite loops.
 
 use crate::META_SKIP_AUTH_REFRESH;
 use swagger_gen::pipeline::{EndpointItem, GeneratorInput, TransformPass};
@@ -11,7 +11,11 @@ use swagger_gen::pipeline::{EndpointItem, GeneratorInput, TransformPass};
 ///
 /// ## Rules
 ///
-/// 1. **Refresh Token endpoints** → `skipAuthRefresh: true`
+/// 1. **Endpoints without required auth** → `skipAuthRefresh: true`
+///    - Matches endpoints declaring `security: []` (or an empty `{}` alternative)
+///    - Example: login / anonymous endpoints
+/// 2. **Refresh Token endpoints** → `skipAuthRefresh: true`
+///    - Fallback for specs that don't declare security on these endpoints
 ///    - Matches endpoints whose operation_name or path ends with `RefreshToken`
 ///    - Example: `MainAPI/User/RefreshToken` → operation_name = `refreshToken`
 ///
@@ -29,7 +33,7 @@ impl TransformPass for AptxMetaPass {
 
     fn apply(&self, input: &mut GeneratorInput) -> Result<(), String> {
         for endpoint in &mut input.endpoints {
-            if is_refresh_token_endpoint(endpoint) {
+            if endpoint.is_auth_optional() || is_refresh_token_endpoint(endpoint) {
                 endpoint
                     .meta
                     .insert(META_SKIP_AUTH_REFRESH.to_string(), "true".to_string());
@@ -64,7 +68,6 @@ fn is_refresh_token_endpoint(endpoint: &EndpointItem) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indexmap::IndexMap;
 
     fn create_test_endpoint(operation_name: &str, path: &str) -> EndpointItem {
         EndpointItem {
@@ -72,19 +75,11 @@ mod tests {
             operation_name: operation_name.to_string(),
             export_name: format!("test{}", operation_name),
             builder_name: format!("buildTest{}Spec", operation_name),
-            summary: None,
             method: "POST".to_string(),
             path: path.to_string(),
             input_type_name: "void".to_string(),
             output_type_name: "void".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }
     }
 
@@ -153,6 +148,7 @@ mod tests {
             model_import: None,
             client_import: None,
             output_root: None,
+            webhooks: vec![],
         };
 
         pass.apply(&mut input).unwrap();
@@ -171,4 +167,58 @@ mod tests {
         // path ending with /RefreshToken should have META_SKIP_AUTH_REFRESH = true
         assert!(input.endpoints[2].meta.get(META_SKIP_AUTH_REFRESH) == Some(&"true".to_string()));
     }
+
+    #[test]
+    fn test_aptx_meta_pass_uses_security_requirements() {
+        use swagger_gen::pipeline::{EndpointSecurityRequirement, EndpointSecurityScheme};
+
+        let pass = AptxMetaPass;
+        let mut anonymous = create_test_endpoint("login", "/auth/login");
+        anonymous.security = Some(vec![]);
+        let mut protected = create_test_endpoint("getProfile", "/user/profile");
+        protected.security = Some(vec![EndpointSecurityRequirement {
+            schemes: vec![EndpointSecurityScheme {
+                name: "bearerAuth".to_string(),
+                scheme_type: Some("http".to_string()),
+                http_scheme: Some("bearer".to_string()),
+                scopes: vec![],
+            }],
+        }]);
+        let mut optional = create_test_endpoint("search", "/search");
+        optional.security = Some(vec![
+            EndpointSecurityRequirement { schemes: vec![] },
+            protected.security.as_ref().unwrap()[0].clone(),
+        ]);
+
+        let mut input = GeneratorInput {
+            project: swagger_gen::pipeline::ProjectContext {
+                package_name: "test".to_string(),
+                api_base_path: None,
+                terminals: vec![],
+                retry_ownership: None,
+            },
+            endpoints: vec![anonymous, protected, optional],
+            model_import: None,
+            client_import: None,
+            output_root: None,
+            webhooks: vec![],
+        };
+
+        pass.apply(&mut input).unwrap();
+
+        assert_eq!(
+            input.endpoints[0].meta.get(META_SKIP_AUTH_REFRESH),
+            Some(&"true".to_string())
+        );
+        assert!(
+            input.endpoints[1]
+                .meta
+                .get(META_SKIP_AUTH_REFRESH)
+                .is_none()
+        );
+        assert_eq!(
+            input.endpoints[2].meta.get(META_SKIP_AUTH_REFRESH),
+            Some(&"true".to_string())
+        );
+    }
 }
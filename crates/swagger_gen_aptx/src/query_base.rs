@@ -4,9 +4,10 @@
 
 use crate::META_SUPPORTS_QUERY;
 use crate::{
-    ResolvedTsName, get_client_call, get_client_import_lines, normalize_type_ref,
-    render_type_import_block, resolve_file_import_path, resolve_final_ts_names,
-    resolve_model_import_base, should_use_package_import,
+    ResolvedTsName, get_client_call, get_client_import_lines, input_import_types,
+    normalize_type_ref, render_type_import_block, resolve_definition_input,
+    resolve_file_import_path, resolve_final_ts_names, resolve_model_import_base,
+    should_use_package_import,
 };
 
 use swagger_gen::pipeline::{EndpointItem, GeneratorInput, PlannedFile, RenderOutput};
@@ -175,21 +176,16 @@ pub fn render_query_file(
         .join(", ");
     let key_prefix_array = format!("[{key_prefix}] as const");
 
-    let input_type = normalize_type_ref(&endpoint.input_type_name);
+    let (input_type, build_spec) = resolve_definition_input(endpoint, &builder);
     let output_type = normalize_type_ref(&endpoint.output_type_name);
-    let is_void_input = input_type == "void";
-    let input_import_types = if is_void_input {
-        vec![output_type.as_str()]
-    } else {
-        vec![input_type.as_str(), output_type.as_str()]
-    };
-    let type_imports =
-        render_type_import_block(&input_import_types, model_import_base, use_package);
-    let build_spec_line = if is_void_input {
-        format!("  buildSpec: () => {builder}(),\n")
-    } else {
-        format!("  buildSpec: {builder},\n")
-    };
+    let import_types = input_import_types(endpoint);
+    let import_types = import_types
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(output_type.as_str()))
+        .collect::<Vec<_>>();
+    let type_imports = render_type_import_block(&import_types, model_import_base, use_package);
+    let build_spec_line = format!("  buildSpec: {build_spec},\n");
 
     let client_import_lines = get_client_import_lines(client_import);
     let client_call = get_client_call(client_import);
@@ -238,21 +234,16 @@ pub fn render_mutation_file(
     );
     let mutation_def = format!("{}MutationDef", resolved_name.export_name);
 
-    let input_type = normalize_type_ref(&endpoint.input_type_name);
+    let (input_type, build_spec) = resolve_definition_input(endpoint, &builder);
     let output_type = normalize_type_ref(&endpoint.output_type_name);
-    let is_void_input = input_type == "void";
-    let input_import_types = if is_void_input {
-        vec![output_type.as_str()]
-    } else {
-        vec![input_type.as_str(), output_type.as_str()]
-    };
-    let type_imports =
-        render_type_import_block(&input_import_types, model_import_base, use_package);
-    let build_spec_line = if is_void_input {
-        format!("  buildSpec: () => {builder}(),\n")
-    } else {
-        format!("  buildSpec: {builder},\n")
-    };
+    let import_types = input_import_types(endpoint);
+    let import_types = import_types
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(output_type.as_str()))
+        .collect::<Vec<_>>();
+    let type_imports = render_type_import_block(&import_types, model_import_base, use_package);
+    let build_spec_line = format!("  buildSpec: {build_spec},\n");
 
     let client_import_lines = get_client_import_lines(client_import);
     let client_call = get_client_call(client_import);
@@ -301,6 +292,7 @@ mod tests {
             model_import: None,
             client_import: None,
             output_root: None,
+            webhooks: vec![],
         }
     }
 
@@ -365,19 +357,12 @@ mod tests {
             operation_name: "fetchOne".to_string(),
             export_name: "itemFetchOne".to_string(),
             builder_name: "buildItemFetchOneSpec".to_string(),
-            summary: None,
             method: "GET".to_string(),
             path: "/group/item".to_string(),
             input_type_name: "FetchOneInput".to_string(),
             output_type_name: "FetchOneOutput".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
             meta,
+            ..Default::default()
         };
 
         let content = render_query_file(
@@ -406,19 +391,11 @@ mod tests {
             operation_name: "add".to_string(),
             export_name: "assignmentAdd".to_string(),
             builder_name: "buildAssignmentAddSpec".to_string(),
-            summary: None,
             method: "POST".to_string(),
             path: "/assignment/add".to_string(),
             input_type_name: "AddAssignmentRequestModel".to_string(),
             output_type_name: "GuidResultModel".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         };
 
         let content = render_mutation_file(
@@ -438,6 +415,50 @@ mod tests {
         assert!(!content.contains("\n\n\n"));
     }
 
+    #[test]
+    fn test_render_mutation_file_passes_headers_separately() {
+        let endpoint = EndpointItem {
+            namespace: vec!["assignment".to_string()],
+            operation_name: "add".to_string(),
+            export_name: "assignmentAdd".to_string(),
+            builder_name: "buildAssignmentAddSpec".to_string(),
+            method: "POST".to_string(),
+            path: "/assignment/add".to_string(),
+            input_type_name: "AddAssignmentRequestModel".to_string(),
+            output_type_name: "GuidResultModel".to_string(),
+            header_params: vec![swagger_gen::pipeline::EndpointParameter {
+                name: "X-Tenant-Id".to_string(),
+                type_name: "string".to_string(),
+                required: true,
+                default: None,
+                example: None,
+            }],
+            ..Default::default()
+        };
+
+        let content = render_mutation_file(
+            &endpoint,
+            &ResolvedTsName {
+                file_stem: "add".to_string(),
+                export_name: "add".to_string(),
+                builder_name: "buildAddSpec".to_string(),
+            },
+            QueryTerminal::React,
+            "react-query/assignment/add.mutation.ts",
+            "../../domains",
+            false,
+            &None,
+        );
+
+        assert!(content.contains(
+            "createMutationDefinition<{ input: AddAssignmentRequestModel; headers: { xTenantId: string } }, GuidResultModel>"
+        ));
+        assert!(
+            content
+                .contains("  buildSpec: ({ input, headers }) => buildAddSpec(input, headers),\n")
+        );
+    }
+
     #[test]
     fn test_render_query_terminal_uses_namespace_prefixed_query_names() {
         let mut meta = IndexMap::new();
@@ -448,19 +469,12 @@ mod tests {
             operation_name: "getAuthorityAPIUserGetLoginUserInfo".to_string(),
             export_name: "userGetLoginUserInfo".to_string(),
             builder_name: "buildUserGetLoginUserInfoSpec".to_string(),
-            summary: None,
             method: "GET".to_string(),
             path: "/AuthorityAPI/User/GetLoginUserInfo".to_string(),
             input_type_name: "void".to_string(),
             output_type_name: "LoginUserInfo".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
             meta,
+            ..Default::default()
         }]);
 
         let output = render_query_terminal(&input, QueryTerminal::React).unwrap();
@@ -490,19 +504,11 @@ mod tests {
             operation_name: "postAuthorityAPIActionAuthorityAdd".to_string(),
             export_name: "actionAuthorityAdd".to_string(),
             builder_name: "buildActionAuthorityAddSpec".to_string(),
-            summary: None,
             method: "POST".to_string(),
             path: "/AuthorityAPI/ActionAuthority/Add".to_string(),
             input_type_name: "AddActionAuthorityRequestModel".to_string(),
             output_type_name: "GuidResultModel".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }]);
 
         let output = render_query_terminal(&input, QueryTerminal::React).unwrap();
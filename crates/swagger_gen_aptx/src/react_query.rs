@@ -48,6 +48,7 @@ mod tests {
             model_import: None,
             client_import: None,
             output_root: None,
+            webhooks: vec![],
         };
 
         let result = renderer.render(&input).unwrap();
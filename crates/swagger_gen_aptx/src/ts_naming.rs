@@ -363,7 +363,6 @@ fn sanitize_reserved(value: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indexmap::IndexMap;
     use swagger_gen::pipeline::EndpointItem;
 
     fn make_endpoint(
@@ -380,19 +379,11 @@ mod tests {
             operation_name: operation_name.to_string(),
             export_name: export_name.to_string(),
             builder_name: format!("build{}Spec", operation_name),
-            summary: None,
             method: method.to_string(),
             path: "/test".to_string(),
             input_type_name: "void".to_string(),
             output_type_name: "void".to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }
     }
 
@@ -0,0 +1,258 @@
+//! Webhooks renderer
+//!
+//! Generates payload types, typed handler signatures and a dispatcher for the
+//! webhooks and callbacks an API sends to the client (e.g. a Node BFF).
+
+use inflector::cases::pascalcase::to_pascal_case;
+
+use crate::{
+    normalize_type_ref, render_type_import_block, resolve_model_import_base,
+    should_use_package_import,
+};
+
+use swagger_gen::pipeline::{
+    GeneratorInput, PlannedFile, RenderOutput, Renderer, WebhookItem, WebhookKind,
+};
+
+const WEBHOOKS_FILE_PATH: &str = "webhooks/index.ts";
+
+/// Webhooks renderer
+///
+/// Generates `webhooks/index.ts` containing:
+/// - `WebhookPayloads` / `WebhookResponses` maps keyed by event name
+/// - One handler type per webhook or callback
+/// - `createWebhookDispatcher` which routes an event to its handler
+#[derive(Default)]
+pub struct AptxWebhooksRenderer;
+
+impl Renderer for AptxWebhooksRenderer {
+    fn id(&self) -> &'static str {
+        "aptx-webhooks"
+    }
+
+    fn render(&self, input: &GeneratorInput) -> Result<RenderOutput, String> {
+        if input.webhooks.is_empty() {
+            return Ok(RenderOutput {
+                files: vec![],
+                warnings: vec!["no webhooks or callbacks declared in the spec".to_string()],
+            });
+        }
+
+        let model_import_base = resolve_model_import_base(input, WEBHOOKS_FILE_PATH);
+        let use_package = should_use_package_import(&input.model_import);
+        Ok(RenderOutput {
+            files: vec![PlannedFile {
+                path: WEBHOOKS_FILE_PATH.to_string(),
+                content: render_webhooks_file(&input.webhooks, &model_import_base, use_package),
+            }],
+            warnings: vec![],
+        })
+    }
+}
+
+fn render_webhooks_file(
+    webhooks: &[WebhookItem],
+    model_import_base: &str,
+    use_package: bool,
+) -> String {
+    let payload_types = webhooks
+        .iter()
+        .map(|item| normalize_type_ref(&item.payload_type_name))
+        .collect::<Vec<_>>();
+    let response_types = webhooks
+        .iter()
+        .map(|item| normalize_type_ref(&item.response_type_name))
+        .collect::<Vec<_>>();
+    let type_names = payload_types
+        .iter()
+        .chain(response_types.iter())
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let type_imports = render_type_import_block(&type_names, model_import_base, use_package);
+
+    let mut payload_fields = String::new();
+    let mut response_fields = String::new();
+    let mut handler_types = String::new();
+    for ((item, payload_type), response_type) in webhooks
+        .iter()
+        .zip(payload_types.iter())
+        .zip(response_types.iter())
+    {
+        let doc = render_doc_comment(item);
+        payload_fields.push_str(&format!("{doc}  \"{}\": {payload_type};\n", item.event));
+        response_fields.push_str(&format!("  \"{}\": {response_type};\n", item.event));
+        handler_types.push_str(&format!(
+            "{}export type {} = WebhookHandler<\"{}\">;\n",
+            doc.replace("\n  ", "\n").trim_start(),
+            handler_type_name(item),
+            item.event
+        ));
+    }
+    let events = webhooks
+        .iter()
+        .map(|item| format!("  \"{}\",\n", item.event))
+        .collect::<String>();
+
+    let type_import_block = if type_imports.is_empty() {
+        String::new()
+    } else {
+        format!("{type_imports}\n")
+    };
+
+    format!(
+        r#"{type_import_block}/** Payload the API sends for each webhook or callback, keyed by event. */
+export interface WebhookPayloads {{
+{payload_fields}}}
+
+/** Response the API expects back from each handler, keyed by event. */
+export interface WebhookResponses {{
+{response_fields}}}
+
+export type WebhookEvent = keyof WebhookPayloads;
+
+export const WEBHOOK_EVENTS: readonly WebhookEvent[] = [
+{events}];
+
+export type WebhookHandler<E extends WebhookEvent> = (
+  payload: WebhookPayloads[E],
+) => WebhookResponses[E] | Promise<WebhookResponses[E]>;
+
+export type WebhookHandlers = {{
+  [E in WebhookEvent]?: WebhookHandler<E>;
+}};
+
+{handler_types}
+export function isWebhookEvent(event: string): event is WebhookEvent {{
+  return (WEBHOOK_EVENTS as readonly string[]).includes(event);
+}}
+
+export function createWebhookDispatcher(handlers: WebhookHandlers) {{
+  return async function dispatch<E extends WebhookEvent>(
+    event: E,
+    payload: WebhookPayloads[E],
+  ): Promise<WebhookResponses[E]> {{
+    const handler = handlers[event] as WebhookHandler<E> | undefined;
+    if (!handler) {{
+      throw new Error(`No handler registered for webhook "${{event}}"`);
+    }}
+    return handler(payload);
+  }};
+}}
+"#
+    )
+}
+
+fn handler_type_name(item: &WebhookItem) -> String {
+    let suffix = match item.kind {
+        WebhookKind::Webhook => "WebhookHandler",
+        WebhookKind::Callback => "CallbackHandler",
+    };
+    format!("{}{suffix}", to_pascal_case(&item.operation_name))
+}
+
+fn render_doc_comment(item: &WebhookItem) -> String {
+    let mut lines = Vec::new();
+    if let Some(summary) = item
+        .summary
+        .as_deref()
+        .filter(|text| !text.trim().is_empty())
+    {
+        lines.push(summary.trim().replace("*/", "*\\/"));
+    }
+    let source = match &item.source_operation {
+        Some(operation) => format!(" (callback of `{operation}`)"),
+        None => String::new(),
+    };
+    lines.push(format!("{} {}{source}", item.method, item.path));
+    if item.deprecated {
+        lines.push("@deprecated".to_string());
+    }
+    let body = lines
+        .iter()
+        .map(|line| format!("   * {line}\n"))
+        .collect::<String>();
+    format!("  /**\n{body}   */\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swagger_gen::pipeline::{ModelImportConfig, ProjectContext};
+
+    fn make_webhook(kind: WebhookKind, event: &str, operation_name: &str) -> WebhookItem {
+        WebhookItem {
+            kind,
+            event: event.to_string(),
+            namespace: vec!["default".to_string()],
+            operation_name: operation_name.to_string(),
+            summary: Some("Pet created".to_string()),
+            method: "POST".to_string(),
+            path: "newPet".to_string(),
+            payload_type_name: "Pet".to_string(),
+            response_type_name: "void".to_string(),
+            source_operation: None,
+            deprecated: false,
+            payload_example: None,
+        }
+    }
+
+    fn make_generator_input(webhooks: Vec<WebhookItem>) -> GeneratorInput {
+        GeneratorInput {
+            project: ProjectContext {
+                package_name: "test".to_string(),
+                api_base_path: None,
+                terminals: vec![],
+                retry_ownership: None,
+            },
+            endpoints: vec![],
+            model_import: None,
+            client_import: None,
+            output_root: None,
+            webhooks,
+        }
+    }
+
+    #[test]
+    fn test_render_webhooks_file() {
+        let mut callback = make_webhook(WebhookKind::Callback, "petAdd.onDone", "petAddOnDone");
+        callback.summary = None;
+        callback.path = "{$request.body#/callbackUrl}".to_string();
+        callback.payload_type_name = "Order".to_string();
+        callback.source_operation = Some("petAdd".to_string());
+        let mut input = make_generator_input(vec![
+            make_webhook(WebhookKind::Webhook, "newPet", "newPet"),
+            callback,
+        ]);
+        input.model_import = Some(ModelImportConfig {
+            import_type: "package".to_string(),
+            package_path: Some("@my-org/models".to_string()),
+            relative_path: None,
+            original_path: None,
+        });
+
+        let output = AptxWebhooksRenderer.render(&input).unwrap();
+        assert_eq!(output.files.len(), 1);
+        assert_eq!(output.files[0].path, "webhooks/index.ts");
+
+        let content = &output.files[0].content;
+        assert!(content.contains("import type { Pet } from \"@my-org/models\";"));
+        assert!(content.contains("import type { Order } from \"@my-org/models\";"));
+        assert!(content.contains("  \"newPet\": Pet;\n"));
+        assert!(content.contains("  \"petAdd.onDone\": Order;\n"));
+        assert!(content.contains("export type NewPetWebhookHandler = WebhookHandler<\"newPet\">;"));
+        assert!(content.contains(
+            "export type PetAddOnDoneCallbackHandler = WebhookHandler<\"petAdd.onDone\">;"
+        ));
+        assert!(content.contains("* POST {$request.body#/callbackUrl} (callback of `petAdd`)"));
+        assert!(content.contains("export function createWebhookDispatcher("));
+    }
+
+    #[test]
+    fn test_render_without_webhooks_warns() {
+        let output = AptxWebhooksRenderer
+            .render(&make_generator_input(vec![]))
+            .unwrap();
+        assert!(output.files.is_empty());
+        assert_eq!(output.warnings.len(), 1);
+    }
+}
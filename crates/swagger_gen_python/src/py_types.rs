@@ -1,5 +1,6 @@
 //! ModelType -> Python type string mapping and import collection.
 
+use serde_json::Value;
 use swagger_gen::model_pipeline::{ModelLiteral, ModelType, NumberFormat, NumberSpec, ScalarType};
 
 /// Render a ModelType to a Python type string.
@@ -9,7 +10,7 @@ pub fn render_python_type(model_type: &ModelType) -> String {
         ModelType::String => "str".to_string(),
         ModelType::Number => "float".to_string(),
         ModelType::Boolean => "bool".to_string(),
-        ModelType::Object => "dict[str, Any]".to_string(),
+        ModelType::Object | ModelType::InlineObject { .. } => "dict[str, Any]".to_string(),
         ModelType::Ref { name } => name.to_string(),
         ModelType::Array { item } => {
             let inner = render_python_type(item);
@@ -25,6 +26,31 @@ pub fn render_python_type(model_type: &ModelType) -> String {
             }
             rendered_variants.join(" | ")
         }
+        // Python typing has no intersection: a single base is the type itself, several
+        // bases are hoisted into a subclass by the model renderer, and members that
+        // can't be inherited fall back to the first one
+        ModelType::Intersection { variants } => match intersection_bases(variants) {
+            Some(bases) if bases.len() == 1 => bases[0].clone(),
+            _ => variants
+                .first()
+                .map(render_python_type)
+                .unwrap_or_else(|| "Any".to_string()),
+        },
+        ModelType::Unknown => "Any".to_string(),
+        ModelType::Discriminated {
+            property_name,
+            variants,
+        } => {
+            let rendered_variants = variants
+                .iter()
+                .map(|variant| render_python_type(&variant.r#type))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "Annotated[Union[{rendered_variants}], Field(discriminator=\"{}\")]",
+                to_snake_case(property_name)
+            )
+        }
         ModelType::Literal { value } => match value {
             ModelLiteral::String { value } => format!("Literal[\"{value}\"]"),
             ModelLiteral::Integer { value } => format!("Literal[{value}]"),
@@ -52,6 +78,25 @@ fn render_python_scalar_type(scalar: &ScalarType) -> String {
     }
 }
 
+/// Model names an intersection can inherit from, in declaration order. Object
+/// members have no class to inherit and are skipped; returns `None` when any other
+/// member can't be expressed as a base class.
+pub fn intersection_bases(variants: &[ModelType]) -> Option<Vec<String>> {
+    let mut bases: Vec<String> = Vec::new();
+    for variant in variants {
+        match variant {
+            ModelType::Ref { name } => {
+                if !bases.contains(name) {
+                    bases.push(name.clone());
+                }
+            }
+            ModelType::Object | ModelType::InlineObject { .. } | ModelType::Unknown => {}
+            _ => return None,
+        }
+    }
+    (!bases.is_empty()).then_some(bases)
+}
+
 /// Render a ModelType with nullable support.
 pub fn render_python_type_nullable(model_type: &ModelType, nullable: bool) -> String {
     let base = render_python_type(model_type);
@@ -74,11 +119,22 @@ pub fn collect_python_imports(model_type: &ModelType) -> Vec<String> {
 fn collect_imports_recursive(model_type: &ModelType, imports: &mut Vec<String>) {
     match model_type {
         ModelType::Scalar(scalar) => collect_imports_for_scalar(scalar, imports),
-        ModelType::Object => {
-            if !imports.contains(&"from typing import Any".to_string()) {
-                imports.push("from typing import Any".to_string());
-            }
+        ModelType::Object | ModelType::InlineObject { .. } | ModelType::Unknown
+            if !imports.contains(&"from typing import Any".to_string()) =>
+        {
+            imports.push("from typing import Any".to_string());
         }
+        ModelType::Intersection { variants } => match intersection_bases(variants) {
+            Some(_) => {}
+            None => match variants.first() {
+                Some(first) => collect_imports_recursive(first, imports),
+                None => {
+                    if !imports.contains(&"from typing import Any".to_string()) {
+                        imports.push("from typing import Any".to_string());
+                    }
+                }
+            },
+        },
         ModelType::Array { item } => {
             collect_imports_recursive(item, imports);
         }
@@ -87,6 +143,20 @@ fn collect_imports_recursive(model_type: &ModelType, imports: &mut Vec<String>)
                 collect_imports_recursive(v, imports);
             }
         }
+        ModelType::Discriminated { variants, .. } => {
+            for import in [
+                "from typing import Annotated",
+                "from typing import Union",
+                "from pydantic import Field",
+            ] {
+                if !imports.contains(&import.to_string()) {
+                    imports.push(import.to_string());
+                }
+            }
+            for variant in variants {
+                collect_imports_recursive(&variant.r#type, imports);
+            }
+        }
         ModelType::Literal { value } => {
             collect_imports_for_literal(model_type, imports);
             if matches!(
@@ -128,6 +198,37 @@ fn collect_imports_for_literal(model_type: &ModelType, imports: &mut Vec<String>
     }
 }
 
+/// Render a JSON value (e.g. a schema `default`) as a Python literal.
+pub fn render_python_literal(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(number) => number.to_string(),
+        // JSON string escapes are valid Python string escapes
+        Value::String(text) => Value::String(text.clone()).to_string(),
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(render_python_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(key, item)| format!(
+                    "{}: {}",
+                    Value::String(key.clone()),
+                    render_python_literal(item)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
 /// Convert a camelCase/PascalCase name to snake_case.
 pub fn to_snake_case(name: &str) -> String {
     let mut result = String::new();
@@ -286,6 +387,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_intersection_with_single_base_renders_that_base() {
+        assert_eq!(
+            render_python_type(&ModelType::Intersection {
+                variants: vec![
+                    ModelType::Ref {
+                        name: "Base".to_string()
+                    },
+                    ModelType::Object,
+                ]
+            }),
+            "Base"
+        );
+    }
+
+    #[test]
+    fn test_intersection_bases_collects_every_ref() {
+        let variants = vec![
+            ModelType::Ref {
+                name: "Audit".to_string(),
+            },
+            ModelType::Object,
+            ModelType::Ref {
+                name: "Address".to_string(),
+            },
+        ];
+        assert_eq!(
+            intersection_bases(&variants),
+            Some(vec!["Audit".to_string(), "Address".to_string()])
+        );
+        assert_eq!(
+            intersection_bases(&[
+                ModelType::Ref {
+                    name: "Audit".to_string()
+                },
+                ModelType::String,
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_discriminated_union_type() {
+        let model_type = ModelType::Discriminated {
+            property_name: "petType".to_string(),
+            variants: vec![
+                swagger_gen::model_pipeline::ModelDiscriminatedVariant {
+                    tags: vec!["dog".to_string()],
+                    r#type: ModelType::Ref {
+                        name: "Dog".to_string(),
+                    },
+                },
+                swagger_gen::model_pipeline::ModelDiscriminatedVariant {
+                    tags: vec!["cat".to_string()],
+                    r#type: ModelType::Ref {
+                        name: "Cat".to_string(),
+                    },
+                },
+            ],
+        };
+        assert_eq!(
+            render_python_type(&model_type),
+            "Annotated[Union[Dog, Cat], Field(discriminator=\"pet_type\")]"
+        );
+        let imports = collect_python_imports(&model_type);
+        assert!(imports.contains(&"from typing import Annotated".to_string()));
+        assert!(imports.contains(&"from pydantic import Field".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_type() {
+        assert_eq!(render_python_type(&ModelType::Unknown), "Any");
+        let imports = collect_python_imports(&ModelType::Unknown);
+        assert!(imports.contains(&"from typing import Any".to_string()));
+    }
+
     #[test]
     fn test_nullable() {
         assert_eq!(
@@ -349,4 +526,13 @@ mod tests {
         assert_eq!(to_snake_case("already_snake"), "already_snake");
         assert_eq!(to_snake_case("HTTPSUrl"), "h_t_t_p_s_url");
     }
+
+    #[test]
+    fn test_render_python_literal() {
+        let value = serde_json::json!({"name": "a\"b", "tags": [1, 2.5, true, null]});
+        assert_eq!(
+            render_python_literal(&value),
+            r#"{"name": "a\"b", "tags": [1, 2.5, True, None]}"#
+        );
+    }
 }
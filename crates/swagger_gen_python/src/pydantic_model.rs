@@ -2,12 +2,17 @@
 
 use std::collections::HashMap;
 
+use serde_json::Value;
+
 use swagger_gen::model_pipeline::{
     ModelEnumMember, ModelIr, ModelKind, ModelLiteral, ModelNode, ModelProperty, ModelType,
     NumberFormat, ScalarType,
 };
 
-use crate::py_types::{collect_python_imports, render_python_type_nullable, to_snake_case};
+use crate::py_types::{
+    collect_python_imports, intersection_bases, render_python_literal, render_python_type_nullable,
+    to_snake_case,
+};
 
 /// Render all models in the IR to Pydantic Python files.
 /// Returns a map of filename -> file content.
@@ -38,11 +43,27 @@ pub fn render_pydantic_models(ir: &ModelIr) -> Result<HashMap<String, String>, S
 fn render_single_model(model: &ModelNode) -> Result<String, String> {
     let mut imports = vec!["from __future__ import annotations".to_string()];
 
-    let body = match &model.kind {
-        ModelKind::Interface { properties } => render_interface(model, properties, &mut imports),
-        ModelKind::Enum { members } => render_enum(model, members, &mut imports),
-        ModelKind::Alias { target, nullable } => {
-            render_alias(model, target, *nullable, &mut imports)
+    // An alias of several intersected models becomes a subclass of all of them
+    let alias_bases = match &model.kind {
+        ModelKind::Alias {
+            target: ModelType::Intersection { variants },
+            nullable: false,
+        } => intersection_bases(variants).filter(|bases| bases.len() > 1),
+        _ => None,
+    };
+
+    let body = if let Some(bases) = &alias_bases {
+        render_interface(model, &[], bases, &mut imports)
+    } else {
+        match &model.kind {
+            ModelKind::Interface {
+                properties,
+                extends,
+            } => render_interface(model, properties, extends, &mut imports),
+            ModelKind::Enum { members } => render_enum(model, members, &mut imports),
+            ModelKind::Alias { target, nullable } => {
+                render_alias(model, target, *nullable, &mut imports)
+            }
         }
     };
 
@@ -62,7 +83,7 @@ fn render_single_model(model: &ModelNode) -> Result<String, String> {
     }
 
     // Always need these for interface models
-    if matches!(model.kind, ModelKind::Interface { .. }) {
+    if matches!(model.kind, ModelKind::Interface { .. }) || alias_bases.is_some() {
         let pydantic_imports = vec!["from pydantic import BaseModel, ConfigDict, Field"];
         for imp in pydantic_imports {
             if !imports.contains(&imp.to_string()) {
@@ -82,21 +103,44 @@ fn render_single_model(model: &ModelNode) -> Result<String, String> {
 fn render_interface(
     model: &ModelNode,
     properties: &[ModelProperty],
+    extends: &[String],
     _imports: &mut Vec<String>,
 ) -> String {
-    let mut lines = vec![format!("class {}(BaseModel):", model.name)];
+    let bases = if extends.is_empty() {
+        "BaseModel".to_string()
+    } else {
+        extends.join(", ")
+    };
+    let mut lines = vec![format!("class {}({}):", model.name, bases)];
     lines.push("    model_config = ConfigDict(populate_by_name=True)".to_string());
     lines.push(String::new());
 
+    let mut helpers = Vec::new();
     if properties.is_empty() {
         lines.push("    pass".to_string());
     } else {
         for prop in properties {
             let snake = to_snake_case(&prop.name);
-            let py_type =
-                render_python_type_nullable(&prop.r#type, prop.nullable || !prop.required);
-
-            if prop.required && !prop.nullable {
+            let prop_type = hoist_intersections(
+                &prop.r#type,
+                &format!("{}{}", model.name, to_pascal_case(&prop.name)),
+                &mut helpers,
+            );
+            let py_type = render_python_type_nullable(&prop_type, prop.nullable || !prop.required);
+
+            if let Some(default) = prop.default.as_ref().filter(|value| !value.is_null()) {
+                // Mutable defaults must be created per instance
+                let default = match default {
+                    Value::Array(_) | Value::Object(_) => {
+                        format!("default_factory=lambda: {}", render_python_literal(default))
+                    }
+                    _ => format!("default={}", render_python_literal(default)),
+                };
+                lines.push(format!(
+                    "    {}: {} = Field({}, alias=\"{}\")",
+                    snake, py_type, default, prop.name
+                ));
+            } else if prop.required && !prop.nullable {
                 lines.push(format!(
                     "    {}: {} = Field(alias=\"{}\")",
                     snake, py_type, prop.name
@@ -110,7 +154,67 @@ fn render_interface(
         }
     }
 
-    lines.join("\n")
+    // Classes standing in for property-level intersections go before the model
+    let mut blocks = helpers
+        .iter()
+        .map(|(name, bases)| {
+            format!(
+                "class {name}({}):\n    model_config = ConfigDict(populate_by_name=True)\n\n    pass",
+                bases.join(", ")
+            )
+        })
+        .collect::<Vec<_>>();
+    blocks.push(lines.join("\n"));
+    blocks.join("\n\n\n")
+}
+
+/// Replaces intersections of several models inside a property type with a
+/// subclass inheriting all of them; the subclasses are collected in `helpers`.
+fn hoist_intersections(
+    model_type: &ModelType,
+    class_name: &str,
+    helpers: &mut Vec<(String, Vec<String>)>,
+) -> ModelType {
+    match model_type {
+        ModelType::Intersection { variants } => {
+            match intersection_bases(variants).filter(|bases| bases.len() > 1) {
+                Some(bases) => {
+                    let mut name = class_name.to_string();
+                    let mut index = 2;
+                    while helpers.iter().any(|(existing, _)| existing == &name) {
+                        name = format!("{class_name}{index}");
+                        index += 1;
+                    }
+                    helpers.push((name.clone(), bases));
+                    ModelType::Ref { name }
+                }
+                None => model_type.clone(),
+            }
+        }
+        ModelType::Array { item } => ModelType::Array {
+            item: Box::new(hoist_intersections(item, class_name, helpers)),
+        },
+        ModelType::Union { variants } => ModelType::Union {
+            variants: variants
+                .iter()
+                .map(|variant| hoist_intersections(variant, class_name, helpers))
+                .collect(),
+        },
+        _ => model_type.clone(),
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
 }
 
 fn render_enum(
@@ -173,7 +277,7 @@ fn render_alias(
 
 fn collect_model_imports(model: &ModelNode) -> Vec<String> {
     match &model.kind {
-        ModelKind::Interface { properties } => {
+        ModelKind::Interface { properties, .. } => {
             let mut imports = Vec::new();
             for prop in properties {
                 let mut prop_imports = collect_python_imports(&prop.r#type);
@@ -194,7 +298,11 @@ fn collect_model_ref_imports(model: &ModelNode) -> Vec<String> {
     let mut refs = Vec::new();
 
     match &model.kind {
-        ModelKind::Interface { properties } => {
+        ModelKind::Interface {
+            properties,
+            extends,
+        } => {
+            refs.extend(extends.iter().cloned());
             for prop in properties {
                 collect_model_refs_recursive(&prop.r#type, &mut refs);
             }
@@ -221,6 +329,16 @@ fn collect_model_refs_recursive(model_type: &ModelType, refs: &mut Vec<String>)
                 collect_model_refs_recursive(variant, refs);
             }
         }
+        ModelType::Intersection { variants } => {
+            for variant in variants {
+                collect_model_refs_recursive(variant, refs);
+            }
+        }
+        ModelType::Discriminated { variants, .. } => {
+            for variant in variants {
+                collect_model_refs_recursive(&variant.r#type, refs);
+            }
+        }
         ModelType::Scalar(ScalarType::String)
         | ModelType::Scalar(ScalarType::Boolean)
         | ModelType::Scalar(ScalarType::Integer(_))
@@ -229,7 +347,9 @@ fn collect_model_refs_recursive(model_type: &ModelType, refs: &mut Vec<String>)
         | ModelType::Number
         | ModelType::Boolean
         | ModelType::Object
-        | ModelType::Literal { .. } => {}
+        | ModelType::InlineObject { .. }
+        | ModelType::Literal { .. }
+        | ModelType::Unknown => {}
     }
 }
 
@@ -241,7 +361,10 @@ mod tests {
         ModelNode {
             name: name.to_string(),
             description: None,
-            kind: ModelKind::Interface { properties },
+            kind: ModelKind::Interface {
+                properties,
+                extends: Vec::new(),
+            },
         }
     }
 
@@ -272,6 +395,8 @@ mod tests {
                     required: true,
                     nullable: false,
                     r#type: ModelType::String,
+                    default: None,
+                    example: None,
                 },
                 ModelProperty {
                     name: "email".to_string(),
@@ -279,6 +404,8 @@ mod tests {
                     required: false,
                     nullable: true,
                     r#type: ModelType::String,
+                    default: None,
+                    example: None,
                 },
             ],
         );
@@ -291,6 +418,31 @@ mod tests {
         assert!(content.contains("from pydantic import BaseModel, ConfigDict, Field"));
     }
 
+    #[test]
+    fn test_interface_extends_renders_subclass() {
+        let model = ModelNode {
+            name: "Dog".to_string(),
+            description: None,
+            kind: ModelKind::Interface {
+                properties: vec![ModelProperty {
+                    name: "bark".to_string(),
+                    description: None,
+                    required: true,
+                    nullable: false,
+                    r#type: ModelType::String,
+                    default: None,
+                    example: None,
+                }],
+                extends: vec!["Animal".to_string()],
+            },
+        };
+
+        let content = render_single_model(&model).unwrap();
+        assert!(content.contains("class Dog(Animal):"));
+        assert!(content.contains("from .Animal import Animal"));
+        assert!(content.contains("bark: str = Field(alias=\"bark\")"));
+    }
+
     #[test]
     fn test_enum_str_rendering() {
         let model = make_enum_model(
@@ -439,6 +591,8 @@ mod tests {
                     required: true,
                     nullable: false,
                     r#type: ModelType::String,
+                    default: None,
+                    example: None,
                 },
                 ModelProperty {
                     name: "lastName".to_string(),
@@ -446,6 +600,8 @@ mod tests {
                     required: true,
                     nullable: false,
                     r#type: ModelType::String,
+                    default: None,
+                    example: None,
                 },
             ],
         );
@@ -469,6 +625,8 @@ mod tests {
                         format: NumberFormat::Float,
                     },
                 )),
+                default: None,
+                example: None,
             }],
         );
 
@@ -476,6 +634,39 @@ mod tests {
         assert!(content.contains("timeout: float | None = Field(default=None, alias=\"timeout\")"));
     }
 
+    #[test]
+    fn test_field_with_schema_default() {
+        let model = make_interface_model(
+            "Query",
+            vec![
+                ModelProperty {
+                    name: "pageSize".to_string(),
+                    description: None,
+                    required: true,
+                    nullable: false,
+                    r#type: ModelType::String,
+                    default: Some(serde_json::json!("20")),
+                    example: None,
+                },
+                ModelProperty {
+                    name: "tags".to_string(),
+                    description: None,
+                    required: false,
+                    nullable: false,
+                    r#type: ModelType::Array {
+                        item: Box::new(ModelType::String),
+                    },
+                    default: Some(serde_json::json!(["a"])),
+                    example: None,
+                },
+            ],
+        );
+
+        let content = render_single_model(&model).unwrap();
+        assert!(content.contains("page_size: str = Field(default=\"20\", alias=\"pageSize\")"));
+        assert!(content.contains("= Field(default_factory=lambda: [\"a\"], alias=\"tags\")"));
+    }
+
     #[test]
     fn test_integer_field_renders_as_int() {
         let model = make_interface_model(
@@ -490,6 +681,8 @@ mod tests {
                         format: swagger_gen::model_pipeline::IntegerFormat::Int64,
                     },
                 )),
+                default: None,
+                example: None,
             }],
         );
 
@@ -511,6 +704,8 @@ mod tests {
                         format: NumberFormat::Double,
                     },
                 )),
+                default: None,
+                example: None,
             }],
         );
 
@@ -532,6 +727,8 @@ mod tests {
                         format: NumberFormat::Decimal,
                     },
                 )),
+                default: None,
+                example: None,
             }],
         );
 
@@ -550,6 +747,8 @@ mod tests {
                 required: true,
                 nullable: false,
                 r#type: ModelType::Object,
+                default: None,
+                example: None,
             }],
         );
 
@@ -570,6 +769,8 @@ mod tests {
                     r#type: ModelType::Ref {
                         name: "Status".to_string(),
                     },
+                    default: None,
+                    example: None,
                 },
                 ModelProperty {
                     name: "roles".to_string(),
@@ -581,6 +782,8 @@ mod tests {
                             name: "Role".to_string(),
                         }),
                     },
+                    default: None,
+                    example: None,
                 },
             ],
         );
@@ -590,6 +793,62 @@ mod tests {
         assert!(content.contains("from .Status import Status"));
     }
 
+    #[test]
+    fn test_alias_of_intersection_inherits_every_member() {
+        let model = make_alias_model(
+            "AuditedAddress",
+            ModelType::Intersection {
+                variants: vec![
+                    ModelType::Ref {
+                        name: "Address".to_string(),
+                    },
+                    ModelType::Ref {
+                        name: "Audit".to_string(),
+                    },
+                    ModelType::Object,
+                ],
+            },
+            false,
+        );
+        let content = render_single_model(&model).unwrap();
+        assert!(content.contains("from .Address import Address"));
+        assert!(content.contains("from .Audit import Audit"));
+        assert!(content.contains("from pydantic import BaseModel, ConfigDict, Field"));
+        assert!(content.contains("class AuditedAddress(Address, Audit):\n"));
+    }
+
+    #[test]
+    fn test_property_intersection_is_hoisted_into_subclass() {
+        let model = make_interface_model(
+            "Order",
+            vec![ModelProperty {
+                name: "shipTo".to_string(),
+                description: None,
+                required: true,
+                nullable: false,
+                r#type: ModelType::Intersection {
+                    variants: vec![
+                        ModelType::Ref {
+                            name: "Address".to_string(),
+                        },
+                        ModelType::Ref {
+                            name: "Contact".to_string(),
+                        },
+                    ],
+                },
+                default: None,
+                example: None,
+            }],
+        );
+        let content = render_single_model(&model).unwrap();
+        assert!(content.contains("from .Address import Address"));
+        assert!(content.contains("from .Contact import Contact"));
+        assert!(content.contains(
+            "class OrderShipTo(Address, Contact):\n    model_config = ConfigDict(populate_by_name=True)\n\n    pass\n\n\nclass Order(BaseModel):"
+        ));
+        assert!(content.contains("    ship_to: OrderShipTo = Field(alias=\"shipTo\")"));
+    }
+
     #[test]
     fn test_render_pydantic_models_writes_to_output_root() {
         let ir = ModelIr {
@@ -164,7 +164,7 @@ fn extract_python_module_exports(content: &str, fallback_symbol: &str) -> Vec<St
 fn is_public_python_identifier(name: &str) -> bool {
     let mut chars = name.chars();
     match chars.next() {
-        Some(first) if first == '_' || !(first.is_ascii_alphabetic() || first == '_') => false,
+        Some(first) if !first.is_ascii_alphabetic() => false,
         Some(_) => chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_'),
         None => false,
     }
@@ -6,7 +6,8 @@ use std::collections::HashMap;
 
 use swagger_gen::pipeline::{
     EndpointItem, EndpointParameter, GeneratorInput, PlannedFile, RenderOutput, Renderer,
-    resolve_file_import_path, resolve_model_import_base, should_use_package_import,
+    RequestBodyKind, resolve_file_import_path, resolve_model_import_base,
+    should_use_package_import,
 };
 
 /// Renderer that generates Python spec + function files.
@@ -62,6 +63,13 @@ impl Renderer for PythonFunctionsRenderer {
             });
         }
 
+        if input.endpoints.iter().any(has_multipart_body) {
+            files.push(PlannedFile {
+                path: MULTIPART_HELPER_PATH.to_string(),
+                content: MULTIPART_HELPER.to_string(),
+            });
+        }
+
         Ok(RenderOutput {
             files,
             warnings: vec![],
@@ -69,6 +77,48 @@ impl Renderer for PythonFunctionsRenderer {
     }
 }
 
+/// Shared multipart encoder, generated once and imported by the spec files.
+const MULTIPART_HELPER_PATH: &str = "spec/_multipart.py";
+
+const MULTIPART_HELPER: &str = r#"from __future__ import annotations
+
+import json
+import uuid
+from typing import Any
+
+
+def encode_multipart(fields: dict[str, Any]) -> tuple[bytes, str]:
+    """Encode fields as multipart/form-data, returning the body and its Content-Type.
+
+    Bytes values become file parts; everything else is sent as a text field,
+    with dicts, lists and booleans serialized as JSON.
+    """
+    boundary = uuid.uuid4().hex
+    parts: list[bytes] = []
+    for name, value in fields.items():
+        for item in value if isinstance(value, list) else [value]:
+            if item is None:
+                continue
+            if isinstance(item, (bytes, bytearray)):
+                disposition = f'form-data; name="{name}"; filename="{name}"'
+                content_type = "application/octet-stream"
+                payload = bytes(item)
+            else:
+                disposition = f'form-data; name="{name}"'
+                content_type = "text/plain; charset=utf-8"
+                text = json.dumps(item) if isinstance(item, (dict, list, bool)) else str(item)
+                payload = text.encode("utf-8")
+            head = f"--{boundary}\r\nContent-Disposition: {disposition}\r\nContent-Type: {content_type}\r\n\r\n"
+            parts.append(head.encode("utf-8") + payload + b"\r\n")
+    parts.append(f"--{boundary}--\r\n".encode("utf-8"))
+    return b"".join(parts), f"multipart/form-data; boundary={boundary}"
+"#;
+
+fn has_multipart_body(endpoint: &EndpointItem) -> bool {
+    endpoint.request_body_field.is_some()
+        && endpoint.request_body_kind() == RequestBodyKind::Multipart
+}
+
 fn get_spec_file_path(endpoint: &EndpointItem, resolved_name: &ResolvedPyName) -> String {
     let namespace: Vec<String> = endpoint
         .namespace
@@ -458,7 +508,7 @@ fn to_snake_case(name: &str) -> String {
         if ch.is_uppercase() {
             if i > 0 {
                 let prev = chars[i - 1];
-                let next_is_lower = chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+                let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
                 if prev.is_lowercase() || next_is_lower {
                     result.push('_');
                 }
@@ -509,8 +559,7 @@ fn parse_inline_fields(input_type_name: &str) -> Vec<(String, String)> {
 
 fn normalize_python_package_path(import_path: &str) -> String {
     import_path
-        .replace('\\', ".")
-        .replace('/', ".")
+        .replace(['\\', '/'], ".")
         .trim_matches('.')
         .to_string()
 }
@@ -548,6 +597,7 @@ fn to_python_relative_import_path(import_path: &str) -> String {
 
 #[derive(Clone)]
 struct RenderedExtraParam {
+    location: ParamLocation,
     original_name: String,
     python_name: String,
     annotation: String,
@@ -586,6 +636,20 @@ fn sanitize_python_identifier(name: &str) -> String {
 fn render_python_annotation(type_name: &str, model_import_base: &str) -> (String, Vec<String>) {
     let trimmed = type_name.trim();
 
+    let members = split_union_members(trimmed);
+    if members.len() > 1 {
+        let mut annotations = Vec::<String>::new();
+        let mut imports = Vec::new();
+        for member in members {
+            let (annotation, member_imports) = render_python_annotation(member, model_import_base);
+            if !annotations.contains(&annotation) {
+                annotations.push(annotation);
+            }
+            imports.extend(member_imports);
+        }
+        return (annotations.join(" | "), dedupe_lines(imports));
+    }
+
     if let Some(inner) = trimmed
         .strip_prefix("Array<")
         .and_then(|rest| rest.strip_suffix('>'))
@@ -598,6 +662,8 @@ fn render_python_annotation(type_name: &str, model_import_base: &str) -> (String
         "string" => ("str".to_string(), vec![]),
         "number" => ("float".to_string(), vec![]),
         "boolean" => ("bool".to_string(), vec![]),
+        "void" | "null" | "undefined" => ("None".to_string(), vec![]),
+        "Blob" | "File" => ("bytes".to_string(), vec![]),
         "object" => (
             "dict[str, Any]".to_string(),
             vec!["from typing import Any".to_string()],
@@ -623,14 +689,44 @@ fn render_python_annotation(type_name: &str, model_import_base: &str) -> (String
     }
 }
 
+/// Members of a top-level `A | B` union; `|` inside `Array<...>` is kept.
+fn split_union_members(type_name: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in type_name.char_indices() {
+        match ch {
+            '<' | '{' | '[' | '(' => depth += 1,
+            '>' | '}' | ']' | ')' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                members.push(type_name[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(type_name[start..].trim());
+    members
+}
+
 fn is_python_model_reference(type_name: &str) -> bool {
     let trimmed = type_name.trim();
 
-    !matches!(trimmed, "string" | "number" | "boolean" | "object")
-        && trimmed
-            .chars()
-            .next()
-            .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
+    !matches!(
+        trimmed,
+        "string"
+            | "number"
+            | "boolean"
+            | "object"
+            | "Blob"
+            | "File"
+            | "void"
+            | "null"
+            | "undefined"
+    ) && trimmed
+        .chars()
+        .next()
+        .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '_')
         && !trimmed.contains(['<', '>', '|', '[', ']', '{', '}', ';'])
 }
 
@@ -664,7 +760,10 @@ fn render_request_body_value_with_depth(value_expr: &str, type_name: &str, depth
         return format!("[{rendered_inner} for {item_var} in {value_expr}]");
     }
 
-    if matches!(trimmed, "string" | "number" | "boolean" | "object") {
+    if matches!(
+        trimmed,
+        "string" | "number" | "boolean" | "object" | "Blob" | "File"
+    ) {
         return value_expr.to_string();
     }
 
@@ -675,15 +774,38 @@ fn render_request_body_value_with_depth(value_expr: &str, type_name: &str, depth
     value_expr.to_string()
 }
 
+/// Where an extra parameter is sent; the same name may appear in several places.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParamLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+impl ParamLocation {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Path => "path",
+            Self::Query => "query",
+            Self::Header => "header",
+            Self::Cookie => "cookie",
+        }
+    }
+}
+
 fn render_extra_param(
     parameter: &EndpointParameter,
+    location: ParamLocation,
+    python_name: String,
     model_import_base: &str,
 ) -> RenderedExtraParam {
     let (annotation, imports) = render_python_annotation(&parameter.type_name, model_import_base);
 
     RenderedExtraParam {
+        location,
         original_name: parameter.name.clone(),
-        python_name: sanitize_python_identifier(&parameter.name),
+        python_name,
         annotation,
         required: parameter.required,
         imports,
@@ -694,21 +816,45 @@ fn collect_extra_params(
     endpoint: &EndpointItem,
     model_import_base: &str,
 ) -> Vec<RenderedExtraParam> {
-    endpoint
-        .path_params
-        .iter()
-        .chain(endpoint.query_params.iter())
-        .map(|parameter| render_extra_param(parameter, model_import_base))
-        .collect()
+    let located = [
+        (&endpoint.path_params, ParamLocation::Path),
+        (&endpoint.query_params, ParamLocation::Query),
+        (&endpoint.header_params, ParamLocation::Header),
+        (&endpoint.cookie_params, ParamLocation::Cookie),
+    ];
+    let mut rendered = Vec::<RenderedExtraParam>::new();
+    for (parameter, location) in located
+        .into_iter()
+        .flat_map(|(params, location)| params.iter().map(move |parameter| (parameter, location)))
+    {
+        // A name already taken by another location gets the location as prefix
+        let is_taken = |name: &str| rendered.iter().any(|item| item.python_name == name);
+        let mut python_name = sanitize_python_identifier(&parameter.name);
+        if is_taken(&python_name) {
+            python_name =
+                sanitize_python_identifier(&format!("{}_{}", location.prefix(), parameter.name));
+        }
+        while is_taken(&python_name) {
+            python_name.push('_');
+        }
+        rendered.push(render_extra_param(
+            parameter,
+            location,
+            python_name,
+            model_import_base,
+        ));
+    }
+    rendered
 }
 
 fn find_rendered_param<'a>(
     params: &'a [RenderedExtraParam],
+    location: ParamLocation,
     original_name: &str,
 ) -> Option<&'a RenderedExtraParam> {
-    params
-        .iter()
-        .find(|parameter| parameter.original_name == original_name)
+    params.iter().find(|parameter| {
+        parameter.location == location && parameter.original_name == original_name
+    })
 }
 
 fn render_extra_param_signature(parameter: &RenderedExtraParam) -> String {
@@ -758,7 +904,7 @@ fn render_builder_call(
     args.extend(
         extra_params
             .iter()
-            .map(|parameter| format!("{}={}", parameter.original_name, parameter.python_name)),
+            .map(|parameter| format!("{0}={0}", parameter.python_name)),
     );
 
     if args.is_empty() {
@@ -770,10 +916,11 @@ fn render_builder_call(
 
 fn resolve_request_value(
     field_name: &str,
+    location: ParamLocation,
     extra_params: &[RenderedExtraParam],
     has_model_input: bool,
 ) -> String {
-    if let Some(parameter) = find_rendered_param(extra_params, field_name) {
+    if let Some(parameter) = find_rendered_param(extra_params, location, field_name) {
         parameter.python_name.clone()
     } else if has_model_input {
         format!("input.{field_name}")
@@ -797,7 +944,7 @@ fn render_path_input_assignment(
         .map(|field| {
             format!(
                 "{field}={}",
-                resolve_request_value(field, extra_params, has_model_input)
+                resolve_request_value(field, ParamLocation::Path, extra_params, has_model_input)
             )
         })
         .collect::<Vec<_>>()
@@ -853,6 +1000,19 @@ fn render_spec_file(
     if !endpoint.path_fields.is_empty() {
         imports.push("from types import SimpleNamespace".to_string());
     }
+    if endpoint.request_body_field.is_some()
+        && endpoint.request_body_kind() == RequestBodyKind::FormUrlEncoded
+    {
+        imports.push("from urllib.parse import urlencode".to_string());
+    }
+    if has_multipart_body(endpoint) {
+        let spec_path = get_spec_file_path(endpoint, resolved_name);
+        let helper_module = MULTIPART_HELPER_PATH.trim_end_matches(".py");
+        imports.push(format!(
+            "from {} import encode_multipart",
+            resolve_python_module_import(&spec_path, helper_module)
+        ));
+    }
     for parameter in &extra_params {
         imports.extend(parameter.imports.iter().cloned());
     }
@@ -864,9 +1024,9 @@ fn render_spec_file(
         imports.push("from aptx_api_core import RequestSpec".to_string());
         imports = dedupe_lines(imports);
         let sig_block = render_signature_block(None, &extra_params);
-        let body = render_spec_fields(endpoint, false, &extra_params, input_type);
+        let (prelude, body) = render_spec_fields(endpoint, false, &extra_params, input_type);
         format!(
-            "{imports_block}\n\ndef {builder_name}({sig_block}) -> RequestSpec:\n    return RequestSpec(\n{body}    )\n",
+            "{imports_block}\n\ndef {builder_name}({sig_block}) -> RequestSpec:\n{prelude}    return RequestSpec(\n{body}    )\n",
             imports_block = imports.join("\n"),
             builder_name = builder_name,
             sig_block = sig_block,
@@ -886,21 +1046,18 @@ fn render_spec_file(
         for (_, rendered_type) in &rendered_inline_fields {
             imports.extend(rendered_type.imports.iter().cloned());
         }
-        let params: Vec<String> = rendered_inline_fields
-            .iter()
-            .map(|(name, rendered_type)| format!("    *, {name}: {}", rendered_type.annotation))
-            .collect();
-        let sig = params.join(",\n");
+        let header_params = inline_header_params(&extra_params);
+        let sig_block = render_inline_signature_block(&rendered_inline_fields, &header_params);
 
         imports.push("from aptx_api_core import RequestSpec".to_string());
         imports = dedupe_lines(imports);
 
-        let body = render_inline_spec_fields(endpoint, &inline_fields);
+        let (prelude, body) = render_inline_spec_fields(endpoint, &inline_fields, &header_params);
         format!(
-            "{imports_block}\n\ndef {builder_name}(\n{sig}\n) -> RequestSpec:\n    return RequestSpec(\n{body}    )\n",
+            "{imports_block}\n\ndef {builder_name}({sig_block}) -> RequestSpec:\n{prelude}    return RequestSpec(\n{body}    )\n",
             imports_block = imports.join("\n"),
             builder_name = builder_name,
-            sig = sig,
+            sig_block = sig_block,
             body = body,
         )
     } else {
@@ -911,9 +1068,9 @@ fn render_spec_file(
             Some(format!("input: {}", primary_input.annotation)),
             &extra_params,
         );
-        let body = render_spec_fields(endpoint, true, &extra_params, input_type);
+        let (prelude, body) = render_spec_fields(endpoint, true, &extra_params, input_type);
         format!(
-            "{imports_block}\n\ndef {builder_name}({sig_block}) -> RequestSpec:\n    return RequestSpec(\n{body}    )\n",
+            "{imports_block}\n\ndef {builder_name}({sig_block}) -> RequestSpec:\n{prelude}    return RequestSpec(\n{body}    )\n",
             imports_block = imports.join("\n"),
             builder_name = builder_name,
             sig_block = sig_block,
@@ -927,21 +1084,19 @@ fn render_spec_fields(
     has_model_input: bool,
     extra_params: &[RenderedExtraParam],
     input_type: &str,
-) -> String {
+) -> (String, String) {
+    let mut prelude = String::new();
     let mut fields = format!("        method=\"{}\",\n", endpoint.method);
     fields.push_str(&format!("        path=\"{}\",\n", endpoint.path));
 
-    if has_model_input {
-        if endpoint.request_body_field.is_some() {
-            fields.push_str(&format!(
-                "        body={},\n",
-                render_request_body_value("input", input_type)
-            ));
-        }
-    } else {
-        if endpoint.request_body_field.is_some() {
-            fields.push_str("        body=body,\n");
-        }
+    if endpoint.request_body_field.is_some() {
+        let value = if has_model_input {
+            render_request_body_value("input", input_type)
+        } else {
+            "body".to_string()
+        };
+        prelude = render_body_prelude(endpoint, &value);
+        fields.push_str(&render_body_field(endpoint, &value));
     }
 
     if !endpoint.query_fields.is_empty() {
@@ -951,26 +1106,118 @@ fn render_spec_fields(
             .map(|field| {
                 format!(
                     "\"{field}\": {}",
-                    resolve_request_value(field, extra_params, has_model_input)
+                    resolve_request_value(
+                        field,
+                        ParamLocation::Query,
+                        extra_params,
+                        has_model_input
+                    )
                 )
             })
             .collect();
         fields.push_str(&format!("        query={{ {} }},\n", keys.join(", ")));
     }
 
+    fields.push_str(&render_headers_field(
+        endpoint,
+        extra_params,
+        has_model_input,
+    ));
+
     if let Some(input_assignment) =
         render_path_input_assignment(endpoint, extra_params, has_model_input)
     {
         fields.push_str(&input_assignment);
     }
 
-    fields
+    (prelude, fields)
+}
+
+/// Header parameters plus a `Cookie` header joined from the cookie parameters.
+/// Unset optional headers (and an empty `Cookie`) are left out of the map.
+fn render_headers_field(
+    endpoint: &EndpointItem,
+    extra_params: &[RenderedExtraParam],
+    has_model_input: bool,
+) -> String {
+    let entry = |parameter: &EndpointParameter, location| {
+        format!(
+            "\"{}\": {}",
+            parameter.name,
+            resolve_request_value(&parameter.name, location, extra_params, has_model_input)
+        )
+    };
+    let mut entries = endpoint
+        .header_params
+        .iter()
+        .map(|parameter| entry(parameter, ParamLocation::Header))
+        .collect::<Vec<_>>();
+    entries.extend(render_content_type_entry(endpoint));
+    if !endpoint.cookie_params.is_empty() {
+        let cookies = endpoint
+            .cookie_params
+            .iter()
+            .map(|parameter| entry(parameter, ParamLocation::Cookie))
+            .collect::<Vec<_>>()
+            .join(", ");
+        entries.push(format!(
+            "\"Cookie\": \"; \".join(f\"{{name}}={{value}}\" for name, value in {{ {cookies} }}.items() if value is not None) or None"
+        ));
+    }
+
+    let may_be_unset = endpoint
+        .header_params
+        .iter()
+        .any(|parameter| !parameter.required)
+        || !endpoint.cookie_params.is_empty();
+    if entries.is_empty() {
+        String::new()
+    } else if may_be_unset {
+        format!(
+            "        headers={{ name: value for name, value in {{ {} }}.items() if value is not None }},\n",
+            entries.join(", ")
+        )
+    } else {
+        format!("        headers={{ {} }},\n", entries.join(", "))
+    }
+}
+
+/// Header and cookie params of an inline-input endpoint; path and query
+/// params are already part of the inline fields.
+fn inline_header_params(extra_params: &[RenderedExtraParam]) -> Vec<RenderedExtraParam> {
+    extra_params
+        .iter()
+        .filter(|parameter| {
+            matches!(
+                parameter.location,
+                ParamLocation::Header | ParamLocation::Cookie
+            )
+        })
+        .cloned()
+        .collect()
+}
+
+/// Keyword-only signature listing the inline fields followed by header params.
+fn render_inline_signature_block(
+    inline_fields: &[(String, RenderedPythonType)],
+    header_params: &[RenderedExtraParam],
+) -> String {
+    let mut lines = vec!["    *".to_string()];
+    lines.extend(
+        inline_fields
+            .iter()
+            .map(|(name, rendered_type)| format!("    {name}: {}", rendered_type.annotation)),
+    );
+    lines.extend(header_params.iter().map(render_extra_param_signature));
+    format!("\n{},\n", lines.join(",\n"))
 }
 
 fn render_inline_spec_fields(
     endpoint: &EndpointItem,
     inline_fields: &[(String, String)],
-) -> String {
+    header_params: &[RenderedExtraParam],
+) -> (String, String) {
+    let mut prelude = String::new();
     let mut fields = format!("        method=\"{}\",\n", endpoint.method);
     fields.push_str(&format!("        path=\"{}\",\n", endpoint.path));
 
@@ -989,13 +1236,49 @@ fn render_inline_spec_fields(
             .find(|(name, _)| name == "body")
             .map(|(_, type_name)| type_name.as_str())
             .unwrap_or("object");
-        fields.push_str(&format!(
-            "        body={},\n",
-            render_request_body_value("body", body_type)
-        ));
+        let value = render_request_body_value("body", body_type);
+        prelude = render_body_prelude(endpoint, &value);
+        fields.push_str(&render_body_field(endpoint, &value));
     }
 
-    fields
+    fields.push_str(&render_headers_field(endpoint, header_params, false));
+
+    (prelude, fields)
+}
+
+/// Statements run before building the spec; multipart bodies are encoded
+/// here so the body and its boundary `Content-Type` come from one call.
+fn render_body_prelude(endpoint: &EndpointItem, value: &str) -> String {
+    if endpoint.request_body_kind() == RequestBodyKind::Multipart {
+        format!("    multipart_body, multipart_content_type = encode_multipart({value})\n")
+    } else {
+        String::new()
+    }
+}
+
+/// Body field encoded for the endpoint's request content type.
+///
+/// Multipart bodies are pre-encoded by `render_body_prelude`, urlencoded
+/// bodies are encoded up front and text bodies are stringified.
+fn render_body_field(endpoint: &EndpointItem, value: &str) -> String {
+    match endpoint.request_body_kind() {
+        RequestBodyKind::Json | RequestBodyKind::Binary => format!("        body={value},\n"),
+        RequestBodyKind::Multipart => "        body=multipart_body,\n".to_string(),
+        RequestBodyKind::FormUrlEncoded => {
+            format!("        body=urlencode({value}, doseq=True),\n")
+        }
+        RequestBodyKind::Text => format!("        body=str({value}),\n"),
+    }
+}
+
+/// `Content-Type` header entry for bodies the client cannot infer on its own.
+fn render_content_type_entry(endpoint: &EndpointItem) -> Option<String> {
+    if has_multipart_body(endpoint) {
+        return Some("\"Content-Type\": multipart_content_type".to_string());
+    }
+    endpoint
+        .request_content_type_header()
+        .map(|content_type| format!("\"Content-Type\": \"{content_type}\""))
 }
 
 fn render_function_file(
@@ -1011,8 +1294,9 @@ fn render_function_file(
     let extra_params = collect_extra_params(endpoint, model_import_base);
     let primary_input = (!is_void_input(input_type) && !is_inline_input(input_type))
         .then(|| render_python_type_usage(input_type, model_import_base));
-    let rendered_output = (output_type != "void" && output_type != "None")
-        .then(|| render_python_type_usage(output_type, model_import_base));
+    let rendered_output =
+        (!endpoint.binary_response && output_type != "void" && output_type != "None")
+            .then(|| render_python_type_usage(output_type, model_import_base));
 
     imports.push("from aptx_api_core import get_api_client".to_string());
 
@@ -1035,7 +1319,7 @@ fn render_function_file(
     let (signature, call_args) = if is_void_input(input_type) {
         (
             render_signature_block(None, &extra_params),
-            render_builder_call(&builder_name, None, &extra_params),
+            render_builder_call(builder_name, None, &extra_params),
         )
     } else if is_inline_input(input_type) {
         let inline_fields = parse_inline_fields(input_type);
@@ -1051,13 +1335,18 @@ fn render_function_file(
         for (_, rendered_type) in &rendered_inline_fields {
             imports.extend(rendered_type.imports.iter().cloned());
         }
-        let params: Vec<String> = rendered_inline_fields
+        let header_params = inline_header_params(&extra_params);
+        let args: Vec<String> = inline_fields
             .iter()
-            .map(|(name, rendered_type)| format!("    *, {name}: {}", rendered_type.annotation))
+            .map(|(name, _)| format!("{name}={name}"))
+            .chain(
+                header_params
+                    .iter()
+                    .map(|parameter| format!("{0}={0}", parameter.python_name)),
+            )
             .collect();
-        let args: Vec<String> = inline_fields.iter().map(|(name, _)| name.clone()).collect();
         (
-            format!("\n{}\n", params.join(",\n")),
+            render_inline_signature_block(&rendered_inline_fields, &header_params),
             format!("{builder_name}({})", args.join(", ")),
         )
     } else {
@@ -1067,14 +1356,20 @@ fn render_function_file(
                 Some(format!("input: {}", primary_input.annotation)),
                 &extra_params,
             ),
-            render_builder_call(&builder_name, Some("input"), &extra_params),
+            render_builder_call(builder_name, Some("input"), &extra_params),
         )
     };
 
+    let (error_classes, error_imports) =
+        render_error_classes(endpoint, &resolved_name.export_name, model_import_base);
+    imports.extend(error_imports);
+
     let is_void_output = output_type == "void" || output_type == "None";
     imports = dedupe_lines(imports);
 
-    let return_type = if is_void_output {
+    let return_type = if endpoint.binary_response {
+        "bytes".to_string()
+    } else if is_void_output {
         "None".to_string()
     } else {
         rendered_output
@@ -1082,34 +1377,136 @@ fn render_function_file(
             .map(|output| output.annotation.clone())
             .unwrap_or_else(|| output_type.to_string())
     };
-
-    let response_type_arg = if is_void_output {
-        String::new()
+    // Binary responses ask the client for the raw body instead of decoding JSON
+    let response_type = if endpoint.binary_response {
+        Some("bytes")
     } else {
         rendered_output
             .as_ref()
-            .and_then(|output| output.runtime_type.as_ref())
-            .map(|runtime_type| format!(",\n        response_type={runtime_type}"))
-            .unwrap_or_default()
+            .and_then(|output| output.runtime_type.as_deref())
+    };
+    let response_type_arg = response_type
+        .map(|runtime_type| format!(",\n    response_type={runtime_type}"))
+        .unwrap_or_default();
+    let body = format!("return get_api_client().execute(\n    {call_args}{response_type_arg}\n)\n");
+    let body = if error_classes.is_empty() {
+        indent_lines(&body, 4)
+    } else {
+        // Client errors carrying `status` and `body` are raised as the typed error classes
+        format!(
+            "    try:\n{}    except Exception as error:\n        status = getattr(error, \"status\", None)\n        if not isinstance(status, int):\n            raise\n        raise {}Error.from_response(status, getattr(error, \"body\", None)) from error\n",
+            indent_lines(&body, 8),
+            to_pascal_identifier(&resolved_name.export_name)
+        )
     };
 
     format!(
-        "{imports_block}\n\ndef {export_name}({sig_block}) -> {return_type}:\n    return get_api_client().execute(\n        {call_expr}{response_type_arg}\n    )\n",
+        "{imports_block}\n\ndef {export_name}({signature}) -> {return_type}:\n{body}{error_classes}",
         imports_block = imports.join("\n"),
         export_name = resolved_name.export_name,
-        sig_block = signature,
-        return_type = return_type,
-        call_expr = call_args,
-        response_type_arg = response_type_arg,
     )
 }
 
+fn indent_lines(text: &str, spaces: usize) -> String {
+    let indent = " ".repeat(spaces);
+    text.lines()
+        .map(|line| format!("{indent}{line}\n"))
+        .collect()
+}
+
+/// Exception classes for the endpoint's error responses: a base class plus one
+/// subclass per status whose `body` is typed by that response.
+fn render_error_classes(
+    endpoint: &EndpointItem,
+    export_name: &str,
+    model_import_base: &str,
+) -> (String, Vec<String>) {
+    if endpoint.error_responses.is_empty() {
+        return (String::new(), vec![]);
+    }
+
+    let base_name = format!("{}Error", to_pascal_identifier(export_name));
+    let mut imports = Vec::new();
+    let mut subclasses = String::new();
+    let mut registry = Vec::new();
+    for response in &endpoint.error_responses {
+        // `4xx` and `4XX` are the same range, the lookup key is always upper case
+        let status_key = if response.status_code == "default" {
+            response.status_code.clone()
+        } else {
+            response.status_code.to_ascii_uppercase()
+        };
+        let class_name = format!("{base_name}{}", to_pascal_identifier(&status_key));
+        let body_annotation = if response.type_name == "void" {
+            "None".to_string()
+        } else {
+            let rendered = render_python_type_usage(&response.type_name, model_import_base);
+            imports.extend(rendered.imports);
+            rendered.annotation
+        };
+        let status_line = if status_key.bytes().all(|ch| ch.is_ascii_digit()) {
+            imports.push("from typing import Literal".to_string());
+            format!("    status: Literal[{status_key}]\n")
+        } else {
+            String::new()
+        };
+        subclasses.push_str(&format!(
+            "\n\nclass {class_name}({base_name}):\n{status_line}    body: {body_annotation}\n"
+        ));
+        registry.push(format!("    \"{status_key}\": {class_name},"));
+    }
+
+    let content = format!(
+        r#"
+
+class {base_name}(Exception):
+    """Error response of ``{export_name}``; subclasses narrow ``body`` by status."""
+
+    status: int
+    body: object
+
+    def __init__(self, status: int, body: object) -> None:
+        super().__init__(f"{export_name} failed with status {{status}}")
+        self.status = status
+        self.body = body
+
+    @classmethod
+    def from_response(cls, status: int, body: object) -> {base_name}:
+        error_class = (
+            _ERROR_CLASSES.get(str(status))
+            or _ERROR_CLASSES.get(f"{{status // 100}}XX")
+            or _ERROR_CLASSES.get("default", cls)
+        )
+        return error_class(status, body)
+{subclasses}
+
+_ERROR_CLASSES: dict[str, type[{base_name}]] = {{
+{registry}
+}}
+"#,
+        registry = registry.join("\n"),
+    );
+    (content, imports)
+}
+
+fn to_pascal_identifier(name: &str) -> String {
+    name.split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indexmap::IndexMap;
     use swagger_gen::pipeline::{
-        EndpointParameter, GeneratorInput, ModelImportConfig, ProjectContext,
+        EndpointParameter, EndpointResponse, GeneratorInput, ModelImportConfig, ProjectContext,
     };
 
     fn make_endpoint(
@@ -1125,19 +1522,11 @@ mod tests {
             operation_name: operation_name.to_string(),
             export_name: operation_name.to_string(),
             builder_name: format!("build_{operation_name}_spec"),
-            summary: None,
             method: method.to_string(),
             path: path.to_string(),
             input_type_name: input_type.to_string(),
             output_type_name: output_type.to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }
     }
 
@@ -1153,6 +1542,7 @@ mod tests {
             model_import: None,
             client_import: None,
             output_root: None,
+            webhooks: vec![],
         }
     }
 
@@ -1172,6 +1562,7 @@ mod tests {
             model_import,
             client_import: None,
             output_root,
+            webhooks: vec![],
         }
     }
 
@@ -1403,6 +1794,8 @@ mod tests {
             name: "subSystemCode".to_string(),
             type_name: "string".to_string(),
             required: true,
+            default: None,
+            example: None,
         }];
 
         let spec = render_spec_file(
@@ -1440,6 +1833,8 @@ mod tests {
             name: "subSystemCode".to_string(),
             type_name: "string".to_string(),
             required: true,
+            default: None,
+            example: None,
         }];
 
         let spec = render_spec_file(&ep, &resolved_py_name("unbind"), "...models");
@@ -1460,6 +1855,366 @@ mod tests {
         assert!(func.contains("build_unbind_spec(input, subSystemCode=subSystemCode)"));
     }
 
+    #[test]
+    fn test_builder_call_uses_python_keyword_names() {
+        let mut ep = make_endpoint(
+            &["user"],
+            "get_user_list",
+            "GET",
+            "/user/list",
+            "void",
+            "ResultModel",
+        );
+        ep.query_fields = vec!["page-size".to_string()];
+        ep.query_params = vec![EndpointParameter {
+            name: "page-size".to_string(),
+            type_name: "number".to_string(),
+            required: true,
+            default: None,
+            example: None,
+        }];
+
+        let spec = render_spec_file(&ep, &resolved_py_name("get_user_list"), "...models");
+        assert!(spec.contains("    page_size: float,\n"));
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("get_user_list"),
+            "functions/user/get_user_list.py",
+            "...models",
+        );
+        assert!(func.contains("build_get_user_list_spec(page_size=page_size)"));
+    }
+
+    #[test]
+    fn test_colliding_header_and_query_names_get_prefixed() {
+        let param = |name: &str| EndpointParameter {
+            name: name.to_string(),
+            type_name: "string".to_string(),
+            required: true,
+            default: None,
+            example: None,
+        };
+        let mut ep = make_endpoint(
+            &["user"],
+            "get_user_list",
+            "GET",
+            "/user/list",
+            "void",
+            "User",
+        );
+        ep.query_fields = vec!["version".to_string()];
+        ep.query_params = vec![param("version")];
+        ep.header_params = vec![param("version")];
+
+        let spec = render_spec_file(&ep, &resolved_py_name("get_user_list"), "...models");
+        assert!(spec.contains("    version: str,\n    header_version: str,\n"));
+        assert!(spec.contains("query={ \"version\": version },"));
+        assert!(spec.contains("headers={ \"version\": header_version },"));
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("get_user_list"),
+            "functions/user/get_user_list.py",
+            "...models",
+        );
+        assert!(
+            func.contains(
+                "build_get_user_list_spec(version=version, header_version=header_version)"
+            )
+        );
+    }
+
+    #[test]
+    fn test_header_and_cookie_params_are_sent_as_headers() {
+        let mut ep = make_endpoint(
+            &["user"],
+            "post_unbind",
+            "POST",
+            "/AuthorityAPI/User/Unbind",
+            "UnbindWechatRequest",
+            "ResultModel",
+        );
+        ep.request_body_field = Some("body".to_string());
+        ep.header_params = vec![EndpointParameter {
+            name: "X-Tenant-Id".to_string(),
+            type_name: "string".to_string(),
+            required: true,
+            default: None,
+            example: None,
+        }];
+        ep.cookie_params = vec![EndpointParameter {
+            name: "session".to_string(),
+            type_name: "string".to_string(),
+            required: false,
+            default: None,
+            example: None,
+        }];
+
+        let spec = render_spec_file(&ep, &resolved_py_name("unbind"), "...models");
+        assert!(spec.contains("    X_Tenant_Id: str,\n"));
+        assert!(spec.contains("    session: str | None = None,\n"));
+        assert!(spec.contains(
+            "headers={ name: value for name, value in { \"X-Tenant-Id\": X_Tenant_Id, \"Cookie\": \"; \".join(f\"{name}={value}\" for name, value in { \"session\": session }.items() if value is not None) or None }.items() if value is not None },"
+        ));
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("unbind"),
+            "functions/user/unbind.py",
+            "...models",
+        );
+        assert!(
+            func.contains("build_unbind_spec(input, X_Tenant_Id=X_Tenant_Id, session=session)")
+        );
+    }
+
+    #[test]
+    fn test_inline_input_keeps_header_params() {
+        let mut ep = make_endpoint(
+            &["stored_file"],
+            "upload_image",
+            "POST",
+            "/stored-file/upload",
+            "{ StoreType: StoreType; body?: object }",
+            "GuidResult",
+        );
+        ep.query_fields = vec!["StoreType".to_string()];
+        ep.query_params = vec![EndpointParameter {
+            name: "StoreType".to_string(),
+            type_name: "StoreType".to_string(),
+            required: true,
+            default: None,
+            example: None,
+        }];
+        ep.header_params = vec![EndpointParameter {
+            name: "X-Tenant-Id".to_string(),
+            type_name: "string".to_string(),
+            required: true,
+            default: None,
+            example: None,
+        }];
+        ep.request_body_field = Some("body".to_string());
+
+        let spec = render_spec_file(&ep, &resolved_py_name("upload_image"), "...models");
+        assert!(spec.contains(
+            "def build_upload_image_spec(\n    *,\n    StoreType: StoreType,\n    body: dict[str, Any],\n    X_Tenant_Id: str,\n) -> RequestSpec:"
+        ));
+        assert!(spec.contains("headers={ \"X-Tenant-Id\": X_Tenant_Id },"));
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("upload_image"),
+            "functions/stored_file/upload_image.py",
+            "...models",
+        );
+        assert!(func.contains("    X_Tenant_Id: str,\n"));
+        assert!(func.contains(
+            "build_upload_image_spec(StoreType=StoreType, body=body, X_Tenant_Id=X_Tenant_Id)"
+        ));
+    }
+
+    #[test]
+    fn test_request_body_is_encoded_by_content_type() {
+        let mut ep = make_endpoint(
+            &["file"],
+            "upload",
+            "POST",
+            "/file/upload",
+            "UploadRequest",
+            "void",
+        );
+        ep.request_body_field = Some("body".to_string());
+        ep.request_content_type = Some("multipart/form-data".to_string());
+
+        let spec = render_spec_file(&ep, &resolved_py_name("upload"), "...models");
+        assert!(spec.contains("from .._multipart import encode_multipart"));
+        assert!(spec.contains(
+            "    multipart_body, multipart_content_type = encode_multipart(input.model_dump(by_alias=True, exclude_none=True))\n    return RequestSpec("
+        ));
+        assert!(spec.contains("        body=multipart_body,\n"));
+        assert!(spec.contains("headers={ \"Content-Type\": multipart_content_type },"));
+        assert!(!spec.contains("files="));
+
+        ep.request_content_type = Some("application/x-www-form-urlencoded".to_string());
+        let spec = render_spec_file(&ep, &resolved_py_name("upload"), "...models");
+        assert!(spec.contains("from urllib.parse import urlencode"));
+        assert!(spec.contains(
+            "body=urlencode(input.model_dump(by_alias=True, exclude_none=True), doseq=True),"
+        ));
+        assert!(
+            spec.contains("headers={ \"Content-Type\": \"application/x-www-form-urlencoded\" },")
+        );
+
+        ep.request_content_type = Some("application/octet-stream".to_string());
+        ep.input_type_name = "Blob".to_string();
+        let spec = render_spec_file(&ep, &resolved_py_name("upload"), "...models");
+        assert!(spec.contains("def build_upload_spec(\n    input: bytes,\n) -> RequestSpec:"));
+        assert!(spec.contains("        body=input,\n"));
+        assert!(spec.contains("headers={ \"Content-Type\": \"application/octet-stream\" },"));
+    }
+
+    #[test]
+    fn test_render_emits_one_shared_multipart_module() {
+        let mut upload = make_endpoint(
+            &["file"],
+            "upload",
+            "POST",
+            "/file/upload",
+            "UploadRequest",
+            "void",
+        );
+        upload.request_body_field = Some("body".to_string());
+        upload.request_content_type = Some("multipart/form-data".to_string());
+        let mut avatar = make_endpoint(
+            &["user"],
+            "upload_avatar",
+            "POST",
+            "/user/avatar",
+            "AvatarRequest",
+            "void",
+        );
+        avatar.request_body_field = Some("body".to_string());
+        avatar.request_content_type = Some("multipart/form-data".to_string());
+
+        let output = PythonFunctionsRenderer
+            .render(&make_generator_input(vec![upload, avatar]))
+            .unwrap();
+        let helpers: Vec<_> = output
+            .files
+            .iter()
+            .filter(|file| file.path == "spec/_multipart.py")
+            .collect();
+        assert_eq!(helpers.len(), 1);
+        assert!(
+            helpers[0]
+                .content
+                .contains("def encode_multipart(fields: dict[str, Any]) -> tuple[bytes, str]:")
+        );
+        assert!(
+            helpers[0]
+                .content
+                .contains("if isinstance(item, (bytes, bytearray)):")
+        );
+
+        let output = PythonFunctionsRenderer
+            .render(&make_generator_input(vec![make_endpoint(
+                &["user"],
+                "get_user",
+                "GET",
+                "/user/get",
+                "void",
+                "User",
+            )]))
+            .unwrap();
+        assert!(
+            output
+                .files
+                .iter()
+                .all(|file| file.path != "spec/_multipart.py")
+        );
+    }
+
+    #[test]
+    fn test_binary_response_returns_bytes() {
+        let mut ep = make_endpoint(
+            &["report"],
+            "download_report",
+            "GET",
+            "/report/download",
+            "void",
+            "Blob",
+        );
+        ep.binary_response = true;
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("download_report"),
+            "functions/report/download_report.py",
+            "...models",
+        );
+        assert!(func.contains("def download_report() -> bytes:"));
+        assert!(func.contains(
+            "    return get_api_client().execute(\n        build_download_report_spec(),\n        response_type=bytes\n    )\n"
+        ));
+        assert!(!func.contains("execute_raw"));
+    }
+
+    #[test]
+    fn test_union_output_from_several_success_responses() {
+        let ep = make_endpoint(
+            &["users"],
+            "save_user",
+            "PUT",
+            "/users",
+            "void",
+            "User | Job",
+        );
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("save_user"),
+            "functions/users/save_user.py",
+            "...models",
+        );
+        assert!(func.contains("from ...models.User import User\n"));
+        assert!(func.contains("from ...models.Job import Job\n"));
+        assert!(func.contains("def save_user() -> User | Job:"));
+        assert!(!func.contains("User | Job import"));
+
+        let ep = make_endpoint(
+            &["users"],
+            "count_users",
+            "GET",
+            "/users",
+            "void",
+            "number | void",
+        );
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("count_users"),
+            "functions/users/count_users.py",
+            "...models",
+        );
+        assert!(func.contains("def count_users() -> float | None:"));
+        assert!(!func.contains("import void"));
+    }
+
+    #[test]
+    fn test_error_responses_render_exception_classes() {
+        let mut ep = make_endpoint(&["users"], "add_user", "POST", "/users", "void", "User");
+        ep.error_responses = vec![
+            EndpointResponse {
+                status_code: "400".to_string(),
+                type_name: "ProblemDetails".to_string(),
+            },
+            EndpointResponse {
+                status_code: "5xx".to_string(),
+                type_name: "void".to_string(),
+            },
+        ];
+
+        let func = render_function_file(
+            &ep,
+            &resolved_py_name("add_user"),
+            "functions/users/add_user.py",
+            "...models",
+        );
+        assert!(func.contains("from typing import Literal"));
+        assert!(func.contains("class AddUserError(Exception):"));
+        assert!(func.contains(
+            "class AddUserError400(AddUserError):\n    status: Literal[400]\n    body: ProblemDetails\n"
+        ));
+        assert!(func.contains("class AddUserError5XX(AddUserError):\n    body: None\n"));
+        assert!(func.contains("    \"5XX\": AddUserError5XX,\n"));
+        assert!(func.contains(
+            "    try:\n        return get_api_client().execute(\n            build_add_user_spec(),\n            response_type=User\n        )\n    except Exception as error:\n"
+        ));
+        assert!(func.contains(
+            "        raise AddUserError.from_response(status, getattr(error, \"body\", None)) from error\n"
+        ));
+    }
+
     #[test]
     fn test_path_only_endpoint_uses_simple_namespace_for_request_input() {
         let mut ep = make_endpoint(
@@ -1475,6 +2230,8 @@ mod tests {
             name: "id".to_string(),
             type_name: "string".to_string(),
             required: true,
+            default: None,
+            example: None,
         }];
 
         let spec = render_spec_file(&ep, &resolved_py_name("get_user_detail"), "...models");
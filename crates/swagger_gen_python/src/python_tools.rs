@@ -14,8 +14,7 @@ impl Renderer for PythonToolsRenderer {
     }
 
     fn render(&self, input: &GeneratorInput) -> Result<RenderOutput, String> {
-        let tools: Vec<serde_json::Value> =
-            input.endpoints.iter().map(|ep| render_tool(ep)).collect();
+        let tools: Vec<serde_json::Value> = input.endpoints.iter().map(render_tool).collect();
 
         let json_output = serde_json::to_string_pretty(&tools)
             .map_err(|e| format!("Failed to serialize tools.json: {e}"))?;
@@ -66,7 +65,6 @@ fn render_tool(endpoint: &EndpointItem) -> serde_json::Value {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indexmap::IndexMap;
 
     fn make_endpoint(
         export_name: &str,
@@ -80,19 +78,11 @@ mod tests {
             operation_name: export_name.to_string(),
             export_name: export_name.to_string(),
             builder_name: format!("build_{export_name}_spec"),
-            summary: None,
             method: method.to_string(),
             path: path.to_string(),
             input_type_name: input_type.to_string(),
             output_type_name: output_type.to_string(),
-            request_body_field: None,
-            query_params: vec![],
-            query_fields: vec![],
-            path_params: vec![],
-            path_fields: vec![],
-            has_request_options: false,
-            deprecated: false,
-            meta: IndexMap::new(),
+            ..Default::default()
         }
     }
 
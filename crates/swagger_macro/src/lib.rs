@@ -27,6 +27,35 @@ pub fn schema_base_attributes(_attrs: TokenStream, item: TokenStream) -> TokenSt
                     /// 是否只写
                     #[serde(rename = "writeOnly")]
                     pub write_only: Option<bool>,
+
+                    /// 默认值
+                    pub default: Option<serde_json::Value>,
+
+                    /// 示例值
+                    pub example: Option<serde_json::Value>,
+
+                    /// 常量值（OpenAPI 3.1）
+                    #[serde(rename = "const")]
+                    pub r#const: Option<serde_json::Value>,
+
+                    /// 示例列表（OpenAPI 3.1）
+                    pub examples: Option<Vec<serde_json::Value>>,
+
+                    /// 本地定义（OpenAPI 3.1）
+                    #[serde(rename = "$defs")]
+                    pub defs: Option<std::collections::BTreeMap<String, crate::model::SchemaEnum>>,
+
+                    /// 扩展字段（`x-` 开头）
+                    #[serde(flatten)]
+                    pub extensions: crate::model::SpecExtensions,
+
+                    /// 未声明的字段，序列化时原样写回
+                    #[serde(flatten)]
+                    pub unknown_fields: crate::model::SpecUnknownFields,
+
+                    /// 被改写为 3.0 形式的 OpenAPI 3.1 关键字原始值，序列化时写回
+                    #[serde(skip)]
+                    pub source_keywords: crate::model::SchemaSourceKeywords,
                 }
             }
         }
@@ -39,3 +68,46 @@ pub fn schema_base_attributes(_attrs: TokenStream, item: TokenStream) -> TokenSt
 
     TokenStream::from(expanded)
 }
+
+/// 为所有 `Option` 字段添加 `#[serde(skip_serializing_if = "Option::is_none")]`，
+/// 序列化时省略未设置的字段，需要放在 `#[derive(Serialize)]` 之前
+#[proc_macro_attribute]
+pub fn skip_serializing_none(_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(item as syn::ItemStruct);
+
+    let Fields::Named(name_fields) = &mut input.fields else {
+        return syn::Error::new_spanned(&input.fields, "Unsupported struct type")
+            .to_compile_error()
+            .into();
+    };
+    for field in name_fields.named.iter_mut() {
+        if is_option_type(&field.ty) && !has_skip_serializing_if(&field.attrs) {
+            field
+                .attrs
+                .push(syn::parse_quote!(#[serde(skip_serializing_if = "Option::is_none")]));
+        }
+    }
+
+    TokenStream::from(quote! { #input })
+}
+
+fn is_option_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+fn has_skip_serializing_if(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("serde")
+            && attr
+                .meta
+                .require_list()
+                .is_ok_and(|list| list.tokens.to_string().contains("skip_serializing_if"))
+    })
+}
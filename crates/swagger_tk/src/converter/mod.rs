@@ -0,0 +1,3 @@
+mod swagger2;
+
+pub use swagger2::*;
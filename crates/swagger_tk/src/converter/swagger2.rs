@@ -0,0 +1,686 @@
+use serde_json::{Map, Value, json};
+
+use crate::model::OpenAPIObject;
+
+/// 升级后文档使用的 OpenAPI 版本号
+pub const UPGRADED_OPENAPI_VERSION: &str = "3.0.3";
+
+/// 需要从参数对象移动到 `schema` 中的 Swagger 2.0 字段
+const PARAMETER_SCHEMA_KEYS: [&str; 16] = [
+    "type",
+    "format",
+    "items",
+    "default",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "enum",
+    "multipleOf",
+];
+
+const DEFAULT_MEDIA_TYPE: &str = "application/json";
+const FORM_URLENCODED: &str = "application/x-www-form-urlencoded";
+const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+const HTTP_METHODS: [&str; 7] = ["get", "put", "post", "delete", "options", "head", "patch"];
+
+/// 判断文档是否为 Swagger 2.0
+pub fn is_swagger2(document: &Value) -> bool {
+    document
+        .get("swagger")
+        .and_then(Value::as_str)
+        .map(|version| version.starts_with('2'))
+        .unwrap_or(false)
+}
+
+/// 将 Swagger 2.0 文档升级为 OpenAPI 3 文档
+pub fn upgrade_swagger2(document: &Value) -> Result<Value, String> {
+    if !is_swagger2(document) {
+        return Err("document is not a Swagger 2.0 specification".to_string());
+    }
+    let source = document
+        .as_object()
+        .ok_or_else(|| "Swagger 2.0 document must be an object".to_string())?;
+    let converter = Swagger2Converter::new(source);
+
+    let mut target = Map::new();
+    target.insert("openapi".to_string(), json!(UPGRADED_OPENAPI_VERSION));
+    for key in ["info", "tags", "security", "externalDocs"] {
+        if let Some(value) = source.get(key) {
+            target.insert(key.to_string(), value.clone());
+        }
+    }
+    copy_extensions(source, &mut target);
+
+    let servers = converter.servers();
+    if !servers.is_empty() {
+        target.insert("servers".to_string(), Value::Array(servers));
+    }
+    if let Some(paths) = source.get("paths").and_then(Value::as_object) {
+        target.insert("paths".to_string(), converter.paths(paths)?);
+    }
+    let components = converter.components();
+    if !components.is_empty() {
+        target.insert("components".to_string(), Value::Object(components));
+    }
+
+    let mut upgraded = Value::Object(target);
+    rewrite_refs(&mut upgraded, &converter.body_parameter_names);
+    Ok(upgraded)
+}
+
+/// 将 Swagger 2.0 文档升级并反序列化为 `OpenAPIObject`
+pub fn swagger2_to_open_api(document: &Value) -> Result<OpenAPIObject, String> {
+    let upgraded = upgrade_swagger2(document)?;
+    serde_json::from_value(upgraded).map_err(|err| err.to_string())
+}
+
+struct Swagger2Converter<'a> {
+    source: &'a Map<String, Value>,
+    consumes: Vec<String>,
+    produces: Vec<String>,
+    /// `parameters` 中 in=body 的全局参数，升级后位于 `components.requestBodies`
+    body_parameter_names: Vec<String>,
+}
+
+impl<'a> Swagger2Converter<'a> {
+    fn new(source: &'a Map<String, Value>) -> Self {
+        let body_parameter_names = source
+            .get("parameters")
+            .and_then(Value::as_object)
+            .map(|parameters| {
+                parameters
+                    .iter()
+                    .filter(|(_, parameter)| parameter_in(parameter) == Some("body"))
+                    .map(|(name, _)| name.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            source,
+            consumes: string_list(source.get("consumes")),
+            produces: string_list(source.get("produces")),
+            body_parameter_names,
+        }
+    }
+
+    fn servers(&self) -> Vec<Value> {
+        let host = self.source.get("host").and_then(Value::as_str);
+        let base_path = self
+            .source
+            .get("basePath")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        let Some(host) = host else {
+            if base_path.is_empty() {
+                return Vec::new();
+            }
+            return vec![json!({ "url": base_path })];
+        };
+        let mut schemes = string_list(self.source.get("schemes"));
+        if schemes.is_empty() {
+            schemes.push("https".to_string());
+        }
+        schemes
+            .iter()
+            .map(|scheme| json!({ "url": format!("{scheme}://{host}{base_path}") }))
+            .collect()
+    }
+
+    fn components(&self) -> Map<String, Value> {
+        let mut components = Map::new();
+
+        if let Some(definitions) = self.source.get("definitions").and_then(Value::as_object) {
+            let schemas = definitions
+                .iter()
+                .map(|(name, schema)| (name.clone(), convert_schema(schema)))
+                .collect::<Map<_, _>>();
+            components.insert("schemas".to_string(), Value::Object(schemas));
+        }
+
+        if let Some(parameters) = self.source.get("parameters").and_then(Value::as_object) {
+            let mut converted = Map::new();
+            let mut request_bodies = Map::new();
+            for (name, parameter) in parameters {
+                if parameter_in(parameter) == Some("body") {
+                    request_bodies.insert(
+                        name.clone(),
+                        body_parameter_to_request_body(parameter, &self.consumes),
+                    );
+                } else {
+                    converted.insert(name.clone(), convert_parameter(parameter));
+                }
+            }
+            if !converted.is_empty() {
+                components.insert("parameters".to_string(), Value::Object(converted));
+            }
+            if !request_bodies.is_empty() {
+                components.insert("requestBodies".to_string(), Value::Object(request_bodies));
+            }
+        }
+
+        if let Some(responses) = self.source.get("responses").and_then(Value::as_object) {
+            let converted = responses
+                .iter()
+                .map(|(name, response)| (name.clone(), convert_response(response, &self.produces)))
+                .collect::<Map<_, _>>();
+            components.insert("responses".to_string(), Value::Object(converted));
+        }
+
+        if let Some(definitions) = self
+            .source
+            .get("securityDefinitions")
+            .and_then(Value::as_object)
+        {
+            let schemes = definitions
+                .iter()
+                .map(|(name, scheme)| (name.clone(), convert_security_scheme(scheme)))
+                .collect::<Map<_, _>>();
+            components.insert("securitySchemes".to_string(), Value::Object(schemes));
+        }
+
+        components
+    }
+
+    fn paths(&self, paths: &Map<String, Value>) -> Result<Value, String> {
+        let mut converted = Map::new();
+        for (path, path_item) in paths {
+            let Some(path_item) = path_item.as_object() else {
+                return Err(format!("path item `{path}` must be an object"));
+            };
+            converted.insert(path.clone(), self.path_item(path_item)?);
+        }
+        Ok(Value::Object(converted))
+    }
+
+    fn path_item(&self, path_item: &Map<String, Value>) -> Result<Value, String> {
+        let mut target = Map::new();
+        if let Some(reference) = path_item.get("$ref") {
+            target.insert("$ref".to_string(), reference.clone());
+        }
+        copy_extensions(path_item, &mut target);
+
+        // 路径级的 body/formData 参数在 OpenAPI 3 中没有对应位置，下沉到每个操作
+        let shared_parameters = path_item
+            .get("parameters")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let (shared_body, shared_rest): (Vec<_>, Vec<_>) = shared_parameters
+            .into_iter()
+            .partition(|parameter| self.is_body_like(parameter));
+        if !shared_rest.is_empty() {
+            target.insert(
+                "parameters".to_string(),
+                Value::Array(shared_rest.iter().map(convert_parameter).collect()),
+            );
+        }
+
+        for method in HTTP_METHODS {
+            let Some(operation) = path_item.get(method) else {
+                continue;
+            };
+            let Some(operation) = operation.as_object() else {
+                return Err(format!("operation `{method}` must be an object"));
+            };
+            target.insert(method.to_string(), self.operation(operation, &shared_body));
+        }
+        Ok(Value::Object(target))
+    }
+
+    fn operation(&self, operation: &Map<String, Value>, shared_body: &[Value]) -> Value {
+        let mut target = Map::new();
+        for key in [
+            "tags",
+            "summary",
+            "description",
+            "externalDocs",
+            "operationId",
+            "deprecated",
+            "security",
+        ] {
+            if let Some(value) = operation.get(key) {
+                target.insert(key.to_string(), value.clone());
+            }
+        }
+        copy_extensions(operation, &mut target);
+
+        let consumes = operation
+            .get("consumes")
+            .map(|value| string_list(Some(value)))
+            .unwrap_or_else(|| self.consumes.clone());
+        let produces = operation
+            .get("produces")
+            .map(|value| string_list(Some(value)))
+            .unwrap_or_else(|| self.produces.clone());
+
+        let mut parameters = Vec::new();
+        let mut body = None;
+        let mut form_fields = Vec::new();
+        let own_parameters = operation
+            .get("parameters")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        for parameter in shared_body.iter().chain(own_parameters.iter()) {
+            if let Some(name) = self.body_parameter_ref_name(parameter) {
+                body = Some(json!({ "$ref": format!("#/components/requestBodies/{name}") }));
+                continue;
+            }
+            match parameter_in(parameter) {
+                Some("body") => body = Some(body_parameter_to_request_body(parameter, &consumes)),
+                Some("formData") => form_fields.push(parameter.clone()),
+                _ => parameters.push(convert_parameter(parameter)),
+            }
+        }
+        if body.is_none() && !form_fields.is_empty() {
+            body = Some(form_data_to_request_body(&form_fields, &consumes));
+        }
+        if !parameters.is_empty() {
+            target.insert("parameters".to_string(), Value::Array(parameters));
+        }
+        if let Some(body) = body {
+            target.insert("requestBody".to_string(), body);
+        }
+
+        if let Some(responses) = operation.get("responses").and_then(Value::as_object) {
+            let converted = responses
+                .iter()
+                .map(|(status, response)| (status.clone(), convert_response(response, &produces)))
+                .collect::<Map<_, _>>();
+            target.insert("responses".to_string(), Value::Object(converted));
+        }
+
+        if let Some(schemes) = operation.get("schemes") {
+            let host = self.source.get("host").and_then(Value::as_str);
+            let base_path = self
+                .source
+                .get("basePath")
+                .and_then(Value::as_str)
+                .unwrap_or("");
+            if let Some(host) = host {
+                let servers = string_list(Some(schemes))
+                    .iter()
+                    .map(|scheme| json!({ "url": format!("{scheme}://{host}{base_path}") }))
+                    .collect::<Vec<_>>();
+                target.insert("servers".to_string(), Value::Array(servers));
+            }
+        }
+
+        Value::Object(target)
+    }
+
+    fn is_body_like(&self, parameter: &Value) -> bool {
+        matches!(parameter_in(parameter), Some("body") | Some("formData"))
+            || self.body_parameter_ref_name(parameter).is_some()
+    }
+
+    fn body_parameter_ref_name(&self, parameter: &Value) -> Option<&str> {
+        let name = parameter
+            .get("$ref")
+            .and_then(Value::as_str)?
+            .strip_prefix("#/parameters/")?;
+        self.body_parameter_names
+            .iter()
+            .find(|item| item.as_str() == name)
+            .map(String::as_str)
+    }
+}
+
+fn parameter_in(parameter: &Value) -> Option<&str> {
+    parameter.get("in").and_then(Value::as_str)
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn copy_extensions(source: &Map<String, Value>, target: &mut Map<String, Value>) {
+    for (key, value) in source {
+        if key.starts_with("x-") && key != "x-nullable" {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn media_types(list: &[String]) -> Vec<String> {
+    if list.is_empty() {
+        vec![DEFAULT_MEDIA_TYPE.to_string()]
+    } else {
+        list.to_vec()
+    }
+}
+
+/// 升级 Schema 对象中 2.0 专有的写法
+fn convert_schema(schema: &Value) -> Value {
+    match schema {
+        Value::Object(source) => {
+            let mut target = Map::new();
+            for (key, value) in source {
+                match key.as_str() {
+                    "x-nullable" => {
+                        target.insert("nullable".to_string(), value.clone());
+                    }
+                    "type" if value.as_str() == Some("file") => {
+                        target.insert("type".to_string(), json!("string"));
+                        target.insert("format".to_string(), json!("binary"));
+                    }
+                    "discriminator" if value.is_string() => {
+                        target.insert(key.clone(), json!({ "propertyName": value }));
+                    }
+                    "properties" | "definitions" | "patternProperties" => {
+                        let converted = value
+                            .as_object()
+                            .map(|items| {
+                                items
+                                    .iter()
+                                    .map(|(name, item)| (name.clone(), convert_schema(item)))
+                                    .collect::<Map<_, _>>()
+                            })
+                            .map(Value::Object)
+                            .unwrap_or_else(|| value.clone());
+                        target.insert(key.clone(), converted);
+                    }
+                    "items" | "additionalProperties" | "not" => {
+                        target.insert(key.clone(), convert_schema(value));
+                    }
+                    "allOf" | "anyOf" | "oneOf" => {
+                        let converted = value
+                            .as_array()
+                            .map(|items| Value::Array(items.iter().map(convert_schema).collect()))
+                            .unwrap_or_else(|| value.clone());
+                        target.insert(key.clone(), converted);
+                    }
+                    _ => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+            Value::Object(target)
+        }
+        _ => schema.clone(),
+    }
+}
+
+/// 把参数对象上的类型字段收拢为 schema
+fn parameter_schema(parameter: &Map<String, Value>) -> Value {
+    let mut schema = Map::new();
+    for key in PARAMETER_SCHEMA_KEYS {
+        if let Some(value) = parameter.get(key) {
+            schema.insert(key.to_string(), value.clone());
+        }
+    }
+    if let Some(nullable) = parameter.get("x-nullable") {
+        schema.insert("nullable".to_string(), nullable.clone());
+    }
+    convert_schema(&Value::Object(schema))
+}
+
+fn convert_parameter(parameter: &Value) -> Value {
+    let Some(source) = parameter.as_object() else {
+        return parameter.clone();
+    };
+    if source.contains_key("$ref") {
+        return parameter.clone();
+    }
+    let mut target = Map::new();
+    for key in ["name", "in", "description", "required", "allowEmptyValue"] {
+        if let Some(value) = source.get(key) {
+            target.insert(key.to_string(), value.clone());
+        }
+    }
+    copy_extensions(source, &mut target);
+    if parameter_in(parameter) == Some("path") {
+        target.insert("required".to_string(), json!(true));
+    }
+    if let Some(collection_format) = source.get("collectionFormat").and_then(Value::as_str) {
+        let (style, explode) = match collection_format {
+            "multi" => ("form", true),
+            "ssv" => ("spaceDelimited", false),
+            "pipes" => ("pipeDelimited", false),
+            _ => match parameter_in(parameter) {
+                Some("query") | Some("cookie") => ("form", false),
+                _ => ("simple", false),
+            },
+        };
+        target.insert("style".to_string(), json!(style));
+        target.insert("explode".to_string(), json!(explode));
+    }
+    target.insert("schema".to_string(), parameter_schema(source));
+    Value::Object(target)
+}
+
+fn body_parameter_to_request_body(parameter: &Value, consumes: &[String]) -> Value {
+    let schema = parameter
+        .get("schema")
+        .map(convert_schema)
+        .unwrap_or_else(|| json!({}));
+    let content = media_types(consumes)
+        .into_iter()
+        .map(|media_type| (media_type, json!({ "schema": schema.clone() })))
+        .collect::<Map<_, _>>();
+    let mut target = Map::new();
+    if let Some(description) = parameter.get("description") {
+        target.insert("description".to_string(), description.clone());
+    }
+    target.insert("content".to_string(), Value::Object(content));
+    if let Some(required) = parameter.get("required") {
+        target.insert("required".to_string(), required.clone());
+    }
+    if let Some(source) = parameter.as_object() {
+        copy_extensions(source, &mut target);
+    }
+    Value::Object(target)
+}
+
+fn form_data_to_request_body(fields: &[Value], consumes: &[String]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let mut has_file = false;
+    for field in fields {
+        let Some(source) = field.as_object() else {
+            continue;
+        };
+        let Some(name) = source.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        has_file |= source.get("type").and_then(Value::as_str) == Some("file");
+        let mut schema = parameter_schema(source);
+        if let (Some(description), Value::Object(schema)) = (source.get("description"), &mut schema)
+        {
+            schema.insert("description".to_string(), description.clone());
+        }
+        properties.insert(name.to_string(), schema);
+        if source.get("required").and_then(Value::as_bool) == Some(true) {
+            required.push(json!(name));
+        }
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+
+    let mut form_types = consumes
+        .iter()
+        .filter(|item| item.as_str() == MULTIPART_FORM_DATA || item.as_str() == FORM_URLENCODED)
+        .cloned()
+        .collect::<Vec<_>>();
+    if form_types.is_empty() {
+        form_types.push(if has_file {
+            MULTIPART_FORM_DATA.to_string()
+        } else {
+            FORM_URLENCODED.to_string()
+        });
+    }
+    let schema = Value::Object(schema);
+    let content = form_types
+        .into_iter()
+        .map(|media_type| (media_type, json!({ "schema": schema.clone() })))
+        .collect::<Map<_, _>>();
+    json!({ "content": content })
+}
+
+fn convert_response(response: &Value, produces: &[String]) -> Value {
+    let Some(source) = response.as_object() else {
+        return response.clone();
+    };
+    if source.contains_key("$ref") {
+        return response.clone();
+    }
+    let mut target = Map::new();
+    target.insert(
+        "description".to_string(),
+        source
+            .get("description")
+            .cloned()
+            .unwrap_or_else(|| json!("")),
+    );
+    copy_extensions(source, &mut target);
+
+    if let Some(schema) = source.get("schema") {
+        let schema = convert_schema(schema);
+        let examples = source.get("examples").and_then(Value::as_object);
+        let content = media_types(produces)
+            .into_iter()
+            .map(|media_type| {
+                let mut media = Map::new();
+                media.insert("schema".to_string(), schema.clone());
+                if let Some(example) = examples.and_then(|items| items.get(&media_type)) {
+                    media.insert("example".to_string(), example.clone());
+                }
+                (media_type, Value::Object(media))
+            })
+            .collect::<Map<_, _>>();
+        target.insert("content".to_string(), Value::Object(content));
+    }
+
+    if let Some(headers) = source.get("headers").and_then(Value::as_object) {
+        let converted = headers
+            .iter()
+            .map(|(name, header)| {
+                let mut target = Map::new();
+                if let Some(header) = header.as_object() {
+                    if let Some(description) = header.get("description") {
+                        target.insert("description".to_string(), description.clone());
+                    }
+                    target.insert("schema".to_string(), parameter_schema(header));
+                }
+                (name.clone(), Value::Object(target))
+            })
+            .collect::<Map<_, _>>();
+        target.insert("headers".to_string(), Value::Object(converted));
+    }
+
+    Value::Object(target)
+}
+
+fn convert_security_scheme(scheme: &Value) -> Value {
+    let Some(source) = scheme.as_object() else {
+        return scheme.clone();
+    };
+    let mut target = Map::new();
+    if let Some(description) = source.get("description") {
+        target.insert("description".to_string(), description.clone());
+    }
+    copy_extensions(source, &mut target);
+    match source.get("type").and_then(Value::as_str) {
+        Some("basic") => {
+            target.insert("type".to_string(), json!("http"));
+            target.insert("scheme".to_string(), json!("basic"));
+        }
+        Some("apiKey") => {
+            target.insert("type".to_string(), json!("apiKey"));
+            for key in ["name", "in"] {
+                if let Some(value) = source.get(key) {
+                    target.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+        Some("oauth2") => {
+            target.insert("type".to_string(), json!("oauth2"));
+            let flow_name = match source.get("flow").and_then(Value::as_str) {
+                Some("implicit") => "implicit",
+                Some("password") => "password",
+                Some("application") => "clientCredentials",
+                _ => "authorizationCode",
+            };
+            let mut flow = Map::new();
+            for key in ["authorizationUrl", "tokenUrl"] {
+                if let Some(value) = source.get(key) {
+                    flow.insert(key.to_string(), value.clone());
+                }
+            }
+            flow.insert(
+                "scopes".to_string(),
+                source.get("scopes").cloned().unwrap_or_else(|| json!({})),
+            );
+            target.insert("flows".to_string(), json!({ flow_name: flow }));
+        }
+        _ => return scheme.clone(),
+    }
+    Value::Object(target)
+}
+
+/// 把 2.0 的引用路径改写为 OpenAPI 3 的 components 路径
+fn rewrite_refs(value: &mut Value, body_parameter_names: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if key == "$ref" {
+                    if let Value::String(reference) = item {
+                        *reference = rewrite_ref(reference, body_parameter_names);
+                    }
+                } else {
+                    rewrite_refs(item, body_parameter_names);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                rewrite_refs(item, body_parameter_names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn rewrite_ref(reference: &str, body_parameter_names: &[String]) -> String {
+    let (location, pointer) = match reference.find('#') {
+        Some(index) => reference.split_at(index),
+        None => return reference.to_string(),
+    };
+    let pointer = if let Some(name) = pointer.strip_prefix("#/definitions/") {
+        format!("#/components/schemas/{name}")
+    } else if let Some(name) = pointer.strip_prefix("#/parameters/") {
+        if body_parameter_names.iter().any(|item| item == name) {
+            format!("#/components/requestBodies/{name}")
+        } else {
+            format!("#/components/parameters/{name}")
+        }
+    } else if let Some(name) = pointer.strip_prefix("#/responses/") {
+        format!("#/components/responses/{name}")
+    } else if let Some(name) = pointer.strip_prefix("#/securityDefinitions/") {
+        format!("#/components/securitySchemes/{name}")
+    } else {
+        pointer.to_string()
+    };
+    format!("{location}{pointer}")
+}
@@ -0,0 +1,937 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::getter::{
+    escape_json_pointer, get_all_schema, get_schema_by_name, get_schema_name_from_ref,
+};
+use crate::model::{
+    MediaTypeObject, OpenAPIObject, OperationObject, ParameterObject, PathItemObject, SchemaEnum,
+    SchemaObject, SchemaStringFormat,
+};
+
+use super::{ChangeSeverity, SpecChange};
+
+/// 比较新旧两个版本的文档，找出会影响前端的变更，结果按 JSON Pointer 排序
+///
+/// 规则：
+/// - `operation-removed` / `operation-added`：接口删除 / 新增
+/// - `operation-moved`：相同 operationId 的接口换了路径或方法
+/// - `operation-id-changed`：operationId 改变，生成的函数名随之改变
+/// - `parameter-removed` / `parameter-added`：参数删除 / 新增，新增必填参数为 breaking
+/// - `parameter-became-required`：可选参数变为必填
+/// - `request-body-added` / `request-body-removed` / `request-body-became-required`
+/// - `media-type-removed`：请求体或响应不再支持某个 content type
+/// - `response-removed`：2xx 响应被删除
+/// - `schema-removed` / `schema-added`：components 中的 schema 删除 / 新增
+/// - `schema-ref-changed`：引用的模型改变
+/// - `type-changed`：类型改变
+/// - `type-narrowed` / `type-widened`：请求中收窄、响应中放宽为 breaking，
+///   包括 oneOf / anyOf 成员减少 / 增加、allOf 成员增加 / 减少
+/// - `enum-member-removed` / `enum-member-added`：删除成员总是 breaking，新增成员在响应中为 breaking
+/// - `property-removed` / `property-added` / `property-became-required` / `property-became-optional`
+/// - `nullable-added` / `nullable-removed`
+pub fn diff_open_api(old: &OpenAPIObject, new: &OpenAPIObject) -> Vec<SpecChange> {
+    let mut differ = Differ {
+        old,
+        new,
+        changes: Vec::new(),
+        visited: HashSet::new(),
+    };
+    differ.diff_operations();
+    differ.diff_component_schemas();
+
+    let mut changes = differ.changes;
+    changes.sort_by(|a, b| {
+        a.pointer
+            .cmp(&b.pointer)
+            .then(a.rule.cmp(&b.rule))
+            .then(a.message.cmp(&b.message))
+            .then(a.severity.cmp(&b.severity))
+    });
+    // 同一个 schema 同时用于请求和响应时只保留更严重的一条
+    changes.dedup_by(|a, b| a.pointer == b.pointer && a.rule == b.rule && a.message == b.message);
+    changes
+}
+
+/// 是否存在 breaking 变更
+pub fn has_breaking_changes(changes: &[SpecChange]) -> bool {
+    changes
+        .iter()
+        .any(|item| item.severity == ChangeSeverity::Breaking)
+}
+
+/// schema 出现的位置：请求中收窄类型、响应中放宽类型会破坏现有调用
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Request,
+    Response,
+}
+
+type OperationMap<'a> = BTreeMap<(String, &'static str), (&'a PathItemObject, &'a OperationObject)>;
+
+struct Differ<'a> {
+    old: &'a OpenAPIObject,
+    new: &'a OpenAPIObject,
+    changes: Vec<SpecChange>,
+    /// 已经比较过的 (schema 名称, 方向)，避免循环引用和重复比较
+    visited: HashSet<(String, Direction)>,
+}
+
+impl<'a> Differ<'a> {
+    fn push(&mut self, rule: &str, severity: ChangeSeverity, pointer: &str, message: String) {
+        self.changes
+            .push(SpecChange::new(rule, severity, pointer, message));
+    }
+
+    /// 只在 `breaking_in` 方向上是 breaking 的变更
+    fn push_directional(
+        &mut self,
+        rule: &str,
+        breaking_in: Direction,
+        direction: Direction,
+        pointer: &str,
+        message: String,
+    ) {
+        let severity = if breaking_in == direction {
+            ChangeSeverity::Breaking
+        } else {
+            ChangeSeverity::NonBreaking
+        };
+        self.push(rule, severity, pointer, message);
+    }
+
+    fn diff_operations(&mut self) {
+        let old_operations = collect_operations(self.old);
+        let new_operations = collect_operations(self.new);
+        let mut moved = HashSet::new();
+
+        for ((path, method), (old_item, old_operation)) in &old_operations {
+            let key = (path.clone(), *method);
+            if let Some((new_item, new_operation)) = new_operations.get(&key) {
+                let pointer = operation_pointer(path, method);
+                self.diff_operation(
+                    &pointer,
+                    (old_item, old_operation),
+                    (new_item, new_operation),
+                );
+                continue;
+            }
+
+            let target = old_operation.operation_id.as_ref().and_then(|id| {
+                new_operations.iter().find(|(key, (_, operation))| {
+                    !old_operations.contains_key(*key)
+                        && operation.operation_id.as_ref() == Some(id)
+                })
+            });
+            match target {
+                Some(((new_path, new_method), (new_item, new_operation))) => {
+                    let pointer = operation_pointer(new_path, new_method);
+                    self.push(
+                        "operation-moved",
+                        ChangeSeverity::Breaking,
+                        &pointer,
+                        format!(
+                            "`{}` moved from {} to {}",
+                            old_operation.operation_id.as_deref().unwrap_or_default(),
+                            operation_label(path, method),
+                            operation_label(new_path, new_method)
+                        ),
+                    );
+                    moved.insert((new_path.clone(), *new_method));
+                    self.diff_operation(
+                        &pointer,
+                        (old_item, old_operation),
+                        (new_item, new_operation),
+                    );
+                }
+                None => self.push(
+                    "operation-removed",
+                    ChangeSeverity::Breaking,
+                    &operation_pointer(path, method),
+                    format!("{} was removed", operation_label(path, method)),
+                ),
+            }
+        }
+
+        for (path, method) in new_operations.keys() {
+            let key = (path.clone(), *method);
+            if !old_operations.contains_key(&key) && !moved.contains(&key) {
+                self.push(
+                    "operation-added",
+                    ChangeSeverity::NonBreaking,
+                    &operation_pointer(path, method),
+                    format!("{} was added", operation_label(path, method)),
+                );
+            }
+        }
+    }
+
+    fn diff_operation(
+        &mut self,
+        pointer: &str,
+        (old_item, old_operation): (&PathItemObject, &OperationObject),
+        (new_item, new_operation): (&PathItemObject, &OperationObject),
+    ) {
+        if let (Some(old_id), Some(new_id)) =
+            (&old_operation.operation_id, &new_operation.operation_id)
+        {
+            if old_id != new_id {
+                self.push(
+                    "operation-id-changed",
+                    ChangeSeverity::Breaking,
+                    &format!("{pointer}/operationId"),
+                    format!("operationId changed from `{old_id}` to `{new_id}`"),
+                );
+            }
+        }
+
+        let path_pointer = pointer
+            .rsplit_once('/')
+            .map(|(path, _)| path)
+            .unwrap_or(pointer);
+        let old_parameters =
+            collect_parameters(self.old, path_pointer, old_item, pointer, old_operation);
+        let new_parameters =
+            collect_parameters(self.new, path_pointer, new_item, pointer, new_operation);
+        self.diff_parameters(&old_parameters, &new_parameters);
+        self.diff_request_body(pointer, old_operation, new_operation);
+        self.diff_responses(pointer, old_operation, new_operation);
+    }
+
+    fn diff_parameters(
+        &mut self,
+        old: &[(String, &ParameterObject)],
+        new: &[(String, &ParameterObject)],
+    ) {
+        for (pointer, old_parameter) in old {
+            let label = format!(
+                "{} parameter `{}`",
+                old_parameter.r#in.as_str(),
+                old_parameter.name
+            );
+            let Some((new_pointer, new_parameter)) = new.iter().find(|(_, item)| {
+                item.name == old_parameter.name && item.r#in == old_parameter.r#in
+            }) else {
+                self.push(
+                    "parameter-removed",
+                    ChangeSeverity::Breaking,
+                    pointer,
+                    format!("{label} was removed"),
+                );
+                continue;
+            };
+            if !old_parameter.required.unwrap_or(false) && new_parameter.required.unwrap_or(false) {
+                self.push(
+                    "parameter-became-required",
+                    ChangeSeverity::Breaking,
+                    new_pointer,
+                    format!("{label} became required"),
+                );
+            }
+            if let (Some(old_schema), Some(new_schema)) =
+                (&old_parameter.schema, &new_parameter.schema)
+            {
+                self.diff_schema(
+                    &format!("{new_pointer}/schema"),
+                    old_schema,
+                    new_schema,
+                    Direction::Request,
+                );
+            }
+        }
+        for (pointer, new_parameter) in new {
+            if old
+                .iter()
+                .any(|(_, item)| item.name == new_parameter.name && item.r#in == new_parameter.r#in)
+            {
+                continue;
+            }
+            let label = format!(
+                "{} parameter `{}`",
+                new_parameter.r#in.as_str(),
+                new_parameter.name
+            );
+            if new_parameter.required.unwrap_or(false) {
+                self.push(
+                    "parameter-added",
+                    ChangeSeverity::Breaking,
+                    pointer,
+                    format!("required {label} was added"),
+                );
+            } else {
+                self.push(
+                    "parameter-added",
+                    ChangeSeverity::NonBreaking,
+                    pointer,
+                    format!("optional {label} was added"),
+                );
+            }
+        }
+    }
+
+    fn diff_request_body(
+        &mut self,
+        pointer: &str,
+        old_operation: &OperationObject,
+        new_operation: &OperationObject,
+    ) {
+        let pointer = format!("{pointer}/requestBody");
+        let old_body = old_operation
+            .request_body
+            .as_ref()
+            .and_then(|body| body.resolve(self.old));
+        let new_body = new_operation
+            .request_body
+            .as_ref()
+            .and_then(|body| body.resolve(self.new));
+        match (old_body, new_body) {
+            (None, None) => {}
+            (None, Some(new_body)) => {
+                if new_body.required.unwrap_or(false) {
+                    self.push(
+                        "request-body-added",
+                        ChangeSeverity::Breaking,
+                        &pointer,
+                        "required request body was added".to_string(),
+                    );
+                } else {
+                    self.push(
+                        "request-body-added",
+                        ChangeSeverity::NonBreaking,
+                        &pointer,
+                        "optional request body was added".to_string(),
+                    );
+                }
+            }
+            (Some(_), None) => self.push(
+                "request-body-removed",
+                ChangeSeverity::Breaking,
+                &pointer,
+                "request body was removed".to_string(),
+            ),
+            (Some(old_body), Some(new_body)) => {
+                if !old_body.required.unwrap_or(false) && new_body.required.unwrap_or(false) {
+                    self.push(
+                        "request-body-became-required",
+                        ChangeSeverity::Breaking,
+                        &pointer,
+                        "request body became required".to_string(),
+                    );
+                }
+                self.diff_content(
+                    &pointer,
+                    &old_body.content,
+                    &new_body.content,
+                    Direction::Request,
+                );
+            }
+        }
+    }
+
+    fn diff_responses(
+        &mut self,
+        pointer: &str,
+        old_operation: &OperationObject,
+        new_operation: &OperationObject,
+    ) {
+        let (Some(old_responses), Some(new_responses)) =
+            (&old_operation.responses, &new_operation.responses)
+        else {
+            return;
+        };
+        for (status, old_value) in old_responses {
+            let pointer = format!("{pointer}/responses/{}", escape_json_pointer(status));
+            let Some(new_value) = new_responses.get(status) else {
+                if status.starts_with('2') {
+                    self.push(
+                        "response-removed",
+                        ChangeSeverity::Breaking,
+                        &pointer,
+                        format!("`{status}` response was removed"),
+                    );
+                }
+                continue;
+            };
+            let old_content = old_value
+                .resolve(self.old)
+                .and_then(|response| response.content.as_ref());
+            let new_content = new_value
+                .resolve(self.new)
+                .and_then(|response| response.content.as_ref());
+            if let (Some(old_content), Some(new_content)) = (old_content, new_content) {
+                self.diff_content(&pointer, old_content, new_content, Direction::Response);
+            }
+        }
+    }
+
+    fn diff_content(
+        &mut self,
+        pointer: &str,
+        old: &BTreeMap<String, MediaTypeObject>,
+        new: &BTreeMap<String, MediaTypeObject>,
+        direction: Direction,
+    ) {
+        for (media_type, old_media) in old {
+            let pointer = format!("{pointer}/content/{}", escape_json_pointer(media_type));
+            match new.get(media_type) {
+                Some(new_media) => self.diff_media_schema(
+                    &format!("{pointer}/schema"),
+                    old_media.schema.as_ref(),
+                    new_media.schema.as_ref(),
+                    direction,
+                ),
+                None => self.push(
+                    "media-type-removed",
+                    ChangeSeverity::Breaking,
+                    &pointer,
+                    format!("`{media_type}` is no longer supported"),
+                ),
+            }
+        }
+    }
+
+    /// 媒体类型的 schema，不声明 schema 视为不限制内容
+    fn diff_media_schema(
+        &mut self,
+        pointer: &str,
+        old: Option<&SchemaEnum>,
+        new: Option<&SchemaEnum>,
+        direction: Direction,
+    ) {
+        match (old, new) {
+            (Some(old), Some(new)) => self.diff_schema(pointer, old, new, direction),
+            (Some(_), None) => self.push_directional(
+                "type-widened",
+                Direction::Response,
+                direction,
+                pointer,
+                "schema was removed".to_string(),
+            ),
+            (None, Some(_)) => self.push_directional(
+                "type-narrowed",
+                Direction::Request,
+                direction,
+                pointer,
+                "schema was added".to_string(),
+            ),
+            (None, None) => {}
+        }
+    }
+
+    fn diff_component_schemas(&mut self) {
+        let empty = BTreeMap::new();
+        let old_schemas = get_all_schema(self.old).unwrap_or(&empty);
+        let new_schemas = get_all_schema(self.new).unwrap_or(&empty);
+        for name in old_schemas.keys() {
+            if !new_schemas.contains_key(name) {
+                self.push(
+                    "schema-removed",
+                    ChangeSeverity::Breaking,
+                    &schema_pointer(name),
+                    format!("schema `{name}` was removed"),
+                );
+            }
+        }
+        for name in new_schemas.keys() {
+            if !old_schemas.contains_key(name) {
+                self.push(
+                    "schema-added",
+                    ChangeSeverity::NonBreaking,
+                    &schema_pointer(name),
+                    format!("schema `{name}` was added"),
+                );
+            }
+        }
+    }
+
+    /// 比较引用的 components schema，同一方向上只比较一次
+    fn diff_ref(&mut self, r#ref: &str, direction: Direction) {
+        let Some(name) = get_schema_name_from_ref(r#ref) else {
+            return;
+        };
+        if !self.visited.insert((name.to_string(), direction)) {
+            return;
+        }
+        // schema 被删除时由 `schema-removed` 报告
+        let (old_api, new_api) = (self.old, self.new);
+        if let (Some(old), Some(new)) = (
+            get_schema_by_name(old_api, name),
+            get_schema_by_name(new_api, name),
+        ) {
+            self.diff_schema(&schema_pointer(name), old, new, direction);
+        }
+    }
+
+    fn diff_schema(
+        &mut self,
+        pointer: &str,
+        old: &SchemaEnum,
+        new: &SchemaEnum,
+        direction: Direction,
+    ) {
+        let (old_api, new_api) = (self.old, self.new);
+        match (old, new) {
+            (SchemaEnum::Ref(old_ref), SchemaEnum::Ref(new_ref)) => {
+                if old_ref.r#ref == new_ref.r#ref {
+                    self.diff_ref(&old_ref.r#ref, direction);
+                } else {
+                    self.push(
+                        "schema-ref-changed",
+                        ChangeSeverity::Breaking,
+                        pointer,
+                        format!(
+                            "type changed from `{}` to `{}`",
+                            ref_name(&old_ref.r#ref),
+                            ref_name(&new_ref.r#ref)
+                        ),
+                    );
+                }
+                return;
+            }
+            // 内联 schema 与引用互换时比较展开后的结构
+            (SchemaEnum::Ref(old_ref), _) => {
+                if let Some(old) = resolve_schema_ref(old_api, &old_ref.r#ref) {
+                    self.diff_schema(pointer, old, new, direction);
+                }
+                return;
+            }
+            (_, SchemaEnum::Ref(new_ref)) => {
+                if let Some(new) = resolve_schema_ref(new_api, &new_ref.r#ref) {
+                    self.diff_schema(pointer, old, new, direction);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        let (old_kind, new_kind) = (schema_kind(old), schema_kind(new));
+        if old_kind != new_kind {
+            match (old_kind, new_kind) {
+                ("integer", "number") => self.push_directional(
+                    "type-widened",
+                    Direction::Response,
+                    direction,
+                    pointer,
+                    "type widened from integer to number".to_string(),
+                ),
+                ("number", "integer") => self.push_directional(
+                    "type-narrowed",
+                    Direction::Request,
+                    direction,
+                    pointer,
+                    "type narrowed from number to integer".to_string(),
+                ),
+                _ => self.push(
+                    "type-changed",
+                    ChangeSeverity::Breaking,
+                    pointer,
+                    format!("type changed from {old_kind} to {new_kind}"),
+                ),
+            }
+            return;
+        }
+
+        self.diff_nullable(pointer, old, new, direction);
+        self.diff_enum(pointer, enum_values(old), enum_values(new), direction);
+        match (old, new) {
+            (SchemaEnum::String(old), SchemaEnum::String(new)) => {
+                if old.format != new.format {
+                    let format = |value: &Option<SchemaStringFormat>| {
+                        value
+                            .as_ref()
+                            .map_or("none", SchemaStringFormat::as_str)
+                            .to_string()
+                    };
+                    self.push(
+                        "type-changed",
+                        ChangeSeverity::Breaking,
+                        pointer,
+                        format!(
+                            "format changed from {} to {}",
+                            format(&old.format),
+                            format(&new.format)
+                        ),
+                    );
+                }
+                self.diff_range(
+                    pointer,
+                    "length",
+                    (old.min_length.map(f64::from), old.max_length.map(f64::from)),
+                    (new.min_length.map(f64::from), new.max_length.map(f64::from)),
+                    direction,
+                );
+            }
+            (SchemaEnum::Integer(old), SchemaEnum::Integer(new)) => self.diff_range(
+                pointer,
+                "value",
+                (number_bound(&old.minimum), number_bound(&old.maximum)),
+                (number_bound(&new.minimum), number_bound(&new.maximum)),
+                direction,
+            ),
+            (SchemaEnum::Number(old), SchemaEnum::Number(new)) => self.diff_range(
+                pointer,
+                "value",
+                (number_bound(&old.minimum), number_bound(&old.maximum)),
+                (number_bound(&new.minimum), number_bound(&new.maximum)),
+                direction,
+            ),
+            (SchemaEnum::Array(old), SchemaEnum::Array(new)) => {
+                self.diff_schema(
+                    &format!("{pointer}/items"),
+                    &old.items,
+                    &new.items,
+                    direction,
+                );
+            }
+            (SchemaEnum::Object(old), SchemaEnum::Object(new)) => {
+                self.diff_properties(pointer, old, new, direction);
+            }
+            (SchemaEnum::AllOf(old), SchemaEnum::AllOf(new)) => {
+                self.diff_members(pointer, "allOf", &old.all_of, &new.all_of, direction);
+            }
+            (SchemaEnum::OneOf(old), SchemaEnum::OneOf(new)) => {
+                self.diff_members(pointer, "oneOf", &old.one_of, &new.one_of, direction);
+            }
+            (SchemaEnum::AnyOf(old), SchemaEnum::AnyOf(new)) => {
+                self.diff_members(pointer, "anyOf", &old.any_of, &new.any_of, direction);
+            }
+            _ => {}
+        }
+    }
+
+    fn diff_nullable(
+        &mut self,
+        pointer: &str,
+        old: &SchemaEnum,
+        new: &SchemaEnum,
+        direction: Direction,
+    ) {
+        match (is_nullable(old), is_nullable(new)) {
+            (false, true) => self.push_directional(
+                "nullable-added",
+                Direction::Response,
+                direction,
+                pointer,
+                "value can now be null".to_string(),
+            ),
+            (true, false) => self.push_directional(
+                "nullable-removed",
+                Direction::Request,
+                direction,
+                pointer,
+                "value can no longer be null".to_string(),
+            ),
+            _ => {}
+        }
+    }
+
+    fn diff_enum(
+        &mut self,
+        pointer: &str,
+        old: Option<Vec<String>>,
+        new: Option<Vec<String>>,
+        direction: Direction,
+    ) {
+        match (old, new) {
+            (Some(old), Some(new)) => {
+                let removed = old
+                    .iter()
+                    .filter(|value| !new.contains(value))
+                    .map(|value| format!("`{value}`"))
+                    .collect::<Vec<_>>();
+                if !removed.is_empty() {
+                    self.push(
+                        "enum-member-removed",
+                        ChangeSeverity::Breaking,
+                        pointer,
+                        format!("enum members removed: {}", removed.join(", ")),
+                    );
+                }
+                let added = new
+                    .iter()
+                    .filter(|value| !old.contains(value))
+                    .map(|value| format!("`{value}`"))
+                    .collect::<Vec<_>>();
+                // 响应中出现客户端不认识的枚举值
+                if !added.is_empty() {
+                    self.push_directional(
+                        "enum-member-added",
+                        Direction::Response,
+                        direction,
+                        pointer,
+                        format!("enum members added: {}", added.join(", ")),
+                    );
+                }
+            }
+            (None, Some(_)) => self.push_directional(
+                "type-narrowed",
+                Direction::Request,
+                direction,
+                pointer,
+                "values are now restricted to an enum".to_string(),
+            ),
+            (Some(_), None) => self.push_directional(
+                "type-widened",
+                Direction::Response,
+                direction,
+                pointer,
+                "enum restriction was removed".to_string(),
+            ),
+            (None, None) => {}
+        }
+    }
+
+    /// 比较取值范围，只报告收窄
+    fn diff_range(
+        &mut self,
+        pointer: &str,
+        label: &str,
+        (old_min, old_max): (Option<f64>, Option<f64>),
+        (new_min, new_max): (Option<f64>, Option<f64>),
+        direction: Direction,
+    ) {
+        let min_raised = match (old_min, new_min) {
+            (Some(old), Some(new)) => new > old,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        let max_lowered = match (old_max, new_max) {
+            (Some(old), Some(new)) => new < old,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        if min_raised || max_lowered {
+            self.push_directional(
+                "type-narrowed",
+                Direction::Request,
+                direction,
+                pointer,
+                format!("allowed {label} range was narrowed"),
+            );
+        }
+    }
+
+    fn diff_properties(
+        &mut self,
+        pointer: &str,
+        old: &SchemaObject,
+        new: &SchemaObject,
+        direction: Direction,
+    ) {
+        let empty = BTreeMap::new();
+        let old_properties = old.properties.as_ref().unwrap_or(&empty);
+        let new_properties = new.properties.as_ref().unwrap_or(&empty);
+        let old_required = old.required.as_deref().unwrap_or_default();
+        let new_required = new.required.as_deref().unwrap_or_default();
+
+        for (name, old_property) in old_properties {
+            let property_pointer = format!("{pointer}/properties/{}", escape_json_pointer(name));
+            let Some(new_property) = new_properties.get(name) else {
+                self.push(
+                    "property-removed",
+                    ChangeSeverity::Breaking,
+                    &property_pointer,
+                    format!("property `{name}` was removed"),
+                );
+                continue;
+            };
+            match (old_required.contains(name), new_required.contains(name)) {
+                (false, true) => self.push_directional(
+                    "property-became-required",
+                    Direction::Request,
+                    direction,
+                    &property_pointer,
+                    format!("property `{name}` became required"),
+                ),
+                (true, false) => self.push_directional(
+                    "property-became-optional",
+                    Direction::Response,
+                    direction,
+                    &property_pointer,
+                    format!("property `{name}` became optional"),
+                ),
+                _ => {}
+            }
+            self.diff_schema(&property_pointer, old_property, new_property, direction);
+        }
+
+        for name in new_properties.keys() {
+            if old_properties.contains_key(name) {
+                continue;
+            }
+            let property_pointer = format!("{pointer}/properties/{}", escape_json_pointer(name));
+            if new_required.contains(name) {
+                self.push_directional(
+                    "property-added",
+                    Direction::Request,
+                    direction,
+                    &property_pointer,
+                    format!("required property `{name}` was added"),
+                );
+            } else {
+                self.push(
+                    "property-added",
+                    ChangeSeverity::NonBreaking,
+                    &property_pointer,
+                    format!("property `{name}` was added"),
+                );
+            }
+        }
+    }
+
+    fn diff_members(
+        &mut self,
+        pointer: &str,
+        keyword: &str,
+        old: &[SchemaEnum],
+        new: &[SchemaEnum],
+        direction: Direction,
+    ) {
+        if old.len() != new.len() {
+            // oneOf / anyOf 的成员越多可接受的值越多，allOf 则相反
+            let widened = if keyword == "allOf" {
+                new.len() < old.len()
+            } else {
+                new.len() > old.len()
+            };
+            let (rule, breaking_in) = if widened {
+                ("type-widened", Direction::Response)
+            } else {
+                ("type-narrowed", Direction::Request)
+            };
+            self.push_directional(
+                rule,
+                breaking_in,
+                direction,
+                pointer,
+                format!(
+                    "`{keyword}` changed from {} to {} members",
+                    old.len(),
+                    new.len()
+                ),
+            );
+            return;
+        }
+        for (index, (old, new)) in old.iter().zip(new).enumerate() {
+            self.diff_schema(&format!("{pointer}/{keyword}/{index}"), old, new, direction);
+        }
+    }
+}
+
+fn collect_operations(open_api: &OpenAPIObject) -> OperationMap<'_> {
+    let mut result = BTreeMap::new();
+    for (path, path_item) in open_api.paths.iter().flatten() {
+        for (method, operation) in path_item.get_operations() {
+            result.insert((path.clone(), method), (path_item, operation));
+        }
+    }
+    result
+}
+
+/// 合并路径级与操作级参数，同名且位置相同时以操作级参数为准
+fn collect_parameters<'b>(
+    open_api: &'b OpenAPIObject,
+    path_pointer: &str,
+    path_item: &'b PathItemObject,
+    operation_pointer: &str,
+    operation: &'b OperationObject,
+) -> Vec<(String, &'b ParameterObject)> {
+    let operation_parameters = operation
+        .parameters
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(index, parameter)| {
+            let parameter = parameter.resolve(open_api)?;
+            Some((format!("{operation_pointer}/parameters/{index}"), parameter))
+        })
+        .collect::<Vec<_>>();
+    let mut result = path_item
+        .parameters
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(index, parameter)| {
+            let parameter = parameter.resolve(open_api)?;
+            let overridden = operation_parameters
+                .iter()
+                .any(|(_, item)| item.name == parameter.name && item.r#in == parameter.r#in);
+            (!overridden).then(|| (format!("{path_pointer}/parameters/{index}"), parameter))
+        })
+        .collect::<Vec<_>>();
+    result.extend(operation_parameters);
+    result
+}
+
+fn resolve_schema_ref<'b>(open_api: &'b OpenAPIObject, r#ref: &str) -> Option<&'b SchemaEnum> {
+    get_schema_by_name(open_api, get_schema_name_from_ref(r#ref)?)
+}
+
+fn ref_name(r#ref: &str) -> &str {
+    get_schema_name_from_ref(r#ref).unwrap_or(r#ref)
+}
+
+fn operation_pointer(path: &str, method: &str) -> String {
+    format!("#/paths/{}/{method}", escape_json_pointer(path))
+}
+
+fn operation_label(path: &str, method: &str) -> String {
+    format!("{} {path}", method.to_uppercase())
+}
+
+fn schema_pointer(name: &str) -> String {
+    format!("#/components/schemas/{}", escape_json_pointer(name))
+}
+
+fn schema_kind(schema: &SchemaEnum) -> &'static str {
+    match schema {
+        SchemaEnum::Ref(_) => "ref",
+        SchemaEnum::Object(_) => "object",
+        SchemaEnum::String(_) => "string",
+        SchemaEnum::Integer(_) => "integer",
+        SchemaEnum::Number(_) => "number",
+        SchemaEnum::Boolean(_) => "boolean",
+        SchemaEnum::Array(_) => "array",
+        SchemaEnum::AllOf(_) => "allOf",
+        SchemaEnum::OneOf(_) => "oneOf",
+        SchemaEnum::AnyOf(_) => "anyOf",
+        SchemaEnum::Not(_) => "not",
+        SchemaEnum::Any(_) => "any",
+    }
+}
+
+fn is_nullable(schema: &SchemaEnum) -> bool {
+    let nullable = match schema {
+        SchemaEnum::Ref(_) => None,
+        SchemaEnum::Object(v) => v.nullable,
+        SchemaEnum::String(v) => v.nullable,
+        SchemaEnum::Integer(v) => v.nullable,
+        SchemaEnum::Number(v) => v.nullable,
+        SchemaEnum::Boolean(v) => v.nullable,
+        SchemaEnum::Array(v) => v.nullable,
+        SchemaEnum::AllOf(v) => v.nullable,
+        SchemaEnum::OneOf(v) => v.nullable,
+        SchemaEnum::AnyOf(v) => v.nullable,
+        SchemaEnum::Not(v) => v.nullable,
+        SchemaEnum::Any(v) => v.nullable,
+    };
+    nullable.unwrap_or(false)
+}
+
+fn number_bound(value: &Option<serde_json::Number>) -> Option<f64> {
+    value.as_ref().and_then(serde_json::Number::as_f64)
+}
+
+fn enum_values(schema: &SchemaEnum) -> Option<Vec<String>> {
+    match schema {
+        SchemaEnum::String(v) => v.r#enum.clone(),
+        SchemaEnum::Integer(v) => v
+            .r#enum
+            .as_ref()
+            .map(|values| values.iter().map(ToString::to_string).collect()),
+        SchemaEnum::Number(v) => v
+            .r#enum
+            .as_ref()
+            .map(|values| values.iter().map(ToString::to_string).collect()),
+        _ => None,
+    }
+}
@@ -0,0 +1,5 @@
+mod diff_open_api;
+mod spec_change;
+
+pub use diff_open_api::*;
+pub use spec_change::*;
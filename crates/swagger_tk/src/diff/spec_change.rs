@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// 变更级别，存在 breaking 时前端需要跟进修改
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangeSeverity {
+    Breaking,
+    NonBreaking,
+}
+
+impl Display for ChangeSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            ChangeSeverity::Breaking => "breaking",
+            ChangeSeverity::NonBreaking => "non-breaking",
+        };
+        write!(f, "{text}")
+    }
+}
+
+/// 单条变更，`pointer` 为变更所在位置的 JSON Pointer，删除的内容指向旧文档
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SpecChange {
+    pub rule: String,
+    pub severity: ChangeSeverity,
+    pub pointer: String,
+    pub message: String,
+}
+
+impl SpecChange {
+    pub fn new(
+        rule: &str,
+        severity: ChangeSeverity,
+        pointer: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rule: rule.to_string(),
+            severity,
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for SpecChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} [{}] {}: {}",
+            self.severity, self.rule, self.pointer, self.message
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    getter::{get_callback_by_ref, get_path_item_by_ref},
+    model::{CallbackValue, ComponentsCallbacks, OpenAPIObject, PathItemObject},
+};
+
+impl ComponentsCallbacks {
+    /// 获取回调定义（运行时表达式到路径项的映射），引用会解析为 components 中的回调
+    pub fn resolve<'a>(
+        &'a self,
+        open_api: &'a OpenAPIObject,
+    ) -> Option<&'a BTreeMap<String, CallbackValue>> {
+        match self {
+            ComponentsCallbacks::Callback(v) => Some(v),
+            ComponentsCallbacks::Reference(v) => get_callback_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
+
+impl CallbackValue {
+    /// 获取回调的路径项，引用会解析为 components 中的路径项
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a PathItemObject> {
+        match self {
+            CallbackValue::PathItem(v) => match &v.r#ref {
+                Some(r#ref) => get_path_item_by_ref(open_api, r#ref),
+                None => Some(v),
+            },
+            CallbackValue::Reference(v) => get_path_item_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
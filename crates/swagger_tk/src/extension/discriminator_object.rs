@@ -0,0 +1,42 @@
+use crate::{getter::get_schema_name_from_ref, model::DiscriminatorObject};
+
+impl DiscriminatorObject {
+    /// 获取某个 schema 对应的判别值；mapping 中没有指向该 schema 时使用 schema 名称本身
+    pub fn get_tags(&self, schema_name: &str) -> Vec<String> {
+        let mut tags = self
+            .mapping
+            .as_ref()
+            .map(|mapping| {
+                mapping
+                    .iter()
+                    .filter(|(_, target)| {
+                        get_schema_name_from_ref(target).unwrap_or(target) == schema_name
+                    })
+                    .map(|(tag, _)| tag.clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if tags.is_empty() {
+            tags.push(schema_name.to_string());
+        }
+        tags.sort();
+        tags
+    }
+
+    /// 获取 mapping 中引用到的 schema 名称
+    pub fn get_mapping_schema_names(&self) -> Vec<&str> {
+        let mut names = self
+            .mapping
+            .as_ref()
+            .map(|mapping| {
+                mapping
+                    .values()
+                    .map(|target| get_schema_name_from_ref(target).unwrap_or(target))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
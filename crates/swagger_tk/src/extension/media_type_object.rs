@@ -1,9 +1,57 @@
-use crate::{getter::get_schema_name_from_ref, model::MediaTypeObject};
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::{
+    getter::get_schema_name_from_ref,
+    model::{ComponentsExamples, MediaTypeObject, SchemaEnum, SchemaStringFormat},
+};
 
 impl MediaTypeObject {
     pub fn get_ref_schema_name(&self) -> Option<&str> {
         self.schema
+            .as_ref()?
             .get_ref_full_name()
             .and_then(|name| get_schema_name_from_ref(name))
     }
+
+    /// 获取示例值，依次取 `example`、第一个内联的命名示例、schema 上的示例
+    pub fn get_example(&self) -> Option<&Value> {
+        self.example
+            .as_ref()
+            .or_else(|| first_example_value(self.examples.as_ref()))
+            .or_else(|| self.schema.as_ref()?.get_example())
+    }
+
+    /// 是否为二进制内容：文件类媒体类型（`application/octet-stream`、`application/pdf`、
+    /// `image/*` 等）或 `type: string, format: binary` 的 schema；不声明 schema 时只看媒体类型
+    pub fn is_binary(&self, media_type: &str) -> bool {
+        let essence = media_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let binary_media_type = matches!(
+            essence.as_str(),
+            "application/octet-stream" | "application/pdf" | "application/zip"
+        ) || ["image/", "audio/", "video/"]
+            .iter()
+            .any(|prefix| essence.starts_with(prefix));
+        binary_media_type
+            || matches!(
+                &self.schema,
+                Some(SchemaEnum::String(schema)) if matches!(schema.format, Some(SchemaStringFormat::Binary))
+            )
+    }
+}
+
+/// 第一个内联命名示例的值，引用的示例不解析
+pub(crate) fn first_example_value(
+    examples: Option<&BTreeMap<String, ComponentsExamples>>,
+) -> Option<&Value> {
+    examples?.values().find_map(|example| match example {
+        ComponentsExamples::Example(example) => example.value.as_ref(),
+        ComponentsExamples::Reference(_) => None,
+    })
 }
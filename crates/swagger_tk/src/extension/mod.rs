@@ -1,3 +1,15 @@
+mod callback_value;
+mod discriminator_object;
 mod media_type_object;
+mod open_api_webhooks;
+mod operation_object_parameters;
+mod operation_object_request_body;
+mod operation_object_responses;
+mod parameter_object;
+mod path_item_object;
+mod request_body_object;
+mod response_headers;
 mod response_value;
 mod schema_enum;
+mod server_object;
+mod spec_extensions;
@@ -0,0 +1,17 @@
+use crate::{
+    getter::get_path_item_by_ref,
+    model::{OpenAPIObject, OpenAPIWebhooks, PathItemObject},
+};
+
+impl OpenAPIWebhooks {
+    /// 获取 webhook 的路径项，引用会解析为 components 中的路径项
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a PathItemObject> {
+        match self {
+            OpenAPIWebhooks::PathItem(v) => match &v.r#ref {
+                Some(r#ref) => get_path_item_by_ref(open_api, r#ref),
+                None => Some(v),
+            },
+            OpenAPIWebhooks::Reference(v) => get_path_item_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    getter::get_parameter_by_ref,
+    model::{OpenAPIObject, OperationObjectParameters, ParameterObject, PathItemParameters},
+};
+
+impl OperationObjectParameters {
+    /// 获取参数定义，引用会解析为 components 中的参数
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a ParameterObject> {
+        match self {
+            OperationObjectParameters::Parameter(v) => Some(v),
+            OperationObjectParameters::Reference(v) => get_parameter_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
+
+impl PathItemParameters {
+    /// 获取参数定义，引用会解析为 components 中的参数
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a ParameterObject> {
+        match self {
+            PathItemParameters::Parameter(v) => Some(v),
+            PathItemParameters::Reference(v) => get_parameter_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
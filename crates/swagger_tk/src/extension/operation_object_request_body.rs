@@ -0,0 +1,14 @@
+use crate::{
+    getter::get_request_body_by_ref,
+    model::{OpenAPIObject, OperationObjectRequestBody, RequestBodyObject},
+};
+
+impl OperationObjectRequestBody {
+    /// 获取请求体定义，引用会解析为 components 中的请求体
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a RequestBodyObject> {
+        match self {
+            OperationObjectRequestBody::RequestBody(v) => Some(v),
+            OperationObjectRequestBody::Reference(v) => get_request_body_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::model::{OperationObject, ResponsesValue};
+
+impl OperationObject {
+    /// 获取成功响应（`2xx` 状态码及 `2XX` 范围），按状态码排序
+    ///
+    /// 仅在未声明任何状态码时才使用 `default`，避免把错误响应当作成功响应
+    pub fn get_success_responses(&self) -> Vec<(&str, &ResponsesValue)> {
+        let Some(responses) = self.responses.as_ref() else {
+            return vec![];
+        };
+        let success = responses
+            .iter()
+            .filter(|(code, _)| is_success_status(code))
+            .map(|(code, response)| (code.as_str(), response))
+            .collect::<Vec<_>>();
+        if !success.is_empty() || responses.keys().any(|code| code != "default") {
+            return success;
+        }
+        responses
+            .get_key_value("default")
+            .map(|(code, response)| vec![(code.as_str(), response)])
+            .unwrap_or_default()
+    }
+
+    /// 获取错误响应（`4xx`、`5xx` 状态码及范围），按状态码排序，`default` 排在最后
+    ///
+    /// 未声明任何状态码时 `default` 视为成功响应，不会包含在内
+    pub fn get_error_responses(&self) -> Vec<(&str, &ResponsesValue)> {
+        let Some(responses) = self.responses.as_ref() else {
+            return vec![];
+        };
+        let mut errors = responses
+            .iter()
+            .filter(|(code, _)| is_status_class(code, b'4') || is_status_class(code, b'5'))
+            .map(|(code, response)| (code.as_str(), response))
+            .collect::<Vec<_>>();
+        if responses.keys().any(|code| code != "default") {
+            if let Some((code, response)) = responses.get_key_value("default") {
+                errors.push((code.as_str(), response));
+            }
+        }
+        errors
+    }
+}
+
+fn is_success_status(code: &str) -> bool {
+    is_status_class(code, b'2')
+}
+
+/// 是否为指定类别的状态码，如 `404`、`4XX`
+fn is_status_class(code: &str, class: u8) -> bool {
+    let bytes = code.as_bytes();
+    bytes.len() == 3
+        && bytes[0] == class
+        && (bytes[1..].iter().all(u8::is_ascii_digit) || bytes[1..].eq_ignore_ascii_case(b"xx"))
+}
@@ -0,0 +1,19 @@
+use serde_json::Value;
+
+use super::media_type_object::first_example_value;
+use crate::model::ParameterObject;
+
+impl ParameterObject {
+    /// 获取 schema 上的默认值
+    pub fn get_default(&self) -> Option<&Value> {
+        self.schema.as_ref().and_then(|schema| schema.get_default())
+    }
+
+    /// 获取示例值，依次取 `example`、第一个内联的命名示例、schema 上的示例
+    pub fn get_example(&self) -> Option<&Value> {
+        self.example
+            .as_ref()
+            .or_else(|| first_example_value(self.examples.as_ref()))
+            .or_else(|| self.schema.as_ref().and_then(|schema| schema.get_example()))
+    }
+}
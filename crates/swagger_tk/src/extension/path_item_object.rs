@@ -0,0 +1,31 @@
+use crate::model::{OperationObject, PathItemObject, ServerObject};
+
+impl PathItemObject {
+    /// 获取已声明的接口，方法名为小写；常用方法排在前面，生成代码时的命名冲突按此顺序解决
+    pub fn get_operations(&self) -> Vec<(&'static str, &OperationObject)> {
+        [
+            ("get", &self.get),
+            ("post", &self.post),
+            ("put", &self.put),
+            ("patch", &self.patch),
+            ("delete", &self.delete),
+            ("head", &self.head),
+            ("options", &self.options),
+            ("trace", &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_ref()?)))
+        .collect()
+    }
+
+    /// 获取接口实际生效的服务地址，接口上的 `servers` 会覆盖路径上的配置
+    pub fn get_operation_servers<'a>(
+        &'a self,
+        operation: &'a OperationObject,
+    ) -> Option<&'a Vec<ServerObject>> {
+        [operation.servers.as_ref(), self.servers.as_ref()]
+            .into_iter()
+            .flatten()
+            .find(|servers| !servers.is_empty())
+    }
+}
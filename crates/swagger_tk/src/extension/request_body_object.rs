@@ -0,0 +1,23 @@
+use crate::model::{MediaTypeObject, RequestBodyObject};
+
+impl RequestBodyObject {
+    /// 获取用于生成请求体的媒体类型，优先 JSON（`application/json`、`*+json`），否则取第一个
+    pub fn get_preferred_content(&self) -> Option<(&str, &MediaTypeObject)> {
+        self.content
+            .iter()
+            .find(|(media_type, _)| is_json_media_type(media_type))
+            .or_else(|| self.content.iter().next())
+            .map(|(media_type, content)| (media_type.as_str(), content))
+    }
+}
+
+/// 是否为 JSON 媒体类型，忽略参数（如 `; charset=utf-8`）
+fn is_json_media_type(media_type: &str) -> bool {
+    let essence = media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    essence == "application/json" || essence.ends_with("+json")
+}
@@ -0,0 +1,14 @@
+use crate::{
+    getter::get_header_by_ref,
+    model::{HeaderObject, OpenAPIObject, ResponseHeaders},
+};
+
+impl ResponseHeaders {
+    /// 获取响应头定义，引用会解析为 components 中的响应头
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a HeaderObject> {
+        match self {
+            ResponseHeaders::Header(v) => Some(v),
+            ResponseHeaders::Reference(v) => get_header_by_ref(open_api, &v.r#ref),
+        }
+    }
+}
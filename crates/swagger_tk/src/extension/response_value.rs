@@ -1,4 +1,7 @@
-use crate::model::{ResponseObject, ResponsesValue};
+use crate::{
+    getter::get_response_by_ref,
+    model::{OpenAPIObject, ResponseObject, ResponsesValue},
+};
 
 impl ResponsesValue {
     pub fn as_response(&self) -> Option<&ResponseObject> {
@@ -7,4 +10,12 @@ impl ResponsesValue {
             _ => None,
         }
     }
+
+    /// 获取响应定义，引用会解析为 components 中的响应
+    pub fn resolve<'a>(&'a self, open_api: &'a OpenAPIObject) -> Option<&'a ResponseObject> {
+        match self {
+            ResponsesValue::Response(v) => Some(v),
+            ResponsesValue::Reference(v) => get_response_by_ref(open_api, &v.r#ref),
+        }
+    }
 }
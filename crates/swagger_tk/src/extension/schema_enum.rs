@@ -1,4 +1,11 @@
-use crate::{getter::get_schema_name_from_ref, model::SchemaEnum};
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::{
+    getter::{escape_json_pointer, get_schema_name_from_ref},
+    model::{DiscriminatorObject, SchemaEnum, SchemaObjectAdditionalProperties, SpecExtensions},
+};
 
 impl SchemaEnum {
     pub fn get_ref_full_name(&self) -> Option<&String> {
@@ -29,4 +36,136 @@ impl SchemaEnum {
             _ => None,
         }
     }
+
+    /// 获取 `$defs` 中的本地定义
+    pub fn get_defs(&self) -> Option<&BTreeMap<String, SchemaEnum>> {
+        match self {
+            SchemaEnum::Ref(_) => None,
+            SchemaEnum::Object(v) => v.defs.as_ref(),
+            SchemaEnum::String(v) => v.defs.as_ref(),
+            SchemaEnum::Integer(v) => v.defs.as_ref(),
+            SchemaEnum::Number(v) => v.defs.as_ref(),
+            SchemaEnum::Boolean(v) => v.defs.as_ref(),
+            SchemaEnum::Array(v) => v.defs.as_ref(),
+            SchemaEnum::AllOf(v) => v.defs.as_ref(),
+            SchemaEnum::OneOf(v) => v.defs.as_ref(),
+            SchemaEnum::AnyOf(v) => v.defs.as_ref(),
+            SchemaEnum::Not(v) => v.defs.as_ref(),
+            SchemaEnum::Any(v) => v.defs.as_ref(),
+        }
+    }
+
+    /// 获取扩展字段，引用没有扩展字段
+    pub fn get_extensions(&self) -> Option<&SpecExtensions> {
+        match self {
+            SchemaEnum::Ref(_) => None,
+            SchemaEnum::Object(v) => Some(&v.extensions),
+            SchemaEnum::String(v) => Some(&v.extensions),
+            SchemaEnum::Integer(v) => Some(&v.extensions),
+            SchemaEnum::Number(v) => Some(&v.extensions),
+            SchemaEnum::Boolean(v) => Some(&v.extensions),
+            SchemaEnum::Array(v) => Some(&v.extensions),
+            SchemaEnum::AllOf(v) => Some(&v.extensions),
+            SchemaEnum::OneOf(v) => Some(&v.extensions),
+            SchemaEnum::AnyOf(v) => Some(&v.extensions),
+            SchemaEnum::Not(v) => Some(&v.extensions),
+            SchemaEnum::Any(v) => Some(&v.extensions),
+        }
+    }
+
+    /// 获取默认值
+    pub fn get_default(&self) -> Option<&Value> {
+        match self {
+            SchemaEnum::Ref(_) => None,
+            SchemaEnum::Object(v) => v.default.as_ref(),
+            SchemaEnum::String(v) => v.default.as_ref(),
+            SchemaEnum::Integer(v) => v.default.as_ref(),
+            SchemaEnum::Number(v) => v.default.as_ref(),
+            SchemaEnum::Boolean(v) => v.default.as_ref(),
+            SchemaEnum::Array(v) => v.default.as_ref(),
+            SchemaEnum::AllOf(v) => v.default.as_ref(),
+            SchemaEnum::OneOf(v) => v.default.as_ref(),
+            SchemaEnum::AnyOf(v) => v.default.as_ref(),
+            SchemaEnum::Not(v) => v.default.as_ref(),
+            SchemaEnum::Any(v) => v.default.as_ref(),
+        }
+    }
+
+    /// 获取示例值，没有 `example` 时取 `examples` 的第一项
+    pub fn get_example(&self) -> Option<&Value> {
+        let (example, examples) = match self {
+            SchemaEnum::Ref(_) => return None,
+            SchemaEnum::Object(v) => (&v.example, &v.examples),
+            SchemaEnum::String(v) => (&v.example, &v.examples),
+            SchemaEnum::Integer(v) => (&v.example, &v.examples),
+            SchemaEnum::Number(v) => (&v.example, &v.examples),
+            SchemaEnum::Boolean(v) => (&v.example, &v.examples),
+            SchemaEnum::Array(v) => (&v.example, &v.examples),
+            SchemaEnum::AllOf(v) => (&v.example, &v.examples),
+            SchemaEnum::OneOf(v) => (&v.example, &v.examples),
+            SchemaEnum::AnyOf(v) => (&v.example, &v.examples),
+            SchemaEnum::Not(v) => (&v.example, &v.examples),
+            SchemaEnum::Any(v) => (&v.example, &v.examples),
+        };
+        example
+            .as_ref()
+            .or_else(|| examples.as_ref().and_then(|items| items.first()))
+    }
+
+    /// 获取判别器（object / oneOf / anyOf）
+    pub fn get_discriminator(&self) -> Option<&DiscriminatorObject> {
+        match self {
+            SchemaEnum::Object(v) => v.discriminator.as_ref(),
+            SchemaEnum::OneOf(v) => v.discriminator.as_ref(),
+            SchemaEnum::AnyOf(v) => v.discriminator.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// 获取直接子 schema，返回相对于当前 schema 的 JSON Pointer 片段
+    pub fn get_child_schemas(&self) -> Vec<(String, &SchemaEnum)> {
+        fn indexed<'a>(key: &str, items: &'a [SchemaEnum]) -> Vec<(String, &'a SchemaEnum)> {
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| (format!("{key}/{index}"), item))
+                .collect()
+        }
+
+        let mut children = match self {
+            SchemaEnum::Object(v) => {
+                let mut children = v
+                    .properties
+                    .iter()
+                    .flatten()
+                    .map(|(name, item)| (format!("properties/{}", escape_json_pointer(name)), item))
+                    .collect::<Vec<_>>();
+                if let Some(SchemaObjectAdditionalProperties::Schema(item)) =
+                    &v.additional_properties
+                {
+                    children.push(("additionalProperties".to_string(), item.as_ref()));
+                }
+                children
+            }
+            SchemaEnum::Array(v) => vec![("items".to_string(), v.items.as_ref())],
+            SchemaEnum::AllOf(v) => {
+                let mut children = indexed("allOf", &v.all_of);
+                children.extend(v.properties.iter().flatten().map(|(name, item)| {
+                    (format!("properties/{}", escape_json_pointer(name)), item)
+                }));
+                children
+            }
+            SchemaEnum::OneOf(v) => indexed("oneOf", &v.one_of),
+            SchemaEnum::AnyOf(v) => indexed("anyOf", &v.any_of),
+            SchemaEnum::Not(v) => vec![("not".to_string(), v.not.as_ref())],
+            _ => Vec::new(),
+        };
+        children.extend(
+            self.get_defs()
+                .into_iter()
+                .flatten()
+                .map(|(name, item)| (format!("$defs/{}", escape_json_pointer(name)), item)),
+        );
+        children
+    }
 }
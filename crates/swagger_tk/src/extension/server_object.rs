@@ -0,0 +1,15 @@
+use crate::model::ServerObject;
+
+impl ServerObject {
+    /// 获取替换了变量默认值后的服务地址，未声明的变量保持原样
+    pub fn resolve_url(&self) -> String {
+        let Some(variables) = self.variables.as_ref() else {
+            return self.url.clone();
+        };
+        variables
+            .iter()
+            .fold(self.url.clone(), |url, (name, variable)| {
+                url.replace(&format!("{{{name}}}"), &variable.default)
+            })
+    }
+}
@@ -0,0 +1,74 @@
+use serde_json::Value;
+
+use crate::model::SpecExtensions;
+
+impl SpecExtensions {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 按 key 排序遍历所有扩展字段
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.0.iter()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key)?.as_str()
+    }
+
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.as_bool()
+    }
+
+    /// 获取字符串数组，数组中存在非字符串元素时返回 `None`
+    pub fn get_str_list(&self, key: &str) -> Option<Vec<&str>> {
+        self.get(key)?
+            .as_array()?
+            .iter()
+            .map(Value::as_str)
+            .collect()
+    }
+
+    /// 写入扩展字段，key 必须以 `x-` 开头
+    pub fn insert(&mut self, key: &str, value: Value) -> Result<Option<Value>, String> {
+        if !key.starts_with("x-") {
+            return Err(format!("extension key must start with `x-`: {key}"));
+        }
+        Ok(self.0.insert(key.to_string(), value))
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.0.remove(key)
+    }
+
+    /// 枚举成员名称：`x-enumNames` 或 `x-enum-varnames`
+    pub fn enum_names(&self) -> Option<Vec<&str>> {
+        self.get_str_list("x-enumNames")
+            .or_else(|| self.get_str_list("x-enum-varnames"))
+    }
+
+    /// 枚举成员说明：`x-enumDescriptions` 或 `x-enum-descriptions`
+    pub fn enum_descriptions(&self) -> Option<Vec<&str>> {
+        self.get_str_list("x-enumDescriptions")
+            .or_else(|| self.get_str_list("x-enum-descriptions"))
+    }
+
+    /// 是否标记为内部使用（`x-internal: true`）
+    pub fn is_internal(&self) -> bool {
+        self.get_bool("x-internal").unwrap_or(false)
+    }
+
+    /// 指定生成代码中使用的名称（`x-aptx-name`）
+    pub fn aptx_name(&self) -> Option<&str> {
+        self.get_str("x-aptx-name")
+    }
+
+    /// Swagger 2.0 常用的可空标记（`x-nullable`）
+    pub fn nullable(&self) -> Option<bool> {
+        self.get_bool("x-nullable")
+    }
+}
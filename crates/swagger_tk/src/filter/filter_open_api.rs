@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use super::{OperationMatch, SpecFilter, prune_components};
+use crate::loader::parse_open_api_value;
+use crate::model::OpenAPIObject;
+
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// 按条件过滤接口，并移除不再被引用的 components 和 tag 定义
+///
+/// 没有设置任何条件时原样返回
+pub fn filter_open_api(
+    open_api: &OpenAPIObject,
+    filter: &SpecFilter,
+) -> Result<OpenAPIObject, String> {
+    let mut value = serde_json::to_value(open_api).map_err(|err| err.to_string())?;
+    if !filter.is_empty() {
+        filter_spec_value(&mut value, filter);
+    }
+    parse_open_api_value(value).map_err(|err| err.to_string())
+}
+
+/// 在 JSON 值上过滤接口，便于处理未合并或未解析为模型的文档
+pub fn filter_spec_value(value: &mut Value, filter: &SpecFilter) {
+    if let Some(paths) = value.get_mut("paths").and_then(Value::as_object_mut) {
+        paths.retain(|path, path_item| {
+            let Some(path_item) = path_item.as_object_mut() else {
+                return true;
+            };
+            let path_internal = is_internal(path_item.get("x-internal"));
+            for method in METHODS {
+                let Some(operation) = path_item.get(method) else {
+                    continue;
+                };
+                let tags = operation
+                    .get("tags")
+                    .and_then(|tags| serde_json::from_value::<Vec<String>>(tags.clone()).ok())
+                    .unwrap_or_default();
+                let matched = filter.matches(&OperationMatch {
+                    path,
+                    method,
+                    tags: &tags,
+                    operation_id: operation.get("operationId").and_then(Value::as_str),
+                    internal: path_internal || is_internal(operation.get("x-internal")),
+                });
+                if !matched {
+                    path_item.remove(method);
+                }
+            }
+            // 接口全部被移除的路径项不再保留
+            METHODS.iter().any(|method| path_item.contains_key(*method))
+        });
+    }
+
+    let used_tags = collect_used_tags(value);
+    if let Some(tags) = value.get_mut("tags").and_then(Value::as_array_mut) {
+        tags.retain(|tag| {
+            tag.get("name")
+                .and_then(Value::as_str)
+                .is_none_or(|name| used_tags.contains(name))
+        });
+    }
+
+    prune_components(value);
+}
+
+fn is_internal(value: Option<&Value>) -> bool {
+    value.and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn collect_used_tags(value: &Value) -> BTreeSet<String> {
+    let mut result = BTreeSet::new();
+    let Some(paths) = value.get("paths").and_then(Value::as_object) else {
+        return result;
+    };
+    for path_item in paths.values() {
+        for method in METHODS {
+            let tags = path_item
+                .get(method)
+                .and_then(|operation| operation.get("tags"))
+                .and_then(Value::as_array);
+            for tag in tags.into_iter().flatten().filter_map(Value::as_str) {
+                result.insert(tag.to_string());
+            }
+        }
+    }
+    result
+}
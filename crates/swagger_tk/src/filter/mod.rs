@@ -0,0 +1,7 @@
+mod filter_open_api;
+mod prune_components;
+mod spec_filter;
+
+pub use filter_open_api::*;
+pub use prune_components::*;
+pub use spec_filter::*;
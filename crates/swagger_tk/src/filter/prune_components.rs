@@ -0,0 +1,94 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::getter::unescape_json_pointer;
+
+/// 通过 `$ref` 按名称引用、可以安全移除的 components 类型；
+/// `securitySchemes` 通过安全要求中的名称引用，始终保留
+const PRUNABLE_COMPONENTS: [&str; 9] = [
+    "schemas",
+    "responses",
+    "parameters",
+    "examples",
+    "requestBodies",
+    "headers",
+    "links",
+    "callbacks",
+    "pathItems",
+];
+
+/// 移除 `paths` 和 `webhooks` 无法（直接或间接）引用到的 components
+pub fn prune_components(value: &mut Value) {
+    let mut pending = Vec::new();
+    for key in ["paths", "webhooks"] {
+        if let Some(root) = value.get(key) {
+            collect_refs(root, &mut pending);
+        }
+    }
+
+    let mut reachable = BTreeSet::new();
+    while let Some(r#ref) = pending.pop() {
+        let Some(target) = parse_component_ref(&r#ref) else {
+            continue;
+        };
+        if !reachable.insert(target.clone()) {
+            continue;
+        }
+        let (kind, name) = &target;
+        if let Some(component) = value
+            .get("components")
+            .and_then(|components| components.get(kind))
+            .and_then(|components| components.get(name))
+        {
+            collect_refs(component, &mut pending);
+        }
+    }
+
+    let Some(components) = value.get_mut("components").and_then(Value::as_object_mut) else {
+        return;
+    };
+    for kind in PRUNABLE_COMPONENTS {
+        let Some(items) = components.get_mut(kind).and_then(Value::as_object_mut) else {
+            continue;
+        };
+        items.retain(|name, _| reachable.contains(&(kind.to_string(), name.clone())));
+        if items.is_empty() {
+            components.remove(kind);
+        }
+    }
+}
+
+/// 收集 `$ref` 以及 discriminator `mapping` 中的引用
+fn collect_refs(value: &Value, result: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map {
+                match (key.as_str(), item) {
+                    ("$ref", Value::String(r#ref)) => result.push(r#ref.clone()),
+                    ("mapping", Value::Object(mapping)) => {
+                        result.extend(
+                            mapping
+                                .values()
+                                .filter_map(Value::as_str)
+                                .map(ToString::to_string),
+                        );
+                        // 名为 mapping 的属性
+                        collect_refs(item, result);
+                    }
+                    _ => collect_refs(item, result),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_refs(item, result)),
+        _ => {}
+    }
+}
+
+/// `#/components/{kind}/{name}[/...]` -> (kind, name)
+fn parse_component_ref(r#ref: &str) -> Option<(String, String)> {
+    let mut segments = r#ref.strip_prefix("#/components/")?.split('/');
+    let kind = segments.next()?;
+    let name = segments.next()?;
+    Some((kind.to_string(), unescape_json_pointer(name)))
+}
@@ -0,0 +1,99 @@
+/// 接口过滤条件
+///
+/// 同一类条件中任意一项匹配即可；设置了多类 include 条件时需要全部满足，
+/// 任意 exclude 条件匹配的接口都会被移除
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecFilter {
+    pub include_tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+    /// 路径 glob，`*` 匹配一段路径，`**` 匹配任意多段，`?` 匹配单个字符
+    pub include_paths: Vec<String>,
+    pub exclude_paths: Vec<String>,
+    /// 不区分大小写
+    pub include_methods: Vec<String>,
+    pub exclude_methods: Vec<String>,
+    pub include_operation_ids: Vec<String>,
+    pub exclude_operation_ids: Vec<String>,
+    /// 移除标记了 `x-internal: true` 的接口（路径项上标记时作用于其下所有接口）
+    pub exclude_internal: bool,
+}
+
+/// 单个接口的匹配信息
+#[derive(Debug, Clone, Copy)]
+pub struct OperationMatch<'a> {
+    pub path: &'a str,
+    pub method: &'a str,
+    pub tags: &'a [String],
+    pub operation_id: Option<&'a str>,
+    pub internal: bool,
+}
+
+impl SpecFilter {
+    /// 没有设置任何条件
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn matches(&self, operation: &OperationMatch) -> bool {
+        if self.exclude_internal && operation.internal {
+            return false;
+        }
+
+        let tag_matches = |list: &[String]| operation.tags.iter().any(|tag| list.contains(tag));
+        let path_matches = |list: &[String]| {
+            list.iter()
+                .any(|pattern| glob_match(pattern, operation.path))
+        };
+        let method_matches = |list: &[String]| {
+            list.iter()
+                .any(|method| method.eq_ignore_ascii_case(operation.method))
+        };
+        let id_matches = |list: &[String]| {
+            operation
+                .operation_id
+                .is_some_and(|id| list.iter().any(|item| item == id))
+        };
+
+        let included =
+            |list: &[String], matches: &dyn Fn(&[String]) -> bool| list.is_empty() || matches(list);
+        let excluded = |list: &[String], matches: &dyn Fn(&[String]) -> bool| {
+            !list.is_empty() && matches(list)
+        };
+
+        included(&self.include_tags, &tag_matches)
+            && included(&self.include_paths, &path_matches)
+            && included(&self.include_methods, &method_matches)
+            && included(&self.include_operation_ids, &id_matches)
+            && !excluded(&self.exclude_tags, &tag_matches)
+            && !excluded(&self.exclude_paths, &path_matches)
+            && !excluded(&self.exclude_methods, &method_matches)
+            && !excluded(&self.exclude_operation_ids, &id_matches)
+    }
+}
+
+/// 路径 glob 匹配：`*` 不跨越 `/`，`**` 可以跨越 `/`
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let path = path.chars().collect::<Vec<_>>();
+    glob_match_at(&pattern, &path)
+}
+
+fn glob_match_at(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        // `/**` 匹配零个或多个路径段，使 `/users/**` 同时匹配 `/users`
+        ['/', '*', '*', rest @ ..] if rest.first().is_none_or(|c| *c == '/') => {
+            glob_match_at(rest, path)
+                || (path.first() == Some(&'/')
+                    && (1..=path.len()).any(|index| glob_match_at(rest, &path[index..])))
+        }
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|index| glob_match_at(rest, &path[index..])),
+        ['*', rest @ ..] => (0..=path.len())
+            .take_while(|&index| index == 0 || path[index - 1] != '/')
+            .any(|index| glob_match_at(rest, &path[index..])),
+        ['?', rest @ ..] => {
+            path.first().is_some_and(|c| *c != '/') && glob_match_at(rest, &path[1..])
+        }
+        [c, rest @ ..] => path.first() == Some(c) && glob_match_at(rest, &path[1..]),
+    }
+}
@@ -0,0 +1,9 @@
+/// 按 JSON Pointer 规则转义片段（`~` -> `~0`，`/` -> `~1`）
+pub fn escape_json_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// 还原经过 JSON Pointer 转义的片段
+pub fn unescape_json_pointer(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
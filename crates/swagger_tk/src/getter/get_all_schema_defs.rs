@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use crate::model::{OpenAPIObject, SchemaEnum};
+
+use super::{escape_json_pointer, get_all_schema};
+
+/// 获取所有 schema 及其任意层级子 schema 中通过 `$defs` 声明的本地定义；
+/// 引用按定义名称解析，同名但内容不同的定义（包括与 components 中的 schema 同名）返回错误
+pub fn get_all_schema_defs(
+    open_api: &OpenAPIObject,
+) -> Result<BTreeMap<&str, &SchemaEnum>, String> {
+    let Some(schemas) = get_all_schema(open_api) else {
+        return Ok(BTreeMap::new());
+    };
+    let mut defs = BTreeMap::new();
+    for (name, schema) in schemas {
+        let pointer = format!("#/components/schemas/{}", escape_json_pointer(name));
+        collect_defs(&pointer, schema, &mut defs)?;
+    }
+    for (name, (pointer, schema)) in &defs {
+        if let Some(component) = schemas.get(*name) {
+            if !is_same_schema(component, schema) {
+                return Err(format!(
+                    "`{pointer}` conflicts with `#/components/schemas/{name}`"
+                ));
+            }
+        }
+    }
+    Ok(defs
+        .into_iter()
+        .map(|(name, (_, schema))| (name, schema))
+        .collect())
+}
+
+fn collect_defs<'a>(
+    parent: &str,
+    schema: &'a SchemaEnum,
+    defs: &mut BTreeMap<&'a str, (String, &'a SchemaEnum)>,
+) -> Result<(), String> {
+    for (name, item) in schema.get_defs().into_iter().flatten() {
+        let pointer = format!("{parent}/$defs/{}", escape_json_pointer(name));
+        if let Some((existing, schema)) = defs.get(name.as_str()) {
+            if !is_same_schema(schema, item) {
+                return Err(format!("`{pointer}` conflicts with `{existing}`"));
+            }
+        } else {
+            defs.insert(name.as_str(), (pointer, item));
+        }
+    }
+    // 子 schema 中也包含 `$defs` 下的定义，嵌套声明一并收集
+    for (segment, child) in schema.get_child_schemas() {
+        collect_defs(&format!("{parent}/{segment}"), child, defs)?;
+    }
+    Ok(())
+}
+
+/// 重复声明的相同定义不算冲突
+fn is_same_schema(a: &SchemaEnum, b: &SchemaEnum) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
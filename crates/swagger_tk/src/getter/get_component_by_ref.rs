@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+
+use crate::model::{
+    CallbackValue, ComponentsCallbacks, ComponentsExamples, ComponentsHeaders,
+    ComponentsParameters, ComponentsPathItems, ComponentsRequestBodies, ComponentsResponses,
+    ExampleObject, HeaderObject, OpenAPIObject, ParameterObject, PathItemObject, RequestBodyObject,
+    ResponseObject, SchemaEnum,
+};
+
+use super::{get_all_schema, get_all_schema_defs, unescape_json_pointer};
+
+/// 引用链的最大长度，超过时视为循环引用
+const MAX_REF_DEPTH: usize = 32;
+
+/// 根据 `#/components/parameters/<name>` 获取参数定义，会继续解析引用链
+pub fn get_parameter_by_ref<'a>(
+    open_api: &'a OpenAPIObject,
+    r#ref: &str,
+) -> Option<&'a ParameterObject> {
+    let parameters = open_api.components.as_ref()?.parameters.as_ref()?;
+    follow_ref(r#ref, "parameters", |name| match parameters.get(name)? {
+        ComponentsParameters::Parameter(v) => Some(Ok(v)),
+        ComponentsParameters::Reference(v) => Some(Err(v.r#ref.as_str())),
+    })
+}
+
+/// 根据 `#/components/requestBodies/<name>` 获取请求体定义
+pub fn get_request_body_by_ref<'a>(
+    open_api: &'a OpenAPIObject,
+    r#ref: &str,
+) -> Option<&'a RequestBodyObject> {
+    let request_bodies = open_api.components.as_ref()?.request_bodies.as_ref()?;
+    follow_ref(r#ref, "requestBodies", |name| {
+        match request_bodies.get(name)? {
+            ComponentsRequestBodies::RequestBody(v) => Some(Ok(v)),
+            ComponentsRequestBodies::Reference(v) => Some(Err(v.r#ref.as_str())),
+        }
+    })
+}
+
+/// 根据 `#/components/responses/<name>` 获取响应定义
+pub fn get_response_by_ref<'a>(
+    open_api: &'a OpenAPIObject,
+    r#ref: &str,
+) -> Option<&'a ResponseObject> {
+    let responses = open_api.components.as_ref()?.responses.as_ref()?;
+    follow_ref(r#ref, "responses", |name| match responses.get(name)? {
+        ComponentsResponses::Response(v) => Some(Ok(v)),
+        ComponentsResponses::Reference(v) => Some(Err(v.r#ref.as_str())),
+    })
+}
+
+/// 根据 `#/components/headers/<name>` 获取响应头定义
+pub fn get_header_by_ref<'a>(open_api: &'a OpenAPIObject, r#ref: &str) -> Option<&'a HeaderObject> {
+    let headers = open_api.components.as_ref()?.headers.as_ref()?;
+    follow_ref(r#ref, "headers", |name| match headers.get(name)? {
+        ComponentsHeaders::Header(v) => Some(Ok(v)),
+        ComponentsHeaders::Reference(v) => Some(Err(v.r#ref.as_str())),
+    })
+}
+
+/// 根据 `#/components/examples/<name>` 获取示例定义
+pub fn get_example_by_ref<'a>(
+    open_api: &'a OpenAPIObject,
+    r#ref: &str,
+) -> Option<&'a ExampleObject> {
+    let examples = open_api.components.as_ref()?.examples.as_ref()?;
+    follow_ref(r#ref, "examples", |name| match examples.get(name)? {
+        ComponentsExamples::Example(v) => Some(Ok(v)),
+        ComponentsExamples::Reference(v) => Some(Err(v.r#ref.as_str())),
+    })
+}
+
+/// 根据 `#/components/callbacks/<name>` 获取回调定义
+pub fn get_callback_by_ref<'a>(
+    open_api: &'a OpenAPIObject,
+    r#ref: &str,
+) -> Option<&'a BTreeMap<String, CallbackValue>> {
+    let callbacks = open_api.components.as_ref()?.callbacks.as_ref()?;
+    follow_ref(r#ref, "callbacks", |name| match callbacks.get(name)? {
+        ComponentsCallbacks::Callback(v) => Some(Ok(v)),
+        ComponentsCallbacks::Reference(v) => Some(Err(v.r#ref.as_str())),
+    })
+}
+
+/// 根据 `#/components/pathItems/<name>` 或 `#/paths/<path>` 获取路径项定义
+pub fn get_path_item_by_ref<'a>(
+    open_api: &'a OpenAPIObject,
+    r#ref: &str,
+) -> Option<&'a PathItemObject> {
+    let mut current = r#ref.to_string();
+    for _ in 0..MAX_REF_DEPTH {
+        let path_item = if let Some(path) = current.strip_prefix("#/paths/") {
+            open_api.paths.as_ref()?.get(&unescape_json_pointer(path))?
+        } else {
+            let name = unescape_json_pointer(current.strip_prefix("#/components/pathItems/")?);
+            let path_items = open_api.components.as_ref()?.path_items.as_ref()?;
+            match path_items.get(&name)? {
+                ComponentsPathItems::PathItem(v) => v.as_ref(),
+                ComponentsPathItems::Reference(v) => {
+                    current = v.r#ref.clone();
+                    continue;
+                }
+            }
+        };
+        // 路径项自身带有 `$ref` 字段，反序列化时引用也会落在 PathItem 分支
+        match &path_item.r#ref {
+            Some(next) => current = next.clone(),
+            None => return Some(path_item),
+        }
+    }
+    None
+}
+
+/// 根据完整的 JSON Pointer 获取 schema，会继续解析引用链：
+/// - `#/components/schemas/<name>` 及其内部位置，如 `#/components/schemas/A/properties/b`
+/// - `#/$defs/<name>`，按定义名称查找 `$defs`
+pub fn get_schema_by_ref<'a>(open_api: &'a OpenAPIObject, r#ref: &str) -> Option<&'a SchemaEnum> {
+    let mut current = r#ref.to_string();
+    for _ in 0..MAX_REF_DEPTH {
+        match get_schema_by_pointer(open_api, &current)? {
+            SchemaEnum::Ref(v) => current = v.r#ref.clone(),
+            schema => return Some(schema),
+        }
+    }
+    None
+}
+
+fn get_schema_by_pointer<'a>(open_api: &'a OpenAPIObject, pointer: &str) -> Option<&'a SchemaEnum> {
+    if let Some(name) = pointer
+        .strip_prefix("#/$defs/")
+        .filter(|name| !name.contains('/'))
+    {
+        let defs = get_all_schema_defs(open_api).ok()?;
+        return defs.get(unescape_json_pointer(name).as_str()).copied();
+    }
+    let rest = pointer.strip_prefix("#/components/schemas/")?;
+    let (name, mut rest) = rest.split_once('/').unwrap_or((rest, ""));
+    let mut schema = get_all_schema(open_api)?.get(&unescape_json_pointer(name))?;
+    // 子 schema 的路径段已经转义，可以直接与剩余的 pointer 比较
+    while !rest.is_empty() {
+        let (child, remaining) =
+            schema
+                .get_child_schemas()
+                .into_iter()
+                .find_map(|(segment, child)| {
+                    let remaining = rest.strip_prefix(segment.as_str())?;
+                    (remaining.is_empty() || remaining.starts_with('/'))
+                        .then(|| (child, remaining.trim_start_matches('/')))
+                })?;
+        schema = child;
+        rest = remaining;
+    }
+    Some(schema)
+}
+
+/// 沿引用链查找组件，`lookup` 返回 `Err` 时表示目标仍是引用
+fn follow_ref<'a, T>(
+    r#ref: &str,
+    section: &str,
+    lookup: impl Fn(&str) -> Option<Result<&'a T, &'a str>>,
+) -> Option<&'a T> {
+    let prefix = format!("#/components/{section}/");
+    let mut current = r#ref.to_string();
+    for _ in 0..MAX_REF_DEPTH {
+        let name = unescape_json_pointer(current.strip_prefix(&prefix)?);
+        match lookup(&name)? {
+            Ok(target) => return Some(target),
+            Err(next) => current = next.to_string(),
+        }
+    }
+    None
+}
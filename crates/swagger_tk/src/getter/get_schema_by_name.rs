@@ -1,8 +1,13 @@
-use crate::model::{OpenAPIObject, SchemaEnum};
-
-use super::get_all_schema;
-
-/// 根据 name 获取单个的 schema
-pub fn get_schema_by_name<'a>(open_api: &'a OpenAPIObject, name: &str) -> Option<&'a SchemaEnum> {
-    get_all_schema(open_api)?.get(name)
-}
+use crate::model::{OpenAPIObject, SchemaEnum};
+
+use super::{get_all_schema, get_all_schema_defs};
+
+/// 根据 name 获取单个的 schema，找不到时再查找 `$defs` 中的定义（定义存在冲突时不查找）
+pub fn get_schema_by_name<'a>(open_api: &'a OpenAPIObject, name: &str) -> Option<&'a SchemaEnum> {
+    let schemas = get_all_schema(open_api)?;
+    schemas.get(name).or_else(|| {
+        get_all_schema_defs(open_api)
+            .ok()
+            .and_then(|defs| defs.get(name).copied())
+    })
+}
@@ -0,0 +1,33 @@
+use crate::model::{
+    ComponentsSecuritySchemes, OpenAPIObject, OperationObject, SecurityRequirementObject,
+    SecuritySchemeObject,
+};
+
+use super::unescape_json_pointer;
+
+/// 根据 securitySchemes 中的名称获取安全方案，会继续解析引用链
+pub fn get_security_scheme<'a>(
+    open_api: &'a OpenAPIObject,
+    name: &str,
+) -> Option<&'a SecuritySchemeObject> {
+    let schemes = open_api.components.as_ref()?.security_schemes.as_ref()?;
+    let mut name = name.to_string();
+    for _ in 0..32 {
+        match schemes.get(&name)? {
+            ComponentsSecuritySchemes::SecurityScheme(v) => return Some(v),
+            ComponentsSecuritySchemes::Reference(v) => {
+                name =
+                    unescape_json_pointer(v.r#ref.strip_prefix("#/components/securitySchemes/")?);
+            }
+        }
+    }
+    None
+}
+
+/// 获取接口实际生效的安全要求，接口上的 `security` 会覆盖全局配置
+pub fn get_operation_security<'a>(
+    open_api: &'a OpenAPIObject,
+    operation: &'a OperationObject,
+) -> Option<&'a Vec<SecurityRequirementObject>> {
+    operation.security.as_ref().or(open_api.security.as_ref())
+}
@@ -1,12 +1,12 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use crate::model::PathItemObject;
 
 use super::get_tags_from_path_item;
 
-pub fn get_tags_from_paths(paths: &HashMap<String, PathItemObject>) -> Vec<&String> {
+pub fn get_tags_from_paths(paths: &BTreeMap<String, PathItemObject>) -> Vec<&String> {
     paths
-        .iter()
-        .flat_map(|(_, item)| get_tags_from_path_item(item))
+        .values()
+        .flat_map(get_tags_from_path_item)
         .collect::<Vec<&String>>()
 }
@@ -0,0 +1,68 @@
+use crate::model::{
+    MediaTypeObject, OpenAPIObject, OperationObjectParameters, OperationObjectRequestBody,
+    ResponsesValue, SchemaEnum,
+};
+
+use super::{escape_json_pointer, get_all_schema};
+
+/// 获取所有无法确定类型（按任意值处理）的 schema 位置，返回排序后的 JSON Pointer
+pub fn get_untyped_schemas(open_api: &OpenAPIObject) -> Vec<String> {
+    let mut result = Vec::new();
+    if let Some(schemas) = get_all_schema(open_api) {
+        for (name, schema) in schemas {
+            let pointer = format!("#/components/schemas/{}", escape_json_pointer(name));
+            collect_untyped(&pointer, schema, &mut result);
+        }
+    }
+
+    for (path, path_item) in open_api.paths.iter().flatten() {
+        for (method, operation) in path_item.get_operations() {
+            let base = format!("#/paths/{}/{method}", escape_json_pointer(path));
+            for (index, parameter) in operation.parameters.iter().flatten().enumerate() {
+                if let OperationObjectParameters::Parameter(parameter) = parameter {
+                    if let Some(schema) = &parameter.schema {
+                        let pointer = format!("{base}/parameters/{index}/schema");
+                        collect_untyped(&pointer, schema, &mut result);
+                    }
+                }
+            }
+            if let Some(OperationObjectRequestBody::RequestBody(body)) = &operation.request_body {
+                collect_content(&format!("{base}/requestBody"), &body.content, &mut result);
+            }
+            for (status, response) in operation.responses.iter().flatten() {
+                if let ResponsesValue::Response(response) = response {
+                    if let Some(content) = &response.content {
+                        let pointer = format!("{base}/responses/{}", escape_json_pointer(status));
+                        collect_content(&pointer, content, &mut result);
+                    }
+                }
+            }
+        }
+    }
+
+    result.sort();
+    result
+}
+
+fn collect_content<'a>(
+    base: &str,
+    content: impl IntoIterator<Item = (&'a String, &'a MediaTypeObject)>,
+    result: &mut Vec<String>,
+) {
+    for (media_type, media) in content {
+        let Some(schema) = &media.schema else {
+            continue;
+        };
+        let pointer = format!("{base}/content/{}/schema", escape_json_pointer(media_type));
+        collect_untyped(&pointer, schema, result);
+    }
+}
+
+fn collect_untyped(pointer: &str, schema: &SchemaEnum, result: &mut Vec<String>) {
+    if matches!(schema, SchemaEnum::Any(_)) {
+        result.push(pointer.to_string());
+    }
+    for (segment, child) in schema.get_child_schemas() {
+        collect_untyped(&format!("{pointer}/{segment}"), child, result);
+    }
+}
@@ -1,21 +1,31 @@
+mod escape_json_pointer;
 mod get_all_schema;
+mod get_all_schema_defs;
+mod get_component_by_ref;
 mod get_controller_description;
 mod get_paths_from_tag;
 mod get_schema_by_name;
 mod get_schema_name_from_ref;
 mod get_schema_name_list;
+mod get_security_scheme;
 mod get_tags;
 mod get_tags_from_open_api;
 mod get_tags_from_path_item;
 mod get_tags_from_paths;
+mod get_untyped_schemas;
 
+pub use escape_json_pointer::*;
 pub use get_all_schema::*;
+pub use get_all_schema_defs::*;
+pub use get_component_by_ref::*;
 pub use get_controller_description::*;
 pub use get_paths_from_tag::*;
 pub use get_schema_by_name::*;
 pub use get_schema_name_from_ref::*;
 pub use get_schema_name_list::*;
+pub use get_security_scheme::*;
 pub use get_tags::*;
 pub use get_tags_from_open_api::*;
 pub use get_tags_from_path_item::*;
 pub use get_tags_from_paths::*;
+pub use get_untyped_schemas::*;
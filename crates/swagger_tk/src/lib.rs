@@ -1,3 +1,9 @@
+pub mod converter;
+pub mod diff;
 pub mod extension;
+pub mod filter;
 pub mod getter;
+pub mod lint;
+pub mod loader;
 pub mod model;
+pub mod overlay;
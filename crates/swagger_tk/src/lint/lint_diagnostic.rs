@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// 诊断级别，存在 error 时视为检查不通过
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Display for LintSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            LintSeverity::Error => "error",
+            LintSeverity::Warning => "warning",
+            LintSeverity::Info => "info",
+        };
+        write!(f, "{text}")
+    }
+}
+
+/// 单条检查结果，`pointer` 为问题所在位置的 JSON Pointer（例如 `#/paths/~1users/get`）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    pub rule: String,
+    pub severity: LintSeverity,
+    pub pointer: String,
+    pub message: String,
+}
+
+impl LintDiagnostic {
+    pub fn new(
+        rule: &str,
+        severity: LintSeverity,
+        pointer: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rule: rule.to_string(),
+            severity,
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for LintDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} [{}] {}: {}",
+            self.severity, self.rule, self.pointer, self.message
+        )
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::getter::{
+    escape_json_pointer, get_header_by_ref, get_parameter_by_ref, get_request_body_by_ref,
+    get_response_by_ref, get_schema_by_ref, get_security_scheme, get_untyped_schemas,
+};
+use crate::model::{
+    ComponentsHeaders, ComponentsParameters, ComponentsRequestBodies, ComponentsResponses,
+    MediaTypeObject, OpenAPIObject, OperationObject, OperationObjectParameters,
+    OperationObjectRequestBody, ParameterObject, ParameterObjectIn, PathItemParameters,
+    ResponseHeaders, ResponseObject, ResponsesValue, SchemaEnum, SecurityRequirementObject,
+};
+
+use super::{LintDiagnostic, LintSeverity};
+
+/// 检查文档中会导致生成结果变差的问题，结果按 JSON Pointer 排序
+///
+/// 规则：
+/// - `operation-id-missing`：接口缺少 operationId，函数名只能根据路径推断
+/// - `operation-id-duplicate`：operationId 重复
+/// - `ref-unresolved`：`$ref` 指向不存在的定义或外部文件
+/// - `path-param-undeclared`：路径模板中的参数没有声明
+/// - `path-param-unused`：声明的 path 参数不在路径模板中
+/// - `body-untyped`：请求体或成功响应没有可用的类型
+/// - `schema-untyped`：schema 缺少 `type`（或 `type` 无法识别）且无法推断
+/// - `response-success-missing`：接口没有 2xx 响应
+/// - `enum-unnamed`：数值枚举没有通过 `x-enumNames` / `x-enum-varnames` 提供成员名称
+/// - `security-scheme-undeclared`：安全要求引用了未声明的安全方案
+pub fn lint_open_api(open_api: &OpenAPIObject) -> Vec<LintDiagnostic> {
+    let mut linter = Linter {
+        open_api,
+        diagnostics: Vec::new(),
+        untyped_bodies: HashSet::new(),
+    };
+    linter.lint_components();
+    linter.lint_paths();
+    if let Some(security) = &open_api.security {
+        linter.lint_security("#/security", security);
+    }
+    linter.lint_untyped_schemas();
+
+    let mut diagnostics = linter.diagnostics;
+    diagnostics.sort_by(|a, b| a.pointer.cmp(&b.pointer).then(a.rule.cmp(&b.rule)));
+    diagnostics
+}
+
+/// 是否存在 error 级别的诊断
+pub fn has_lint_errors(diagnostics: &[LintDiagnostic]) -> bool {
+    diagnostics
+        .iter()
+        .any(|item| item.severity == LintSeverity::Error)
+}
+
+struct Linter<'a> {
+    open_api: &'a OpenAPIObject,
+    diagnostics: Vec<LintDiagnostic>,
+    /// 已经按 `body-untyped` 报告的 schema 位置，避免重复报告
+    untyped_bodies: HashSet<String>,
+}
+
+impl<'a> Linter<'a> {
+    fn push(&mut self, rule: &str, severity: LintSeverity, pointer: &str, message: String) {
+        self.diagnostics
+            .push(LintDiagnostic::new(rule, severity, pointer, message));
+    }
+
+    fn check_ref(&mut self, pointer: &str, r#ref: &str, resolved: bool) {
+        if resolved {
+            return;
+        }
+        let message = if r#ref.starts_with('#') {
+            format!("`{ref}` does not point to an existing definition")
+        } else {
+            format!("external `{ref}` is not bundled; run `spec bundle` first")
+        };
+        self.push("ref-unresolved", LintSeverity::Error, pointer, message);
+    }
+
+    fn lint_components(&mut self) {
+        let Some(components) = self.open_api.components.as_ref() else {
+            return;
+        };
+        for (name, schema) in components.schemas.iter().flatten() {
+            let pointer = format!("#/components/schemas/{}", escape_json_pointer(name));
+            self.lint_schema(&pointer, schema);
+            self.lint_enum_names(&pointer, schema);
+        }
+        for (name, parameter) in components.parameters.iter().flatten() {
+            let pointer = format!("#/components/parameters/{}", escape_json_pointer(name));
+            match parameter {
+                ComponentsParameters::Parameter(v) => self.lint_parameter(&pointer, v),
+                ComponentsParameters::Reference(v) => {
+                    let resolved = get_parameter_by_ref(self.open_api, &v.r#ref).is_some();
+                    self.check_ref(&pointer, &v.r#ref, resolved);
+                }
+            }
+        }
+        for (name, request_body) in components.request_bodies.iter().flatten() {
+            let pointer = format!("#/components/requestBodies/{}", escape_json_pointer(name));
+            match request_body {
+                ComponentsRequestBodies::RequestBody(v) => {
+                    self.lint_content(&pointer, &v.content, false)
+                }
+                ComponentsRequestBodies::Reference(v) => {
+                    let resolved = get_request_body_by_ref(self.open_api, &v.r#ref).is_some();
+                    self.check_ref(&pointer, &v.r#ref, resolved);
+                }
+            }
+        }
+        for (name, response) in components.responses.iter().flatten() {
+            let pointer = format!("#/components/responses/{}", escape_json_pointer(name));
+            match response {
+                ComponentsResponses::Response(v) => self.lint_response(&pointer, v, false),
+                ComponentsResponses::Reference(v) => {
+                    let resolved = get_response_by_ref(self.open_api, &v.r#ref).is_some();
+                    self.check_ref(&pointer, &v.r#ref, resolved);
+                }
+            }
+        }
+        for (name, header) in components.headers.iter().flatten() {
+            let pointer = format!("#/components/headers/{}", escape_json_pointer(name));
+            match header {
+                ComponentsHeaders::Header(v) => {
+                    if let Some(schema) = &v.schema {
+                        self.lint_schema(&format!("{pointer}/schema"), schema);
+                    }
+                }
+                ComponentsHeaders::Reference(v) => {
+                    let resolved = get_header_by_ref(self.open_api, &v.r#ref).is_some();
+                    self.check_ref(&pointer, &v.r#ref, resolved);
+                }
+            }
+        }
+    }
+
+    fn lint_paths(&mut self) {
+        let mut operation_ids: HashMap<&str, String> = HashMap::new();
+        let mut paths = self.open_api.paths.iter().flatten().collect::<Vec<_>>();
+        paths.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (path, path_item) in paths {
+            let path_pointer = format!("#/paths/{}", escape_json_pointer(path));
+            let mut path_params = Vec::new();
+            for (index, parameter) in path_item.parameters.iter().flatten().enumerate() {
+                let pointer = format!("{path_pointer}/parameters/{index}");
+                let parameter = match parameter {
+                    PathItemParameters::Parameter(v) => {
+                        self.lint_parameter(&pointer, v);
+                        Some(v)
+                    }
+                    PathItemParameters::Reference(v) => {
+                        let resolved = get_parameter_by_ref(self.open_api, &v.r#ref);
+                        self.check_ref(&pointer, &v.r#ref, resolved.is_some());
+                        resolved
+                    }
+                };
+                if let Some(v) = parameter.filter(|v| v.r#in == ParameterObjectIn::Path) {
+                    path_params.push(v.name.as_str());
+                }
+            }
+
+            for (method, operation) in path_item.get_operations() {
+                let pointer = format!("{path_pointer}/{method}");
+                match operation.operation_id.as_deref() {
+                    Some(id) => {
+                        if let Some(first) = operation_ids.get(id) {
+                            let message = format!("operationId `{id}` is already used by {first}");
+                            self.push(
+                                "operation-id-duplicate",
+                                LintSeverity::Error,
+                                &pointer,
+                                message,
+                            );
+                        } else {
+                            operation_ids.insert(id, pointer.clone());
+                        }
+                    }
+                    None => self.push(
+                        "operation-id-missing",
+                        LintSeverity::Warning,
+                        &pointer,
+                        "operation has no operationId; the function name is derived from the path"
+                            .to_string(),
+                    ),
+                }
+                self.lint_operation(&pointer, path, &path_params, operation);
+            }
+        }
+    }
+
+    fn lint_operation(
+        &mut self,
+        pointer: &str,
+        path: &str,
+        path_level_params: &[&str],
+        operation: &OperationObject,
+    ) {
+        let mut declared = path_level_params
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<HashSet<_>>();
+        for (index, parameter) in operation.parameters.iter().flatten().enumerate() {
+            let param_pointer = format!("{pointer}/parameters/{index}");
+            let parameter = match parameter {
+                OperationObjectParameters::Parameter(v) => {
+                    self.lint_parameter(&param_pointer, v);
+                    Some(v)
+                }
+                OperationObjectParameters::Reference(v) => {
+                    let resolved = get_parameter_by_ref(self.open_api, &v.r#ref);
+                    self.check_ref(&param_pointer, &v.r#ref, resolved.is_some());
+                    resolved
+                }
+            };
+            if let Some(v) = parameter.filter(|v| v.r#in == ParameterObjectIn::Path) {
+                declared.insert(v.name.clone());
+            }
+        }
+
+        let template = path_template_params(path);
+        for name in &template {
+            if !declared.contains(*name) {
+                self.push(
+                    "path-param-undeclared",
+                    LintSeverity::Error,
+                    pointer,
+                    format!("path parameter `{name}` is not declared"),
+                );
+            }
+        }
+        for name in &declared {
+            if !template.contains(&name.as_str()) {
+                self.push(
+                    "path-param-unused",
+                    LintSeverity::Warning,
+                    pointer,
+                    format!("path parameter `{name}` does not appear in `{path}`"),
+                );
+            }
+        }
+
+        match &operation.request_body {
+            Some(OperationObjectRequestBody::RequestBody(v)) => {
+                self.lint_content(&format!("{pointer}/requestBody"), &v.content, true)
+            }
+            Some(OperationObjectRequestBody::Reference(v)) => {
+                let resolved = get_request_body_by_ref(self.open_api, &v.r#ref).is_some();
+                self.check_ref(&format!("{pointer}/requestBody"), &v.r#ref, resolved);
+            }
+            None => {}
+        }
+
+        let mut has_success = false;
+        for (status, response) in operation.responses.iter().flatten() {
+            let is_success = status.starts_with('2');
+            has_success |= is_success;
+            let response_pointer = format!("{pointer}/responses/{}", escape_json_pointer(status));
+            match response {
+                ResponsesValue::Response(v) => self.lint_response(&response_pointer, v, is_success),
+                ResponsesValue::Reference(v) => {
+                    let resolved = get_response_by_ref(self.open_api, &v.r#ref).is_some();
+                    self.check_ref(&response_pointer, &v.r#ref, resolved);
+                }
+            }
+        }
+        if !has_success {
+            self.push(
+                "response-success-missing",
+                LintSeverity::Warning,
+                pointer,
+                "operation has no 2xx response; the return type falls back to void".to_string(),
+            );
+        }
+
+        if let Some(security) = &operation.security {
+            self.lint_security(&format!("{pointer}/security"), security);
+        }
+    }
+
+    fn lint_parameter(&mut self, pointer: &str, parameter: &ParameterObject) {
+        if let Some(schema) = &parameter.schema {
+            self.lint_schema(&format!("{pointer}/schema"), schema);
+        }
+    }
+
+    fn lint_response(&mut self, pointer: &str, response: &ResponseObject, check_untyped: bool) {
+        if let Some(content) = &response.content {
+            self.lint_content(pointer, content, check_untyped);
+        }
+        for (name, header) in response.headers.iter().flatten() {
+            let header_pointer = format!("{pointer}/headers/{}", escape_json_pointer(name));
+            match header {
+                ResponseHeaders::Header(v) => {
+                    if let Some(schema) = &v.schema {
+                        self.lint_schema(&format!("{header_pointer}/schema"), schema);
+                    }
+                }
+                ResponseHeaders::Reference(v) => {
+                    let resolved = get_header_by_ref(self.open_api, &v.r#ref).is_some();
+                    self.check_ref(&header_pointer, &v.r#ref, resolved);
+                }
+            }
+        }
+    }
+
+    fn lint_content(
+        &mut self,
+        pointer: &str,
+        content: &BTreeMap<String, MediaTypeObject>,
+        check_untyped: bool,
+    ) {
+        for (media_type, media) in content {
+            let Some(schema) = &media.schema else {
+                continue;
+            };
+            let schema_pointer = format!(
+                "{pointer}/content/{}/schema",
+                escape_json_pointer(media_type)
+            );
+            if check_untyped && is_untyped_body(schema) {
+                self.push(
+                    "body-untyped",
+                    LintSeverity::Warning,
+                    &schema_pointer,
+                    "body schema declares no properties; the generated type is not useful"
+                        .to_string(),
+                );
+                self.untyped_bodies.insert(schema_pointer.clone());
+            }
+            self.lint_schema(&schema_pointer, schema);
+        }
+    }
+
+    /// 检查 schema 中的引用
+    fn lint_schema(&mut self, pointer: &str, schema: &SchemaEnum) {
+        if let SchemaEnum::Ref(v) = schema {
+            let resolved = get_schema_by_ref(self.open_api, &v.r#ref).is_some();
+            self.check_ref(pointer, &v.r#ref, resolved);
+        }
+        for (segment, child) in schema.get_child_schemas() {
+            self.lint_schema(&format!("{pointer}/{segment}"), child);
+        }
+    }
+
+    fn lint_enum_names(&mut self, pointer: &str, schema: &SchemaEnum) {
+        let is_numeric_enum = match schema {
+            SchemaEnum::Integer(v) => v.r#enum.is_some(),
+            SchemaEnum::Number(v) => v.r#enum.is_some(),
+            _ => false,
+        };
+        let has_names = schema
+            .get_extensions()
+            .and_then(|extensions| extensions.enum_names())
+            .is_some();
+        if is_numeric_enum && !has_names {
+            self.push(
+                "enum-unnamed",
+                LintSeverity::Info,
+                pointer,
+                "numeric enum has no `x-enumNames`; members are generated as Value1, Value2, ..."
+                    .to_string(),
+            );
+        }
+    }
+
+    fn lint_security(&mut self, pointer: &str, security: &[SecurityRequirementObject]) {
+        for (index, requirement) in security.iter().enumerate() {
+            let mut names = requirement.keys().collect::<Vec<_>>();
+            names.sort();
+            for name in names {
+                if get_security_scheme(self.open_api, name).is_none() {
+                    self.push(
+                        "security-scheme-undeclared",
+                        LintSeverity::Error,
+                        &format!("{pointer}/{index}"),
+                        format!("security scheme `{name}` is not declared in components"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn lint_untyped_schemas(&mut self) {
+        for pointer in get_untyped_schemas(self.open_api) {
+            if self.untyped_bodies.contains(&pointer) {
+                continue;
+            }
+            self.push(
+                "schema-untyped",
+                LintSeverity::Warning,
+                &pointer,
+                "schema has no usable `type` and none could be inferred; treated as any"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+/// 请求体或响应体是否没有任何类型信息（任意值或没有属性的 object）
+fn is_untyped_body(schema: &SchemaEnum) -> bool {
+    match schema {
+        SchemaEnum::Any(_) => true,
+        SchemaEnum::Object(v) => {
+            v.properties.as_ref().is_none_or(|items| items.is_empty())
+                && v.additional_properties.is_none()
+        }
+        _ => false,
+    }
+}
+
+/// 路径模板中的参数名称，例如 `/users/{id}` -> `id`
+fn path_template_params(path: &str) -> Vec<&str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}').map(|(name, _)| name))
+        .collect()
+}
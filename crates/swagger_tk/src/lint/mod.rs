@@ -0,0 +1,5 @@
+mod lint_diagnostic;
+mod lint_open_api;
+
+pub use lint_diagnostic::*;
+pub use lint_open_api::*;
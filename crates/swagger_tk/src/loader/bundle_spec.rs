@@ -0,0 +1,316 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Value};
+
+use super::{SpecFormat, SpecParseError, parse_spec_value};
+use crate::converter::is_swagger2;
+use crate::getter::{escape_json_pointer, unescape_json_pointer};
+
+/// 读取多文件文档，把指向其他文件的相对 `$ref`（例如 `./schemas/User.json#/User`）合并到根文档：
+/// - schema / parameter / response 等可复用对象提升到 components 中，并改写为内部引用
+/// - 其他位置（例如 paths 下的路径项）直接内联，内联出现循环引用时返回错误
+pub fn bundle_spec(path: &Path) -> Result<Value, SpecParseError> {
+    let root_path = canonical_path(path)?;
+    let mut bundler = SpecBundler {
+        root_path: root_path.clone(),
+        ..Default::default()
+    };
+    let mut root = bundler.document(&root_path)?.clone();
+    bundler.swagger2 = is_swagger2(&root);
+    bundler.reserve_names(&root);
+    bundler.resolve(&mut root, &root_path, &mut Vec::new(), &mut Vec::new())?;
+    bundler.insert_hoisted(&mut root);
+    Ok(root)
+}
+
+/// 文档中是否存在指向其他文件的 `$ref`
+pub fn has_external_refs(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => {
+            matches!(map.get("$ref"), Some(Value::String(r)) if !r.starts_with('#'))
+                || map.values().any(has_external_refs)
+        }
+        Value::Array(items) => items.iter().any(has_external_refs),
+        _ => false,
+    }
+}
+
+enum ResolvedRef {
+    Ref(String),
+    Inline(Value),
+}
+
+#[derive(Default)]
+struct SpecBundler {
+    root_path: PathBuf,
+    swagger2: bool,
+    documents: HashMap<PathBuf, Value>,
+    /// (文件, JSON Pointer) -> 改写后的内部引用
+    refs: HashMap<(PathBuf, String), String>,
+    /// 每个分区中已占用的名称
+    names: HashMap<&'static str, HashSet<String>>,
+    hoisted: Vec<(&'static str, String, Value)>,
+}
+
+impl SpecBundler {
+    fn document(&mut self, path: &Path) -> Result<&Value, SpecParseError> {
+        if !self.documents.contains_key(path) {
+            let text = std::fs::read_to_string(path).map_err(|err| {
+                SpecParseError::new(format!("failed to read {}: {err}", path.display()))
+            })?;
+            let value = parse_spec_value(&text, SpecFormat::resolve(Some(path), &text))
+                .map_err(|err| err.with_file(path))?;
+            self.documents.insert(path.to_path_buf(), value);
+        }
+        Ok(&self.documents[path])
+    }
+
+    fn target(&mut self, path: &Path, pointer: &str) -> Result<Value, SpecParseError> {
+        self.document(path)?
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| {
+                SpecParseError::new(format!("$ref target `#{pointer}` not found")).with_file(path)
+            })
+    }
+
+    fn reserve_names(&mut self, root: &Value) {
+        for kind in COMPONENT_KINDS {
+            let Some(section) = self.section(kind) else {
+                continue;
+            };
+            let existing = section
+                .iter()
+                .try_fold(root, |value, key| value.get(key))
+                .and_then(Value::as_object)
+                .map(|map| map.keys().cloned().collect())
+                .unwrap_or_default();
+            self.names.insert(kind, existing);
+        }
+    }
+
+    /// 可复用对象在根文档中的分区路径，Swagger 2.0 只支持部分分区
+    fn section(&self, kind: &str) -> Option<Vec<&'static str>> {
+        let kind = COMPONENT_KINDS.into_iter().find(|item| *item == kind)?;
+        if !self.swagger2 {
+            return Some(vec!["components", kind]);
+        }
+        match kind {
+            "schemas" => Some(vec!["definitions"]),
+            "parameters" => Some(vec!["parameters"]),
+            "responses" => Some(vec!["responses"]),
+            _ => None,
+        }
+    }
+
+    fn resolve(
+        &mut self,
+        value: &mut Value,
+        file: &Path,
+        keys: &mut Vec<String>,
+        stack: &mut Vec<String>,
+    ) -> Result<(), SpecParseError> {
+        match value {
+            Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    keys.push(index.to_string());
+                    self.resolve(item, file, keys, stack)?;
+                    keys.pop();
+                }
+            }
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    let reference = reference.clone();
+                    match self.resolve_ref(&reference, file, keys, stack)? {
+                        Some(ResolvedRef::Ref(internal)) => {
+                            map.insert("$ref".to_string(), Value::String(internal));
+                        }
+                        Some(ResolvedRef::Inline(target)) => {
+                            map.remove("$ref");
+                            *value = merge_siblings(target, std::mem::take(map));
+                            return Ok(());
+                        }
+                        None => {}
+                    }
+                }
+                for (key, item) in map.iter_mut() {
+                    // 示例值中的 `$ref` 只是数据，不做解析
+                    if key == "$ref" || key == "example" {
+                        continue;
+                    }
+                    keys.push(key.clone());
+                    self.resolve(item, file, keys, stack)?;
+                    keys.pop();
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn resolve_ref(
+        &mut self,
+        reference: &str,
+        file: &Path,
+        keys: &[String],
+        stack: &mut Vec<String>,
+    ) -> Result<Option<ResolvedRef>, SpecParseError> {
+        let (file_part, pointer) = reference.split_once('#').unwrap_or((reference, ""));
+        if file_part.contains("://") {
+            // 只处理本地文件，远程引用原样保留
+            return Ok(None);
+        }
+        let target_file = if file_part.is_empty() {
+            file.to_path_buf()
+        } else {
+            let base = file.parent().unwrap_or(Path::new("."));
+            canonical_path(&base.join(file_part)).map_err(|err| err.with_file(file))?
+        };
+        if target_file == self.root_path {
+            return Ok((!file_part.is_empty()).then(|| ResolvedRef::Ref(format!("#{pointer}"))));
+        }
+
+        let key = (target_file.clone(), pointer.to_string());
+        let hoist = component_kind(keys).and_then(|kind| Some((kind, self.section(kind)?)));
+        if let Some((kind, section)) = hoist {
+            if let Some(internal) = self.refs.get(&key) {
+                return Ok(Some(ResolvedRef::Ref(internal.clone())));
+            }
+            let name = self.unique_name(kind, &suggest_name(&target_file, pointer));
+            let mut section_keys = section
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>();
+            let internal = format!(
+                "#/{}/{}",
+                section_keys.join("/"),
+                escape_json_pointer(&name)
+            );
+            // 先登记再解析目标，递归引用自身时直接得到内部引用
+            self.refs.insert(key, internal.clone());
+            let mut target = self.target(&target_file, pointer)?;
+            section_keys.push(name.clone());
+            self.resolve(
+                &mut target,
+                &target_file,
+                &mut section_keys,
+                &mut Vec::new(),
+            )?;
+            self.hoisted.push((kind, name, target));
+            return Ok(Some(ResolvedRef::Ref(internal)));
+        }
+
+        let id = format!("{}#{pointer}", target_file.display());
+        if stack.contains(&id) {
+            let mut chain = stack.clone();
+            chain.push(id);
+            return Err(SpecParseError::new(format!(
+                "circular $ref: {}",
+                chain.join(" -> ")
+            )));
+        }
+        let mut target = self.target(&target_file, pointer)?;
+        stack.push(id);
+        self.resolve(&mut target, &target_file, &mut keys.to_vec(), stack)?;
+        stack.pop();
+        Ok(Some(ResolvedRef::Inline(target)))
+    }
+
+    fn unique_name(&mut self, kind: &'static str, base: &str) -> String {
+        let names = self.names.entry(kind).or_default();
+        let mut name = base.to_string();
+        let mut index = 2;
+        while names.contains(&name) {
+            name = format!("{base}{index}");
+            index += 1;
+        }
+        names.insert(name.clone());
+        name
+    }
+
+    fn insert_hoisted(&mut self, root: &mut Value) {
+        for (kind, name, value) in std::mem::take(&mut self.hoisted) {
+            let Some(section) = self.section(kind) else {
+                continue;
+            };
+            let mut pointer = String::new();
+            for key in section {
+                if let Some(parent) = root.pointer_mut(&pointer).and_then(Value::as_object_mut) {
+                    parent
+                        .entry(key.to_string())
+                        .or_insert_with(|| Value::Object(Map::new()));
+                }
+                pointer = format!("{pointer}/{key}");
+            }
+            if let Some(map) = root.pointer_mut(&pointer).and_then(Value::as_object_mut) {
+                map.insert(name, value);
+            }
+        }
+    }
+}
+
+const COMPONENT_KINDS: [&str; 7] = [
+    "schemas",
+    "parameters",
+    "requestBodies",
+    "responses",
+    "headers",
+    "examples",
+    "securitySchemes",
+];
+
+/// 根据 `$ref` 所在的位置判断引用对象的类型，返回 `None` 时直接内联
+fn component_kind(keys: &[String]) -> Option<&'static str> {
+    let last = keys.last()?.as_str();
+    let parent = keys.len().checked_sub(2).map(|index| keys[index].as_str());
+    match (parent, last) {
+        (Some("paths" | "webhooks" | "callbacks"), _) => None,
+        (_, "schema" | "items" | "additionalProperties" | "not") => Some("schemas"),
+        (
+            Some("schemas" | "definitions" | "properties" | "$defs" | "allOf" | "oneOf" | "anyOf"),
+            _,
+        ) => Some("schemas"),
+        (Some("parameters"), _) => Some("parameters"),
+        (Some("requestBodies"), _) | (_, "requestBody") => Some("requestBodies"),
+        (Some("responses"), _) => Some("responses"),
+        (Some("headers"), _) => Some("headers"),
+        (Some("examples"), _) => Some("examples"),
+        (Some("securitySchemes"), _) => Some("securitySchemes"),
+        _ => None,
+    }
+}
+
+/// 优先使用 JSON Pointer 的最后一段作为名称，否则使用文件名
+fn suggest_name(file: &Path, pointer: &str) -> String {
+    pointer
+        .rsplit('/')
+        .next()
+        .filter(|segment| !segment.is_empty())
+        .map(unescape_json_pointer)
+        .or_else(|| {
+            file.file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+        })
+        .unwrap_or_else(|| "Unnamed".to_string())
+}
+
+/// 内联时保留 `$ref` 旁的其他字段（例如 description）
+fn merge_siblings(target: Value, siblings: Map<String, Value>) -> Value {
+    match target {
+        Value::Object(mut map) => {
+            for (key, value) in siblings {
+                map.entry(key).or_insert(value);
+            }
+            Value::Object(map)
+        }
+        target => target,
+    }
+}
+
+fn canonical_path(path: &Path) -> Result<PathBuf, SpecParseError> {
+    path.canonicalize()
+        .map_err(|err| SpecParseError::new(format!("failed to read {}: {err}", path.display())))
+}
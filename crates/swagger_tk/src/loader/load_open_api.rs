@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{
+    SpecFormat, SpecParseError, bundle_spec, has_external_refs, is_merge_manifest, merge_spec,
+    merge_spec::merge_spec_visiting, parse_open_api::parse_open_api_source_value,
+    parse_open_api_value, parse_spec_value,
+};
+use crate::model::OpenAPIObject;
+use crate::overlay::{apply_overlay, load_overlay};
+
+/// 读取并解析 OpenAPI 文件，格式由扩展名或内容自动识别，文本只解析一次；
+/// 存在指向其他文件的 `$ref` 时先合并为单个文档，文件为多文档合并清单时合并清单中的所有文档
+pub fn load_open_api(path: &Path) -> Result<OpenAPIObject, SpecParseError> {
+    load_open_api_visiting(path, &mut Vec::new())
+}
+
+/// `visiting` 为正在合并的清单，用于发现互相包含的清单
+pub(crate) fn load_open_api_visiting(
+    path: &Path,
+    visiting: &mut Vec<PathBuf>,
+) -> Result<OpenAPIObject, SpecParseError> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| SpecParseError::new(format!("failed to read {}: {err}", path.display())))?;
+    let format = SpecFormat::resolve(Some(path), &text);
+    let value = parse_spec_value(&text, format)?;
+    if is_merge_manifest(&value) {
+        return parse_open_api_value(merge_spec_visiting(path, value, visiting)?);
+    }
+    if !has_external_refs(&value) {
+        return parse_open_api_source_value(&text, format, value);
+    }
+    parse_open_api_value(bundle_spec(path)?)
+}
+
+/// 读取 OpenAPI 文件为 JSON 值，同样会合并外部引用及多文档清单，但不解析为 `OpenAPIObject`
+pub fn load_spec_value(path: &Path) -> Result<Value, SpecParseError> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| SpecParseError::new(format!("failed to read {}: {err}", path.display())))?;
+    let value = parse_spec_value(&text, SpecFormat::resolve(Some(path), &text))?;
+    if is_merge_manifest(&value) {
+        return merge_spec(path, value);
+    }
+    if !has_external_refs(&value) {
+        return Ok(value);
+    }
+    bundle_spec(path)
+}
+
+/// 读取 OpenAPI 文件并依次应用 Overlay 文档后再解析，同时返回未匹配任何节点的 action 提示
+pub fn load_open_api_with_overlays(
+    path: &Path,
+    overlays: &[PathBuf],
+) -> Result<(OpenAPIObject, Vec<String>), SpecParseError> {
+    if overlays.is_empty() {
+        return Ok((load_open_api(path)?, vec![]));
+    }
+    let mut value = load_spec_value(path)?;
+    let mut warnings = Vec::new();
+    for overlay_path in overlays {
+        let overlay = load_overlay(overlay_path)?;
+        let overlay_warnings = apply_overlay(&mut value, &overlay)
+            .map_err(|err| SpecParseError::new(err).with_file(overlay_path))?;
+        warnings.extend(
+            overlay_warnings
+                .into_iter()
+                .map(|warning| format!("{}: {warning}", overlay_path.display())),
+        );
+    }
+    Ok((parse_open_api_value(value)?, warnings))
+}
@@ -0,0 +1,492 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::{SpecParseError, load_open_api::load_open_api_visiting};
+use crate::getter::{escape_json_pointer, unescape_json_pointer};
+
+/// 多文档合并清单，`-i` 指向该文件时按顺序加载并合并其中的文档
+///
+/// ```yaml
+/// info: { title: gateway, version: 1.0.0 }
+/// specs:
+///   - input: ./user.json
+///     prefix: user
+///     basePath: /user-api
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct MergeManifest {
+    /// 覆盖合并结果的 info，默认取第一个文档的 info
+    pub info: Option<Value>,
+    /// 覆盖合并结果的 servers，默认取第一个文档的 servers
+    pub servers: Option<Value>,
+    pub specs: Vec<MergeManifestEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MergeManifestEntry {
+    /// 文档路径，相对于清单文件
+    pub input: String,
+    /// 命名空间前缀，同时用于重命名冲突的 components
+    pub prefix: Option<String>,
+    /// 添加到该文档所有路径前的基础路径
+    #[serde(rename = "basePath")]
+    pub base_path: Option<String>,
+}
+
+/// 待合并的单个文档
+#[derive(Debug, Clone)]
+pub struct MergeSource {
+    pub spec: Value,
+    pub prefix: Option<String>,
+    pub base_path: Option<String>,
+}
+
+/// 是否为多文档合并清单（包含 `specs` 数组且不是 OpenAPI / Swagger 文档）
+pub fn is_merge_manifest(value: &Value) -> bool {
+    value.get("specs").is_some_and(Value::is_array)
+        && value.get("openapi").is_none()
+        && value.get("swagger").is_none()
+}
+
+/// 读取清单中的所有文档并合并，文档本身可以是多文件文档、Swagger 2.0 或另一个清单
+pub fn merge_spec(path: &Path, manifest: Value) -> Result<Value, SpecParseError> {
+    merge_spec_visiting(path, manifest, &mut Vec::new())
+}
+
+/// `visiting` 为正在合并的清单，清单直接或间接包含自身时返回错误
+pub(crate) fn merge_spec_visiting(
+    path: &Path,
+    manifest: Value,
+    visiting: &mut Vec<PathBuf>,
+) -> Result<Value, SpecParseError> {
+    let key = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if let Some(start) = visiting.iter().position(|item| *item == key) {
+        let cycle = visiting[start..]
+            .iter()
+            .chain([&key])
+            .map(|item| item.display().to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        return Err(
+            SpecParseError::new(format!("merge manifest includes itself: {cycle}")).with_file(path),
+        );
+    }
+    visiting.push(key);
+    let merged = merge_manifest(path, manifest, visiting);
+    visiting.pop();
+    merged
+}
+
+fn merge_manifest(
+    path: &Path,
+    manifest: Value,
+    visiting: &mut Vec<PathBuf>,
+) -> Result<Value, SpecParseError> {
+    let manifest = serde_json::from_value::<MergeManifest>(manifest).map_err(|err| {
+        SpecParseError::new(format!("invalid merge manifest: {err}")).with_file(path)
+    })?;
+    if manifest.specs.is_empty() {
+        return Err(SpecParseError::new("merge manifest has no specs").with_file(path));
+    }
+
+    let base = path.parent().unwrap_or(Path::new("."));
+    let mut sources = Vec::new();
+    for entry in manifest.specs {
+        let input = base.join(&entry.input);
+        let open_api = load_open_api_visiting(&input, visiting).map_err(|err| match err.file {
+            Some(_) => err,
+            None => err.with_file(&input),
+        })?;
+        let spec = serde_json::to_value(&open_api)
+            .map_err(|err| SpecParseError::new(err.to_string()).with_file(&input))?;
+        sources.push(MergeSource {
+            spec,
+            prefix: entry.prefix,
+            base_path: entry.base_path,
+        });
+    }
+
+    let mut merged =
+        merge_spec_values(sources).map_err(|err| SpecParseError::new(err).with_file(path))?;
+    for (key, value) in [("info", manifest.info), ("servers", manifest.servers)] {
+        if let Some(value) = value {
+            merged[key] = value;
+        }
+    }
+    Ok(merged)
+}
+
+/// 合并多个 OpenAPI 3 文档：
+/// - 路径加上各自的 `base_path`，同一路径的同一方法重复时报错
+/// - 接口的 tag 加上 `prefix/` 前缀（没有 tag 的接口使用 `prefix`），生成时作为命名空间的第一段
+/// - 文档级 `security` 下放到没有声明 `security` 的接口上
+/// - 同名的 components 内容相同时合并，不同时以前缀重命名后来的一个并改写引用
+pub fn merge_spec_values(sources: Vec<MergeSource>) -> Result<Value, String> {
+    let mut merged = Map::new();
+    let mut paths = Map::new();
+    let mut components = Map::<String, Value>::new();
+    let mut tags = Vec::<Value>::new();
+    let mut webhooks = Map::new();
+
+    for (index, source) in sources.into_iter().enumerate() {
+        let MergeSource {
+            spec,
+            prefix,
+            base_path,
+        } = source;
+        let Value::Object(mut spec) = spec else {
+            return Err(format!("spec #{} is not an object", index + 1));
+        };
+        let prefix = prefix.filter(|value| !value.trim().is_empty());
+        if index == 0 {
+            for key in ["openapi", "info", "servers", "jsonSchemaDialect"] {
+                if let Some(value) = spec.get(key) {
+                    merged.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+
+        push_down_security(&mut spec);
+        if let Some(prefix) = &prefix {
+            prefix_tags(&mut spec, prefix);
+        }
+
+        let renames = reconcile_components(&spec, &components, prefix.as_deref());
+        for value in spec.values_mut() {
+            rewrite_refs(value, &renames);
+        }
+
+        if let Some(Value::Object(source_components)) = spec.remove("components") {
+            for (kind, items) in source_components {
+                let Value::Object(items) = items else {
+                    continue;
+                };
+                let target = components
+                    .entry(kind.clone())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                let Some(target) = target.as_object_mut() else {
+                    continue;
+                };
+                for (name, value) in items {
+                    let name = renames
+                        .get(&(kind.clone(), name.clone()))
+                        .cloned()
+                        .unwrap_or(name);
+                    target.entry(name).or_insert(value);
+                }
+            }
+        }
+
+        if let Some(Value::Object(source_paths)) = spec.remove("paths") {
+            for (path, item) in source_paths {
+                let path = join_base_path(base_path.as_deref(), &path);
+                merge_path_item(&mut paths, path, item)?;
+            }
+        }
+
+        if let Some(Value::Object(source_webhooks)) = spec.remove("webhooks") {
+            for (name, item) in source_webhooks {
+                match webhooks.get(&name) {
+                    Some(existing) if *existing != item => {
+                        return Err(format!("webhook `{name}` is declared by several specs"));
+                    }
+                    _ => {
+                        webhooks.insert(name, item);
+                    }
+                }
+            }
+        }
+
+        if let Some(Value::Array(source_tags)) = spec.remove("tags") {
+            for tag in source_tags {
+                let name = tag.get("name");
+                if !tags.iter().any(|item| item.get("name") == name) {
+                    tags.push(tag);
+                }
+            }
+        }
+    }
+
+    merged.insert("paths".to_string(), Value::Object(paths));
+    if !webhooks.is_empty() {
+        merged.insert("webhooks".to_string(), Value::Object(webhooks));
+    }
+    if !components.is_empty() {
+        merged.insert("components".to_string(), Value::Object(components));
+    }
+    if !tags.is_empty() {
+        merged.insert("tags".to_string(), Value::Array(tags));
+    }
+    Ok(Value::Object(merged))
+}
+
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+fn operations_mut(spec: &mut Map<String, Value>) -> impl Iterator<Item = &mut Map<String, Value>> {
+    spec.get_mut("paths")
+        .and_then(Value::as_object_mut)
+        .into_iter()
+        .flat_map(|paths| paths.values_mut())
+        .filter_map(Value::as_object_mut)
+        .flat_map(|item| {
+            item.iter_mut()
+                .filter(|(key, _)| METHODS.contains(&key.as_str()))
+                .filter_map(|(_, operation)| operation.as_object_mut())
+        })
+}
+
+/// 合并后只有一个文档级 `security`，因此先写到各个接口上
+fn push_down_security(spec: &mut Map<String, Value>) {
+    let Some(security) = spec.remove("security") else {
+        return;
+    };
+    for operation in operations_mut(spec) {
+        operation
+            .entry("security")
+            .or_insert_with(|| security.clone());
+    }
+}
+
+fn prefix_tags(spec: &mut Map<String, Value>, prefix: &str) {
+    for operation in operations_mut(spec) {
+        let tags = match operation.get("tags").and_then(Value::as_array) {
+            Some(tags) if !tags.is_empty() => tags
+                .iter()
+                .map(|tag| match tag.as_str() {
+                    Some(tag) => Value::String(format!("{prefix}/{tag}")),
+                    None => tag.clone(),
+                })
+                .collect(),
+            _ => vec![Value::String(prefix.to_string())],
+        };
+        operation.insert("tags".to_string(), Value::Array(tags));
+    }
+    if let Some(tags) = spec.get_mut("tags").and_then(Value::as_array_mut) {
+        for tag in tags.iter_mut() {
+            if let Some(Value::String(name)) = tag.get_mut("name") {
+                *name = format!("{prefix}/{name}");
+            }
+        }
+    }
+}
+
+/// 计算需要重命名的 components：(分区, 原名) -> 新名称
+///
+/// 重命名会改变引用它的 components 的内容，因此反复比较直到没有新的冲突
+fn reconcile_components(
+    spec: &Map<String, Value>,
+    merged: &Map<String, Value>,
+    prefix: Option<&str>,
+) -> BTreeMap<(String, String), String> {
+    let mut renames = BTreeMap::new();
+    let Some(Value::Object(source)) = spec.get("components") else {
+        return renames;
+    };
+    let prefix = prefix.map(to_pascal_case).unwrap_or_default();
+
+    loop {
+        let mut rewritten = Value::Object(source.clone());
+        rewrite_refs(&mut rewritten, &renames);
+        let mut conflicts = Vec::new();
+        for (kind, items) in rewritten.as_object().into_iter().flatten() {
+            for (name, value) in items.as_object().into_iter().flatten() {
+                let key = (kind.clone(), name.clone());
+                if renames.contains_key(&key) {
+                    continue;
+                }
+                let existing = merged.get(kind).and_then(|items| items.get(name));
+                if existing.is_some_and(|existing| existing != value) {
+                    conflicts.push(key);
+                }
+            }
+        }
+        if conflicts.is_empty() {
+            return renames;
+        }
+        for (kind, name) in conflicts {
+            let taken = |candidate: &str| {
+                merged
+                    .get(&kind)
+                    .and_then(|items| items.get(candidate))
+                    .is_some()
+                    || source
+                        .get(&kind)
+                        .and_then(|items| items.get(candidate))
+                        .is_some()
+                    || renames
+                        .iter()
+                        .any(|((item_kind, _), renamed)| *item_kind == kind && renamed == candidate)
+            };
+            let base = format!("{prefix}{name}");
+            let mut candidate = base.clone();
+            let mut index = 2;
+            while taken(&candidate) {
+                candidate = format!("{base}{index}");
+                index += 1;
+            }
+            renames.insert((kind, name), candidate);
+        }
+    }
+}
+
+/// 改写 `$ref`、discriminator `mapping` 和安全要求中被重命名的 components
+fn rewrite_refs(value: &mut Value, renames: &BTreeMap<(String, String), String>) {
+    if renames.is_empty() {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                match (key.as_str(), item) {
+                    ("$ref", Value::String(r#ref)) => rename_ref(r#ref, renames),
+                    ("mapping", Value::Object(mapping)) => {
+                        for target in mapping.values_mut() {
+                            match target {
+                                // 不含 `/` 的映射值是 schema 名称而不是引用
+                                Value::String(name) if !name.contains('/') => {
+                                    let key = ("schemas".to_string(), name.clone());
+                                    if let Some(renamed) = renames.get(&key) {
+                                        *name = renamed.clone();
+                                    }
+                                }
+                                Value::String(r#ref) => rename_ref(r#ref, renames),
+                                other => rewrite_refs(other, renames),
+                            }
+                        }
+                    }
+                    ("security", Value::Array(requirements)) => {
+                        for requirement in requirements.iter_mut() {
+                            rename_security_requirement(requirement, renames);
+                        }
+                    }
+                    (_, item) => rewrite_refs(item, renames),
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| rewrite_refs(item, renames)),
+        _ => {}
+    }
+}
+
+fn rename_ref(r#ref: &mut String, renames: &BTreeMap<(String, String), String>) {
+    let Some(rest) = r#ref.strip_prefix("#/components/") else {
+        return;
+    };
+    let mut segments = rest.splitn(3, '/');
+    let (Some(kind), Some(name)) = (segments.next(), segments.next()) else {
+        return;
+    };
+    let key = (kind.to_string(), unescape_json_pointer(name));
+    if let Some(renamed) = renames.get(&key) {
+        let mut result = format!("#/components/{kind}/{}", escape_json_pointer(renamed));
+        if let Some(tail) = segments.next() {
+            result.push('/');
+            result.push_str(tail);
+        }
+        *r#ref = result;
+    }
+}
+
+fn rename_security_requirement(
+    requirement: &mut Value,
+    renames: &BTreeMap<(String, String), String>,
+) {
+    let Value::Object(map) = requirement else {
+        return;
+    };
+    *map = std::mem::take(map)
+        .into_iter()
+        .map(|(name, scopes)| {
+            let key = ("securitySchemes".to_string(), name);
+            let name = renames.get(&key).cloned().unwrap_or(key.1);
+            (name, scopes)
+        })
+        .collect();
+}
+
+fn join_base_path(base_path: Option<&str>, path: &str) -> String {
+    let base = base_path.unwrap_or_default().trim().trim_end_matches('/');
+    if base.is_empty() {
+        return path.to_string();
+    }
+    let base = if base.starts_with('/') {
+        base.to_string()
+    } else {
+        format!("/{base}")
+    };
+    match path {
+        "" | "/" => base,
+        path if path.starts_with('/') => format!("{base}{path}"),
+        path => format!("{base}/{path}"),
+    }
+}
+
+/// 同一路径出现在多个文档中时按方法合并，路径级的其他字段必须一致
+fn merge_path_item(
+    paths: &mut Map<String, Value>,
+    path: String,
+    item: Value,
+) -> Result<(), String> {
+    let Some(existing) = paths.get_mut(&path) else {
+        paths.insert(path, item);
+        return Ok(());
+    };
+    let (Some(existing), Value::Object(item)) = (existing.as_object_mut(), item) else {
+        return Err(format!("path `{path}` is declared by several specs"));
+    };
+
+    let shared = |map: &Map<String, Value>| {
+        map.iter()
+            .filter(|(key, _)| !METHODS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    if shared(existing) != shared(&item) {
+        return Err(format!(
+            "path `{path}` is declared by several specs with different path-level fields"
+        ));
+    }
+    let methods = item
+        .keys()
+        .filter(|key| METHODS.contains(&key.as_str()))
+        .collect::<BTreeSet<_>>();
+    if let Some(method) = methods
+        .iter()
+        .find(|method| existing.contains_key(method.as_str()))
+    {
+        return Err(format!(
+            "operation {} {path} is declared by several specs",
+            method.to_uppercase()
+        ));
+    }
+    for (key, value) in item {
+        if METHODS.contains(&key.as_str()) {
+            existing.insert(key, value);
+        }
+    }
+    Ok(())
+}
+
+/// `user-service` -> `UserService`
+fn to_pascal_case(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,17 @@
+mod bundle_spec;
+mod load_open_api;
+mod merge_spec;
+mod normalize_spec;
+mod parse_open_api;
+mod spec_format;
+mod spec_parse_error;
+mod stringify_spec;
+
+pub use bundle_spec::*;
+pub use load_open_api::*;
+pub use merge_spec::*;
+pub use normalize_spec::*;
+pub use parse_open_api::*;
+pub use spec_format::*;
+pub use spec_parse_error::*;
+pub use stringify_spec::*;
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::model::OpenAPIObject;
+
+/// 将文档重新序列化为规范形式：
+/// - 对象的 key 按字典序排列
+/// - 去掉 `required` / `enum` / `tags` / `parameters` / `security` 中的重复项，保留首次出现的顺序
+/// - 按字段在文档中的位置区分结构与数据，示例值、默认值和扩展字段只排序，不去重
+pub fn normalize_spec(open_api: &OpenAPIObject) -> Result<Value, String> {
+    let mut value = serde_json::to_value(open_api).map_err(|err| err.to_string())?;
+    normalize_value(&mut value, Slot::Structure);
+    Ok(value)
+}
+
+/// 节点在文档中的位置，决定对象的 key 是关键字、名称还是数据
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Slot {
+    /// 普通的文档结构（operation、parameter、media type 等）
+    Structure,
+    Schema,
+    /// key 是名称的映射（paths、responses、content 等），值为文档结构
+    Names,
+    /// key 是名称、值为 schema 的映射（components.schemas、properties 等）
+    SchemaNames,
+    /// key 是名称、值为 Example Object 的映射
+    ExampleNames,
+    Example,
+    /// key 是名称、值为 Link Object 的映射
+    LinkNames,
+    Link,
+    /// 示例值、默认值、扩展字段等数据，只排序不去重
+    Data,
+}
+
+impl Slot {
+    /// 字段 `key` 的值所处的位置
+    fn child(self, key: &str, value: &Value) -> Slot {
+        match self {
+            Slot::Data => Slot::Data,
+            _ if key.starts_with("x-") => Slot::Data,
+            Slot::Names => Slot::Structure,
+            Slot::SchemaNames => Slot::Schema,
+            Slot::ExampleNames => Slot::Example,
+            Slot::LinkNames => Slot::Link,
+            Slot::Schema => match key {
+                "default" | "const" | "example" | "examples" | "enum" => Slot::Data,
+                "properties" | "patternProperties" | "dependentSchemas" | "$defs"
+                | "definitions" => Slot::SchemaNames,
+                "items"
+                | "additionalProperties"
+                | "not"
+                | "contains"
+                | "propertyNames"
+                | "if"
+                | "then"
+                | "else"
+                | "unevaluatedItems"
+                | "unevaluatedProperties"
+                | "additionalItems"
+                | "contentSchema"
+                | "allOf"
+                | "oneOf"
+                | "anyOf"
+                | "prefixItems" => Slot::Schema,
+                _ => Slot::Structure,
+            },
+            Slot::Example => match key {
+                "value" => Slot::Data,
+                _ => Slot::Structure,
+            },
+            Slot::Link => match key {
+                "parameters" | "requestBody" => Slot::Data,
+                _ => Slot::Structure,
+            },
+            Slot::Structure => match key {
+                "schema" => Slot::Schema,
+                "schemas" => Slot::SchemaNames,
+                "example" => Slot::Data,
+                "examples" => Slot::ExampleNames,
+                "links" => Slot::LinkNames,
+                // operation 中是参数数组，components 中是名称映射
+                "parameters" if value.is_object() => Slot::Names,
+                "paths" | "webhooks" | "responses" | "callbacks" | "content" | "headers"
+                | "encoding" | "requestBodies" | "securitySchemes" | "pathItems" | "variables"
+                | "scopes" | "mapping" => Slot::Names,
+                _ => Slot::Structure,
+            },
+        }
+    }
+}
+
+fn normalize_value(value: &mut Value, slot: Slot) {
+    match value {
+        Value::Object(map) => {
+            let mut entries = std::mem::take(map).into_iter().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key, item) in entries.iter_mut() {
+                if slot != Slot::Data {
+                    dedup_items(key, item);
+                }
+                // enum 的成员是数据，只排序内部对象的 key
+                let child = slot.child(key, item);
+                normalize_value(item, child);
+            }
+            map.extend(entries);
+        }
+        Value::Array(items) => {
+            for item in items {
+                normalize_value(item, slot);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn dedup_items(key: &str, value: &mut Value) {
+    let Value::Array(items) = value else {
+        return;
+    };
+    let identity: fn(&Value) -> Value = match key {
+        "required" | "enum" | "security" => |item| item.clone(),
+        // 顶层 tags 是标签对象，operation 中是标签名
+        "tags" => |item| item.get("name").unwrap_or(item).clone(),
+        // 参数由 name + in 唯一确定，引用按 `$ref` 去重
+        "parameters" => |item| match item.get("$ref") {
+            Some(reference) => reference.clone(),
+            None => Value::Array(vec![
+                item.get("name").cloned().unwrap_or_default(),
+                item.get("in").cloned().unwrap_or_default(),
+            ]),
+        },
+        _ => return,
+    };
+    let mut seen = HashSet::new();
+    items.retain(|item| seen.insert(identity(item).to_string()));
+}
@@ -0,0 +1,230 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+
+use super::{SpecFormat, SpecParseError};
+use crate::converter::{is_swagger2, swagger2_to_open_api};
+use crate::model::OpenAPIObject;
+
+/// 将文本解析为 JSON 值，YAML 中的非字符串键（例如 `200:`）会被转换为字符串
+pub fn parse_spec_value(text: &str, format: SpecFormat) -> Result<Value, SpecParseError> {
+    match format {
+        SpecFormat::Json => {
+            serde_json::from_str(text).map_err(|err| SpecParseError::from_json(&err))
+        }
+        SpecFormat::Yaml => {
+            let value: serde_yaml::Value =
+                serde_yaml::from_str(text).map_err(|err| SpecParseError::from_yaml(&err))?;
+            if !has_inexact_number(&value) {
+                return yaml_to_json(value);
+            }
+            // serde_yaml 把小数存为 f64，按第一遍的结构再读一遍以取回数字原文
+            YamlJsonSeed(&value)
+                .deserialize(serde_yaml::Deserializer::from_str(text))
+                .map_err(|err| SpecParseError::from_yaml(&err))
+        }
+    }
+}
+
+/// 按指定格式解析 OpenAPI 文档，Swagger 2.0 文档会先升级为 OpenAPI 3
+pub fn parse_open_api(text: &str, format: SpecFormat) -> Result<OpenAPIObject, SpecParseError> {
+    match format {
+        SpecFormat::Json => serde_json::from_str(text).or_else(|err| {
+            // 只有直接解析失败时才检查是否为 Swagger 2.0，避免 OpenAPI 3 文档被解析两次
+            let value = parse_spec_value(text, SpecFormat::Json)?;
+            if is_swagger2(&value) {
+                upgrade_swagger2_value(&value, Some(format))
+            } else {
+                Err(SpecParseError::from_json(&err))
+            }
+        }),
+        SpecFormat::Yaml => {
+            let value = parse_spec_value(text, SpecFormat::Yaml)?;
+            parse_open_api_source_value(text, format, value)
+        }
+    }
+}
+
+/// 解析已由 `text` 读取得到的 JSON 值，Swagger 2.0 文档会先升级为 OpenAPI 3；
+/// 通过 JSON 值转换会丢失位置信息，只在失败时按原文重新反序列化一次以取得行列号
+pub(crate) fn parse_open_api_source_value(
+    text: &str,
+    format: SpecFormat,
+    value: Value,
+) -> Result<OpenAPIObject, SpecParseError> {
+    if is_swagger2(&value) {
+        return upgrade_swagger2_value(&value, Some(format));
+    }
+    serde_json::from_value(value).map_err(|err| {
+        let located = match format {
+            SpecFormat::Json => serde_json::from_str::<OpenAPIObject>(text)
+                .err()
+                .filter(|json_err| json_err.line() != 0)
+                .map(|json_err| SpecParseError::from_json(&json_err)),
+            SpecFormat::Yaml => serde_yaml::from_str::<OpenAPIObject>(text)
+                .err()
+                .filter(|yaml_err| yaml_err.location().is_some())
+                .map(|yaml_err| SpecParseError::from_yaml(&yaml_err)),
+        };
+        located.unwrap_or_else(|| SpecParseError {
+            format: Some(format),
+            ..SpecParseError::new(err.to_string())
+        })
+    })
+}
+
+/// 根据内容自动识别格式并解析 OpenAPI 文档
+pub fn parse_open_api_auto(text: &str) -> Result<OpenAPIObject, SpecParseError> {
+    parse_open_api(text, SpecFormat::detect(text))
+}
+
+/// 解析已读取为 JSON 值的文档（例如合并后的多文件文档），Swagger 2.0 文档会先升级为 OpenAPI 3
+pub fn parse_open_api_value(value: Value) -> Result<OpenAPIObject, SpecParseError> {
+    if is_swagger2(&value) {
+        return upgrade_swagger2_value(&value, None);
+    }
+    serde_json::from_value(value).map_err(|err| SpecParseError::new(err.to_string()))
+}
+
+fn upgrade_swagger2_value(
+    value: &Value,
+    format: Option<SpecFormat>,
+) -> Result<OpenAPIObject, SpecParseError> {
+    swagger2_to_open_api(value).map_err(|message| SpecParseError {
+        format,
+        ..SpecParseError::new(format!("failed to upgrade Swagger 2.0 document: {message}"))
+    })
+}
+
+fn yaml_to_json(value: serde_yaml::Value) -> Result<Value, SpecParseError> {
+    Ok(match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(v) => Value::Bool(v),
+        serde_yaml::Value::Number(v) => yaml_number_to_json(&v),
+        serde_yaml::Value::String(v) => Value::String(v),
+        serde_yaml::Value::Sequence(items) => Value::Array(
+            items
+                .into_iter()
+                .map(yaml_to_json)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        serde_yaml::Value::Mapping(mapping) => {
+            let mut object = Map::new();
+            for (key, value) in mapping {
+                object.insert(yaml_key_to_string(key)?, yaml_to_json(value)?);
+            }
+            Value::Object(object)
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_to_json(tagged.value)?,
+    })
+}
+
+fn yaml_number_to_json(number: &serde_yaml::Number) -> Value {
+    if let Some(v) = number.as_u64() {
+        Value::from(v)
+    } else if let Some(v) = number.as_i64() {
+        Value::from(v)
+    } else {
+        number
+            .as_f64()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or(Value::Null)
+    }
+}
+
+/// 优先按原文构造数字（如 `19.990`、`0.12345678901234567890`），
+/// 原文不是 JSON 数字写法时（如 `0x1F`、`.inf`）再使用 serde_yaml 解析出的值
+fn yaml_scalar_to_number(text: &str, number: &serde_yaml::Number) -> Value {
+    text.parse::<serde_json::Number>()
+        .map(Value::Number)
+        .unwrap_or_else(|_| yaml_number_to_json(number))
+}
+
+/// 是否存在无法用 64 位整数精确表示的数字
+fn has_inexact_number(value: &serde_yaml::Value) -> bool {
+    match value {
+        serde_yaml::Value::Number(v) => v.as_u64().is_none() && v.as_i64().is_none(),
+        serde_yaml::Value::Sequence(items) => items.iter().any(has_inexact_number),
+        serde_yaml::Value::Mapping(mapping) => mapping.values().any(has_inexact_number),
+        serde_yaml::Value::Tagged(tagged) => has_inexact_number(&tagged.value),
+        _ => false,
+    }
+}
+
+/// 以已解析的 YAML 值为向导重新反序列化同一文档：数字位置改用 `deserialize_str` 读取标量原文，
+/// 其余节点沿用 [`yaml_to_json`] 的转换
+struct YamlJsonSeed<'a>(&'a serde_yaml::Value);
+
+impl<'de> DeserializeSeed<'de> for YamlJsonSeed<'_> {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        match self.0 {
+            serde_yaml::Value::Number(number) => {
+                let text = String::deserialize(deserializer)?;
+                Ok(yaml_scalar_to_number(&text, number))
+            }
+            serde_yaml::Value::Sequence(_) => deserializer.deserialize_seq(self),
+            serde_yaml::Value::Mapping(_) => deserializer.deserialize_map(self),
+            other => {
+                IgnoredAny::deserialize(deserializer)?;
+                yaml_to_json(other.clone()).map_err(|err| de::Error::custom(err.message))
+            }
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for YamlJsonSeed<'_> {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the same YAML document as the first pass")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let serde_yaml::Value::Sequence(items) = self.0 else {
+            return Err(de::Error::custom("unexpected sequence"));
+        };
+        let mut values = Vec::with_capacity(items.len());
+        for item in items {
+            let value = seq
+                .next_element_seed(YamlJsonSeed(item))?
+                .ok_or_else(|| de::Error::invalid_length(values.len(), &self))?;
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let serde_yaml::Value::Mapping(mapping) = self.0 else {
+            return Err(de::Error::custom("unexpected mapping"));
+        };
+        let mut object = Map::new();
+        for (key, value) in mapping {
+            if map.next_key::<IgnoredAny>()?.is_none() {
+                return Err(de::Error::invalid_length(object.len(), &self));
+            }
+            let key =
+                yaml_key_to_string(key.clone()).map_err(|err| de::Error::custom(err.message))?;
+            object.insert(key, map.next_value_seed(YamlJsonSeed(value))?);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+fn yaml_key_to_string(key: serde_yaml::Value) -> Result<String, SpecParseError> {
+    match key {
+        serde_yaml::Value::String(v) => Ok(v),
+        serde_yaml::Value::Number(v) => Ok(v.to_string()),
+        serde_yaml::Value::Bool(v) => Ok(v.to_string()),
+        serde_yaml::Value::Null => Ok("null".to_string()),
+        serde_yaml::Value::Tagged(tagged) => yaml_key_to_string(tagged.value),
+        _ => Err(SpecParseError {
+            format: Some(SpecFormat::Yaml),
+            ..SpecParseError::new("mapping keys must be scalar values")
+        }),
+    }
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+/// 文档的源格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecFormat {
+    Json,
+    Yaml,
+}
+
+impl SpecFormat {
+    /// 根据文件扩展名判断格式，无法识别时返回 None
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// 根据内容嗅探格式：以 `{` 或 `[` 开头视为 JSON，否则视为 YAML
+    pub fn detect(text: &str) -> Self {
+        let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            Self::Json
+        } else {
+            Self::Yaml
+        }
+    }
+
+    /// 结合扩展名与内容判断格式；扩展名不一定可靠（例如下载时统一补上 `.json`），
+    /// 因此除 `.yaml` / `.yml` 外都以内容嗅探为准
+    pub fn resolve(path: Option<&Path>, text: &str) -> Self {
+        match path.and_then(Self::from_path) {
+            Some(Self::Yaml) => Self::Yaml,
+            _ => Self::detect(text),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use super::SpecFormat;
+
+/// 解析文档时的错误，尽可能携带源文件中的行列号（从 1 开始）
+#[derive(Debug, Clone)]
+pub struct SpecParseError {
+    pub format: Option<SpecFormat>,
+    /// 出错的文件，多文件文档中用于定位被引用的文件
+    pub file: Option<PathBuf>,
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl SpecParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            format: None,
+            file: None,
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// 记录出错的文件
+    pub fn with_file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_path_buf());
+        self
+    }
+
+    pub(crate) fn from_json(err: &serde_json::Error) -> Self {
+        let (line, column) = if err.line() == 0 {
+            (None, None)
+        } else {
+            (Some(err.line()), Some(err.column()))
+        };
+        Self {
+            format: Some(SpecFormat::Json),
+            file: None,
+            message: strip_location_suffix(&err.to_string()),
+            line,
+            column,
+        }
+    }
+
+    pub(crate) fn from_yaml(err: &serde_yaml::Error) -> Self {
+        let location = err.location();
+        Self {
+            format: Some(SpecFormat::Yaml),
+            file: None,
+            message: strip_location_suffix(&err.to_string()),
+            line: location.as_ref().map(|v| v.line()),
+            column: location.as_ref().map(|v| v.column()),
+        }
+    }
+}
+
+impl fmt::Display for SpecParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.format {
+            Some(SpecFormat::Json) => "JSON parse error",
+            Some(SpecFormat::Yaml) => "YAML parse error",
+            None => "OpenAPI parse error",
+        };
+        let kind = match &self.file {
+            Some(file) => format!("{kind} in {}", file.display()),
+            None => kind.to_string(),
+        };
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(
+                f,
+                "{kind} at line {line}, column {column}: {}",
+                self.message
+            ),
+            (Some(line), None) => write!(f, "{kind} at line {line}: {}", self.message),
+            _ => write!(f, "{kind}: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SpecParseError {}
+
+/// serde_json / serde_yaml 会把位置拼接到消息末尾，这里去掉以免重复
+fn strip_location_suffix(message: &str) -> String {
+    match message.rfind(" at line ") {
+        Some(index) if message[index..].contains("column") => message[..index].to_string(),
+        _ => message.to_string(),
+    }
+}
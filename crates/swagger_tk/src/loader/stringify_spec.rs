@@ -0,0 +1,13 @@
+use serde_json::Value;
+
+use super::SpecFormat;
+
+/// 将文档按指定格式输出为文本，JSON 使用两个空格缩进
+pub fn stringify_spec(value: &Value, format: SpecFormat) -> Result<String, String> {
+    match format {
+        SpecFormat::Json => serde_json::to_string_pretty(value)
+            .map(|text| text + "\n")
+            .map_err(|err| err.to_string()),
+        SpecFormat::Yaml => serde_yaml::to_string(value).map_err(|err| err.to_string()),
+    }
+}
@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use super::{CallbackValue, ReferenceObject};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum ComponentsCallbacks {
-    Callback(HashMap<String, CallbackValue>),
+    Callback(BTreeMap<String, CallbackValue>),
     Reference(ReferenceObject),
 }
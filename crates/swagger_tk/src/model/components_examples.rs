@@ -0,0 +1,10 @@
+use super::{ExampleObject, ReferenceObject};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum ComponentsExamples {
+    // ExampleObject 的字段都是可选的，必须先尝试引用
+    Reference(ReferenceObject),
+    Example(ExampleObject),
+}
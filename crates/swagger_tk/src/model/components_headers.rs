@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ComponentsHeaders {
-    Header(HeaderObject),
+    // HeaderObject 的字段都是可选的，必须先尝试引用，否则 `$ref` 会被当成空的 header
     Reference(ReferenceObject),
+    Header(HeaderObject),
 }
@@ -1,23 +1,39 @@
-use super::SchemaEnum;
 use super::{
-    ComponentsCallbacks, ComponentsHeaders, ComponentsParameters, ComponentsPathItems,
-    ComponentsResponses,
+    ComponentsCallbacks, ComponentsExamples, ComponentsHeaders, ComponentsParameters,
+    ComponentsPathItems, ComponentsRequestBodies, ComponentsResponses, ComponentsSecuritySchemes,
 };
+use super::{SchemaEnum, SpecExtensions, SpecUnknownFields};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use swagger_macro::skip_serializing_none;
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ComponentsObject {
-    pub schemas: Option<HashMap<String, SchemaEnum>>,
+    pub schemas: Option<BTreeMap<String, SchemaEnum>>,
 
-    pub responses: Option<HashMap<String, ComponentsResponses>>,
+    pub responses: Option<BTreeMap<String, ComponentsResponses>>,
 
-    pub parameters: Option<HashMap<String, ComponentsParameters>>,
+    pub parameters: Option<BTreeMap<String, ComponentsParameters>>,
 
-    pub headers: Option<HashMap<String, ComponentsHeaders>>,
+    pub examples: Option<BTreeMap<String, ComponentsExamples>>,
 
-    pub callbacks: Option<HashMap<String, ComponentsCallbacks>>,
+    #[serde(rename = "requestBodies")]
+    pub request_bodies: Option<BTreeMap<String, ComponentsRequestBodies>>,
+
+    pub headers: Option<BTreeMap<String, ComponentsHeaders>>,
+
+    #[serde(rename = "securitySchemes")]
+    pub security_schemes: Option<BTreeMap<String, ComponentsSecuritySchemes>>,
+
+    pub callbacks: Option<BTreeMap<String, ComponentsCallbacks>>,
 
     #[serde(rename = "pathItems")]
-    pub path_items: Option<HashMap<String, ComponentsPathItems>>,
+    pub path_items: Option<BTreeMap<String, ComponentsPathItems>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
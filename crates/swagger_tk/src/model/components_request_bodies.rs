@@ -0,0 +1,9 @@
+use super::{ReferenceObject, RequestBodyObject};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ComponentsRequestBodies {
+    RequestBody(RequestBodyObject),
+    Reference(ReferenceObject),
+}
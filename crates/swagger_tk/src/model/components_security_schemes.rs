@@ -0,0 +1,9 @@
+use super::{ReferenceObject, SecuritySchemeObject};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ComponentsSecuritySchemes {
+    SecurityScheme(Box<SecuritySchemeObject>),
+    Reference(ReferenceObject),
+}
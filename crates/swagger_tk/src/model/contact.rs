@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OpenAPIInfoContact {
     pub name: Option<String>,
@@ -7,4 +11,10 @@ pub struct OpenAPIInfoContact {
     pub url: Option<String>,
 
     pub email: Option<String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
+
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiscriminatorObject {
+    /// 用于区分类型的属性名
+    #[serde(rename = "propertyName")]
+    pub property_name: String,
+
+    /// 属性值到 schema 名称或引用的映射
+    pub mapping: Option<BTreeMap<String, String>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
+}
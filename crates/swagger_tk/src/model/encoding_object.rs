@@ -0,0 +1,29 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
+
+use super::{ResponseHeaders, SpecExtensions, SpecUnknownFields};
+
+/// 请求体中单个属性的编码方式
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EncodingObject {
+    #[serde(rename = "contentType")]
+    pub content_type: Option<String>,
+
+    pub headers: Option<BTreeMap<String, ResponseHeaders>>,
+
+    pub style: Option<String>,
+
+    pub explode: Option<bool>,
+
+    #[serde(rename = "allowReserved")]
+    pub allow_reserved: Option<bool>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
+}
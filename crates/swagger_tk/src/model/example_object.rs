@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
+
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ExampleObject {
+    pub summary: Option<String>,
+
+    pub description: Option<String>,
+
+    pub value: Option<serde_json::Value>,
+
+    #[serde(rename = "externalValue")]
+    pub external_value: Option<String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
+}
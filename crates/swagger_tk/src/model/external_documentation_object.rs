@@ -1,8 +1,18 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ExternalDocumentationObject {
     pub description: Option<String>,
 
     pub url: String,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,5 +1,11 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
+
+use super::{ComponentsExamples, SchemaEnum, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct HeaderObject {
     pub name: Option<String>,
@@ -12,4 +18,18 @@ pub struct HeaderObject {
 
     #[serde(rename = "allowEmptyValue")]
     pub allow_empty_value: Option<bool>,
+
+    pub schema: Option<SchemaEnum>,
+
+    /// 示例值
+    pub example: Option<serde_json::Value>,
+
+    /// 命名示例，与 `example` 互斥
+    pub examples: Option<BTreeMap<String, ComponentsExamples>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct OpenAPIInfoLicense {
     pub name: String,
@@ -7,4 +11,10 @@ pub struct OpenAPIInfoLicense {
     pub identifier: Option<String>,
 
     pub url: Option<String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::ServerObject;
+use super::{ServerObject, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LinkObject {
     #[serde(rename = "operationRef")]
@@ -13,4 +15,10 @@ pub struct LinkObject {
     pub description: Option<String>,
 
     pub server: Option<ServerObject>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
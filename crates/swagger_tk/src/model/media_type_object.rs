@@ -1,8 +1,27 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::SchemaEnum;
+use super::{ComponentsExamples, EncodingObject, SchemaEnum, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MediaTypeObject {
-    pub schema: SchemaEnum,
+    pub schema: Option<SchemaEnum>,
+
+    /// 示例值
+    pub example: Option<serde_json::Value>,
+
+    /// 命名示例，与 `example` 互斥
+    pub examples: Option<BTreeMap<String, ComponentsExamples>>,
+
+    /// multipart / form 请求体中各属性的编码方式
+    pub encoding: Option<BTreeMap<String, EncodingObject>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
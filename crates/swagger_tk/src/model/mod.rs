@@ -1,11 +1,17 @@
 mod callback_value;
 mod components_callbacks;
+mod components_examples;
 mod components_headers;
 mod components_object;
 mod components_parameters;
 mod components_path_items;
+mod components_request_bodies;
 mod components_responses;
+mod components_security_schemes;
 mod contact;
+mod discriminator_object;
+mod encoding_object;
+mod example_object;
 mod external_documentation_object;
 mod header_object;
 mod license;
@@ -30,22 +36,30 @@ mod response_lihk;
 mod response_object;
 mod responses_value;
 mod schema;
-mod schema_object_enum;
+mod security_requirement_object;
 mod security_scheme_in;
 mod security_scheme_object;
 mod security_scheme_type;
 mod server_object;
 mod server_variable_object;
+mod spec_extensions;
+mod spec_unknown_fields;
 mod tag_object;
 
 pub use callback_value::*;
 pub use components_callbacks::*;
+pub use components_examples::*;
 pub use components_headers::*;
 pub use components_object::*;
 pub use components_parameters::*;
 pub use components_path_items::*;
+pub use components_request_bodies::*;
 pub use components_responses::*;
+pub use components_security_schemes::*;
 pub use contact::*;
+pub use discriminator_object::*;
+pub use encoding_object::*;
+pub use example_object::*;
 pub use external_documentation_object::*;
 pub use header_object::*;
 pub use license::*;
@@ -70,10 +84,12 @@ pub use response_lihk::*;
 pub use response_object::*;
 pub use responses_value::*;
 pub use schema::*;
-pub use schema_object_enum::*;
+pub use security_requirement_object::*;
 pub use security_scheme_in::*;
 pub use security_scheme_object::*;
 pub use security_scheme_type::*;
 pub use server_object::*;
 pub use server_variable_object::*;
+pub use spec_extensions::*;
+pub use spec_unknown_fields::*;
 pub use tag_object::*;
@@ -1,17 +1,28 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-#[derive(Debug, Serialize, Deserialize)]
+use super::{SpecExtensions, SpecUnknownFields};
+
+/// `authorizationUrl` 只用于 implicit / authorizationCode，`tokenUrl` 只用于 password / clientCredentials / authorizationCode
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OAuthFlowObject {
     #[serde(rename = "authorizationUrl")]
-    pub authorization_url: String,
+    pub authorization_url: Option<String>,
 
     #[serde(rename = "tokenUrl")]
-    pub token_url: String,
+    pub token_url: Option<String>,
 
     #[serde(rename = "refreshUrl")]
     pub refresh_url: Option<String>,
 
-    pub scopes: HashMap<String, String>,
+    pub scopes: BTreeMap<String, String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
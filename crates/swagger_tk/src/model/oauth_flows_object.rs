@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::OAuthFlowObject;
+use super::{OAuthFlowObject, SpecExtensions, SpecUnknownFields};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct OAuthFlowsObject {
     pub implicit: Option<OAuthFlowObject>,
 
@@ -13,4 +15,10 @@ pub struct OAuthFlowsObject {
 
     #[serde(rename = "authorizationCode")]
     pub authorization_code: Option<OAuthFlowObject>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,5 +1,7 @@
-use super::{OpenAPIInfoContact, OpenAPIInfoLicense};
+use super::{OpenAPIInfoContact, OpenAPIInfoLicense, SpecExtensions, SpecUnknownFields};
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OpenAPIInfo {
     /// 标题
@@ -20,4 +22,10 @@ pub struct OpenAPIInfo {
 
     /// 版本号
     pub version: String,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,9 +1,13 @@
-use super::{ComponentsObject, OpenAPIWebhooks};
+use super::{
+    ComponentsObject, OpenAPIWebhooks, SecurityRequirementObject, SpecExtensions, SpecUnknownFields,
+};
 use super::{ExternalDocumentationObject, OpenAPIInfo, PathItemObject, ServerObject, TagObject};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::str::FromStr;
+use swagger_macro::skip_serializing_none;
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OpenAPIObject {
     /// 版本号
@@ -12,21 +16,30 @@ pub struct OpenAPIObject {
     /// 信息
     pub info: Option<OpenAPIInfo>,
 
-    // pub json_schema_dialect: String,
+    /// Schema 默认方言（OpenAPI 3.1）
+    #[serde(rename = "jsonSchemaDialect")]
+    pub json_schema_dialect: Option<String>,
+
     pub servers: Option<Vec<ServerObject>>,
 
-    pub paths: Option<HashMap<String, PathItemObject>>,
+    pub paths: Option<BTreeMap<String, PathItemObject>>,
 
-    pub webhooks: Option<HashMap<String, OpenAPIWebhooks>>,
+    pub webhooks: Option<BTreeMap<String, OpenAPIWebhooks>>,
 
     pub components: Option<ComponentsObject>,
 
-    pub security: Option<Vec<HashMap<String, Vec<String>>>>,
+    pub security: Option<Vec<SecurityRequirementObject>>,
 
     pub tags: Option<Vec<TagObject>>,
 
     #[serde(rename = "externalDocs")]
     pub external_docs: Option<ExternalDocumentationObject>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
 
 impl FromStr for OpenAPIObject {
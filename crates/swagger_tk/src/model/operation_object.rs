@@ -1,12 +1,15 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
 use super::{
-    CallbackValue, ExternalDocumentationObject, OperationObjectParameters,
-    OperationObjectRequestBody, ResponsesValue, ServerObject,
+    ComponentsCallbacks, ExternalDocumentationObject, OperationObjectParameters,
+    OperationObjectRequestBody, ResponsesValue, SecurityRequirementObject, ServerObject,
+    SpecExtensions, SpecUnknownFields,
 };
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct OperationObject {
     pub tags: Option<Vec<String>>,
@@ -26,13 +29,19 @@ pub struct OperationObject {
     #[serde(rename = "requestBody")]
     pub request_body: Option<OperationObjectRequestBody>,
 
-    pub responses: Option<HashMap<String, ResponsesValue>>,
+    pub responses: Option<BTreeMap<String, ResponsesValue>>,
 
-    pub callbacks: Option<HashMap<String, CallbackValue>>,
+    pub callbacks: Option<BTreeMap<String, ComponentsCallbacks>>,
 
     pub deprecated: Option<bool>,
 
-    pub security: Option<Vec<HashMap<String, Vec<String>>>>,
+    pub security: Option<Vec<SecurityRequirementObject>>,
 
     pub servers: Option<Vec<ServerObject>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
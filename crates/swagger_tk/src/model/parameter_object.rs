@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::{ParameterObjectIn, SchemaEnum};
+use super::{ComponentsExamples, ParameterObjectIn, SchemaEnum, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ParameterObject {
     pub name: String,
@@ -25,4 +29,16 @@ pub struct ParameterObject {
     pub allow_reserved: Option<bool>,
 
     pub schema: Option<SchemaEnum>,
+
+    /// 示例值
+    pub example: Option<serde_json::Value>,
+
+    /// 命名示例，与 `example` 互斥
+    pub examples: Option<BTreeMap<String, ComponentsExamples>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum ParameterObjectIn {
     #[serde(rename = "query")]
     Query,
@@ -14,3 +14,15 @@ pub enum ParameterObjectIn {
     #[serde(rename = "cookie")]
     Cookie,
 }
+
+impl ParameterObjectIn {
+    /// 规范中的参数位置名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParameterObjectIn::Query => "query",
+            ParameterObjectIn::Header => "header",
+            ParameterObjectIn::Path => "path",
+            ParameterObjectIn::Cookie => "cookie",
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::{PathItemParameters, ServerObject, operation_object::OperationObject};
+use super::{
+    PathItemParameters, ServerObject, SpecExtensions, SpecUnknownFields,
+    operation_object::OperationObject,
+};
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PathItemObject {
     #[serde(rename = "$ref")]
@@ -29,5 +34,11 @@ pub struct PathItemObject {
 
     pub servers: Option<Vec<ServerObject>>,
 
-    pub parameters: Option<PathItemParameters>,
+    pub parameters: Option<Vec<PathItemParameters>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
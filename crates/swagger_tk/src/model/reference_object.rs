@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ReferenceObject {
     #[serde(rename = "$ref")]
@@ -8,4 +12,10 @@ pub struct ReferenceObject {
     pub summary: Option<String>,
 
     pub description: Option<String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,14 +1,22 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::MediaTypeObject;
+use super::{MediaTypeObject, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RequestBodyObject {
     pub description: Option<String>,
 
-    pub content: HashMap<String, MediaTypeObject>,
+    pub content: BTreeMap<String, MediaTypeObject>,
 
     pub required: Option<bool>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -5,6 +5,7 @@ use super::{HeaderObject, ReferenceObject};
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum ResponseHeaders {
-    Header(HeaderObject),
+    // HeaderObject 的字段都是可选的，必须先尝试引用，否则 `$ref` 会被当成空的 header
     Reference(ReferenceObject),
+    Header(HeaderObject),
 }
@@ -5,6 +5,7 @@ use super::{LinkObject, ReferenceObject};
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum ResponseLink {
-    Link(LinkObject),
+    // LinkObject 的字段都是可选的，必须先尝试引用
     Reference(ReferenceObject),
+    Link(LinkObject),
 }
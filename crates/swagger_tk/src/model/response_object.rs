@@ -1,16 +1,27 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::{MediaTypeObject, ResponseHeaders, response_lihk::ResponseLink};
+use super::{
+    MediaTypeObject, ResponseHeaders, SpecExtensions, SpecUnknownFields,
+    response_lihk::ResponseLink,
+};
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ResponseObject {
     pub description: String,
 
-    pub headers: Option<HashMap<String, ResponseHeaders>>,
+    pub headers: Option<BTreeMap<String, ResponseHeaders>>,
 
-    pub content: Option<HashMap<String, MediaTypeObject>>,
+    pub content: Option<BTreeMap<String, MediaTypeObject>>,
 
-    pub links: Option<HashMap<String, ResponseLink>>,
+    pub links: Option<BTreeMap<String, ResponseLink>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
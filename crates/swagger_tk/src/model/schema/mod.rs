@@ -1,21 +1,33 @@
+mod schema_all_of;
+mod schema_any;
+mod schema_any_of;
 mod schema_array;
 mod schema_bool;
 mod schema_enum;
 mod schema_integer;
+mod schema_not;
 mod schema_number;
 mod schema_object;
 mod schema_object_additional_properties;
+mod schema_one_of;
+mod schema_source_keywords;
 mod schema_string;
 mod schema_string_format;
 mod schema_type_enum;
 
+pub use schema_all_of::*;
+pub use schema_any::*;
+pub use schema_any_of::*;
 pub use schema_array::*;
 pub use schema_bool::*;
 pub use schema_enum::*;
 pub use schema_integer::*;
+pub use schema_not::*;
 pub use schema_number::*;
 pub use schema_object::*;
 pub use schema_object_additional_properties::*;
+pub use schema_one_of::*;
+pub use schema_source_keywords::*;
 pub use schema_string::*;
 pub use schema_string_format::*;
 pub use schema_type_enum::*;
@@ -0,0 +1,24 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
+
+use super::{SchemaEnum, SchemaTypeEnum};
+
+/// allOf 组合，所有成员同时成立（常用于继承）
+#[schema_base_attributes]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaAllOf {
+    #[serde(rename = "allOf")]
+    pub all_of: Vec<SchemaEnum>,
+
+    #[serde(rename = "type")]
+    pub r#type: Option<SchemaTypeEnum>,
+
+    pub required: Option<Vec<String>>,
+
+    pub properties: Option<BTreeMap<String, SchemaEnum>>,
+
+    pub description: Option<String>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
+
+/// 未声明 type 且无法从关键字推断类型的 schema，视为任意值
+#[schema_base_attributes]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaAny {
+    pub description: Option<String>,
+}
@@ -1,9 +1,10 @@
 use serde::{self, Deserialize, Serialize};
-use swagger_macro::schema_base_attributes;
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
 
 use super::{SchemaEnum, SchemaTypeEnum};
 
 #[schema_base_attributes]
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SchemaArray {
     #[serde(rename = "type")]
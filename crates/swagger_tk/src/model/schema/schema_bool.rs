@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
-use swagger_macro::schema_base_attributes;
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
 
 use super::SchemaTypeEnum;
 
 #[schema_base_attributes]
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SchemaBool {
     #[serde(rename = "type")]
@@ -1,18 +1,28 @@
-use serde::{Deserialize, Deserializer, Serialize, de};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
 use serde_json::Value;
 
-use super::{SchemaArray, SchemaBool, SchemaInteger, SchemaNumber, SchemaObject, SchemaString};
+use super::{
+    SchemaAllOf, SchemaAny, SchemaAnyOf, SchemaArray, SchemaBool, SchemaInteger, SchemaNot,
+    SchemaNumber, SchemaObject, SchemaOneOf, SchemaSourceKeywords, SchemaString,
+};
 use crate::model::ReferenceObject;
 
-#[derive(Debug, Serialize, Clone)]
+/// 反序列化时根据 `$ref` / 组合关键字 / `type` 选择分支，序列化时输出对应的 schema，
+/// 并写回被改写的 OpenAPI 3.1 关键字
+#[derive(Debug, Clone)]
 pub enum SchemaEnum {
     Ref(ReferenceObject),
-    Object(SchemaObject),
-    String(SchemaString),
-    Integer(SchemaInteger),
-    Number(SchemaNumber),
-    Boolean(SchemaBool),
-    Array(SchemaArray),
+    Object(Box<SchemaObject>),
+    String(Box<SchemaString>),
+    Integer(Box<SchemaInteger>),
+    Number(Box<SchemaNumber>),
+    Boolean(Box<SchemaBool>),
+    Array(Box<SchemaArray>),
+    AllOf(Box<SchemaAllOf>),
+    OneOf(Box<SchemaOneOf>),
+    AnyOf(Box<SchemaAnyOf>),
+    Not(Box<SchemaNot>),
+    Any(Box<SchemaAny>),
 }
 
 impl<'de> Deserialize<'de> for SchemaEnum {
@@ -20,40 +30,338 @@ impl<'de> Deserialize<'de> for SchemaEnum {
     where
         D: Deserializer<'de>,
     {
-        let v = Value::deserialize(deserializer)?;
-        let r#ref = v.get("$ref");
-        if r#ref.is_some() {
-            let a = ReferenceObject::deserialize(v).map_err(de::Error::custom)?;
-            return Ok(SchemaEnum::Ref(a));
+        let original = Value::deserialize(deserializer)?;
+        let mut v = original.clone();
+        normalize_json_schema(&mut v).map_err(de::Error::custom)?;
+        let source_keywords = SchemaSourceKeywords::diff(&original, &v);
+        let mut schema = schema_from_value(v)?;
+        if let Some(keywords) = schema.source_keywords_mut() {
+            *keywords = source_keywords;
         }
+        Ok(schema)
+    }
+}
+
+impl Serialize for SchemaEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(keywords) = self
+            .source_keywords()
+            .filter(|keywords| !keywords.is_empty())
+        else {
+            return self.serialize_variant(serializer);
+        };
+        if let Some(value) = keywords.boolean() {
+            return value.serialize(serializer);
+        }
+        let mut value = self
+            .serialize_variant(serde_json::value::Serializer)
+            .map_err(ser::Error::custom)?;
+        if let Value::Object(schema) = &mut value {
+            keywords.restore(schema);
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl SchemaEnum {
+    /// 反序列化时被改写的 OpenAPI 3.1 关键字，`$ref` 没有
+    pub fn source_keywords(&self) -> Option<&SchemaSourceKeywords> {
+        match self {
+            SchemaEnum::Ref(_) => None,
+            SchemaEnum::Object(schema) => Some(&schema.source_keywords),
+            SchemaEnum::String(schema) => Some(&schema.source_keywords),
+            SchemaEnum::Integer(schema) => Some(&schema.source_keywords),
+            SchemaEnum::Number(schema) => Some(&schema.source_keywords),
+            SchemaEnum::Boolean(schema) => Some(&schema.source_keywords),
+            SchemaEnum::Array(schema) => Some(&schema.source_keywords),
+            SchemaEnum::AllOf(schema) => Some(&schema.source_keywords),
+            SchemaEnum::OneOf(schema) => Some(&schema.source_keywords),
+            SchemaEnum::AnyOf(schema) => Some(&schema.source_keywords),
+            SchemaEnum::Not(schema) => Some(&schema.source_keywords),
+            SchemaEnum::Any(schema) => Some(&schema.source_keywords),
+        }
+    }
+
+    fn source_keywords_mut(&mut self) -> Option<&mut SchemaSourceKeywords> {
+        match self {
+            SchemaEnum::Ref(_) => None,
+            SchemaEnum::Object(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::String(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::Integer(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::Number(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::Boolean(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::Array(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::AllOf(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::OneOf(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::AnyOf(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::Not(schema) => Some(&mut schema.source_keywords),
+            SchemaEnum::Any(schema) => Some(&mut schema.source_keywords),
+        }
+    }
+
+    /// 按模型中的字段输出，不恢复原始关键字
+    fn serialize_variant<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            SchemaEnum::Ref(schema) => schema.serialize(serializer),
+            SchemaEnum::Object(schema) => schema.serialize(serializer),
+            SchemaEnum::String(schema) => schema.serialize(serializer),
+            SchemaEnum::Integer(schema) => schema.serialize(serializer),
+            SchemaEnum::Number(schema) => schema.serialize(serializer),
+            SchemaEnum::Boolean(schema) => schema.serialize(serializer),
+            SchemaEnum::Array(schema) => schema.serialize(serializer),
+            SchemaEnum::AllOf(schema) => schema.serialize(serializer),
+            SchemaEnum::OneOf(schema) => schema.serialize(serializer),
+            SchemaEnum::AnyOf(schema) => schema.serialize(serializer),
+            SchemaEnum::Not(schema) => schema.serialize(serializer),
+            SchemaEnum::Any(schema) => schema.serialize(serializer),
+        }
+    }
+}
+
+/// 根据 `$ref` / 组合关键字 / `type` 将已改写的 schema 解析为对应分支
+fn schema_from_value<E: de::Error>(v: Value) -> Result<SchemaEnum, E> {
+    let r#ref = v.get("$ref");
+    if r#ref.is_some() {
+        let a = ReferenceObject::deserialize(v).map_err(de::Error::custom)?;
+        return Ok(SchemaEnum::Ref(a));
+    }
+
+    if v.get("allOf").is_some() {
+        return Ok(SchemaEnum::AllOf(Box::new(
+            SchemaAllOf::deserialize(v).map_err(de::Error::custom)?,
+        )));
+    }
+    if v.get("oneOf").is_some() {
+        return Ok(SchemaEnum::OneOf(Box::new(
+            SchemaOneOf::deserialize(v).map_err(de::Error::custom)?,
+        )));
+    }
+    if v.get("anyOf").is_some() {
+        return Ok(SchemaEnum::AnyOf(Box::new(
+            SchemaAnyOf::deserialize(v).map_err(de::Error::custom)?,
+        )));
+    }
+    if v.get("not").is_some() && v.get("type").is_none() {
+        return Ok(SchemaEnum::Not(Box::new(
+            SchemaNot::deserialize(v).map_err(de::Error::custom)?,
+        )));
+    }
+
+    let Some(r#type) = v.get("type").and_then(|t| t.as_str()) else {
+        return Ok(SchemaEnum::Any(Box::new(
+            SchemaAny::deserialize(v).map_err(de::Error::custom)?,
+        )));
+    };
+
+    match r#type {
+        "string" => Ok(SchemaEnum::String(Box::new(
+            SchemaString::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+        "object" => Ok(SchemaEnum::Object(Box::new(
+            SchemaObject::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+        "number" => Ok(SchemaEnum::Number(Box::new(
+            SchemaNumber::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+        "integer" => Ok(SchemaEnum::Integer(Box::new(
+            SchemaInteger::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+        "boolean" => Ok(SchemaEnum::Boolean(Box::new(
+            SchemaBool::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+        "array" => Ok(SchemaEnum::Array(Box::new(
+            SchemaArray::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+        _ => Ok(SchemaEnum::Any(Box::new(
+            SchemaAny::deserialize(v).map_err(de::Error::custom)?,
+        ))),
+    }
+}
+
+/// 可以解析为具体分支的 `type`
+const KNOWN_SCHEMA_TYPES: [&str; 6] = ["string", "object", "number", "integer", "boolean", "array"];
+
+/// 将 OpenAPI 3.1（JSON Schema 2020-12）的写法转换为 3.0 模型可以表达的形式：
+/// - `type: ["string", "null"]` 转为 `type: "string"` + `nullable: true`
+/// - `enum` 中的 `null`、`x-nullable: true` 转为 `nullable: true`
+/// - `const` 转为单值 `enum`，缺少 `type` 时根据值推断
+/// - 数值形式的 `exclusiveMinimum` / `exclusiveMaximum` 转为 3.0 的布尔形式
+/// - `type: "null"` 转为 `nullable: true` 的任意值
+/// - 无法识别的 `type`（如 3.x 中的 `file`）视为任意值
+/// - 缺少 `type` 时根据 `properties` / `items` / `enum` 推断类型
+/// - 布尔 schema：`true` 转为任意值 `{}`，`false` 转为不接受任何值的 `{ "not": {} }`
+fn normalize_json_schema(v: &mut Value) -> Result<(), String> {
+    if let Value::Bool(accept) = v {
+        *v = if *accept {
+            serde_json::json!({})
+        } else {
+            serde_json::json!({ "not": {} })
+        };
+        return Ok(());
+    }
+    let Some(schema) = v.as_object_mut() else {
+        return Ok(());
+    };
+    if schema.contains_key("$ref") {
+        return Ok(());
+    }
+
+    let mut nullable = false;
+    if schema.get("type").and_then(Value::as_str) == Some("null") {
+        schema.remove("type");
+        nullable = true;
+    }
+    if let Some(Value::Array(types)) = schema.get("type") {
+        let mut names = Vec::new();
+        for item in types {
+            match item.as_str() {
+                Some("null") => nullable = true,
+                Some(name) => names.push(name.to_string()),
+                None => return Err("\"type\" array must only contain strings".to_string()),
+            }
+        }
+        match names.len() {
+            0 => {
+                schema.remove("type");
+            }
+            1 => {
+                schema.insert("type".to_string(), Value::String(names.remove(0)));
+            }
+            _ => {
+                // 多个非 null 类型等价于 oneOf，每个分支只保留与该类型相关的关键字
+                let variants = names
+                    .iter()
+                    .map(|name| type_variant(schema, name))
+                    .collect::<Vec<_>>();
+                schema.remove("type");
+                schema.insert("oneOf".to_string(), Value::Array(variants));
+            }
+        }
+    }
+
+    let unknown_type = schema
+        .get("type")
+        .and_then(Value::as_str)
+        .is_some_and(|name| !KNOWN_SCHEMA_TYPES.contains(&name));
+    if unknown_type {
+        schema.remove("type");
+    }
+
+    if schema.get("x-nullable").and_then(Value::as_bool) == Some(true) {
+        nullable = true;
+    }
 
-        let r#type = v
-            .get("type")
-            .and_then(|t| t.as_str())
-            .ok_or_else(|| de::Error::missing_field("\"type\" field is missing"))?;
-
-        match r#type {
-            "string" => Ok(SchemaEnum::String(
-                SchemaString::deserialize(v).map_err(de::Error::custom)?,
-            )),
-            "object" => Ok(SchemaEnum::Object(
-                SchemaObject::deserialize(v).map_err(de::Error::custom)?,
-            )),
-            "number" => Ok(SchemaEnum::Number(
-                SchemaNumber::deserialize(v).map_err(de::Error::custom)?,
-            )),
-            "integer" => Ok(SchemaEnum::Integer(
-                SchemaInteger::deserialize(v).map_err(de::Error::custom)?,
-            )),
-            "boolean" => Ok(SchemaEnum::Boolean(
-                SchemaBool::deserialize(v).map_err(de::Error::custom)?,
-            )),
-            "array" => Ok(SchemaEnum::Array(
-                SchemaArray::deserialize(v).map_err(de::Error::custom)?,
-            )),
-            _ => Err(de::Error::missing_field("Unsupported model conversions")),
+    if let Some(Value::Array(items)) = schema.get_mut("enum") {
+        let count = items.len();
+        items.retain(|item| !item.is_null());
+        nullable |= items.len() != count;
+    }
+
+    if let Some(value) = schema.get("const").cloned() {
+        if value.is_null() {
+            nullable = true;
+        } else {
+            if !schema.contains_key("type") {
+                if let Some(name) = json_value_type_name(&value) {
+                    schema.insert("type".to_string(), Value::String(name.to_string()));
+                }
+            }
+            if !schema.contains_key("enum") {
+                schema.insert("enum".to_string(), Value::Array(vec![value]));
+            }
         }
     }
+
+    for (exclusive_key, bound_key) in [
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(bound) = schema.get(exclusive_key).filter(|v| v.is_number()).cloned() {
+            schema.insert(bound_key.to_string(), bound);
+            schema.insert(exclusive_key.to_string(), Value::Bool(true));
+        }
+    }
+
+    if let Some(name) = infer_schema_type(schema).filter(|_| !unknown_type) {
+        schema.insert("type".to_string(), Value::String(name.to_string()));
+    }
+
+    if nullable {
+        schema.insert("nullable".to_string(), Value::Bool(true));
+    }
+    Ok(())
+}
+
+/// 推断缺少 `type` 的 schema 类型，无法推断时返回 `None`（按任意值处理）
+fn infer_schema_type(schema: &serde_json::Map<String, Value>) -> Option<&'static str> {
+    let composed = ["type", "allOf", "oneOf", "anyOf", "not"];
+    if composed.iter().any(|key| schema.contains_key(*key)) {
+        return None;
+    }
+    if schema.contains_key("properties") || schema.contains_key("additionalProperties") {
+        return Some("object");
+    }
+    if schema.contains_key("items") {
+        return Some("array");
+    }
+    let Some(Value::Array(values)) = schema.get("enum") else {
+        return None;
+    };
+    let mut names = values.iter().map(json_value_type_name);
+    let first = names.next()??;
+    names.try_fold(first, |current, name| match (current, name?) {
+        (a, b) if a == b => Some(a),
+        ("integer" | "number", "integer" | "number") => Some("number"),
+        _ => None,
+    })
+}
+
+fn type_variant(schema: &serde_json::Map<String, Value>, name: &str) -> Value {
+    let keys: &[&str] = match name {
+        "string" => &["format", "pattern", "minLength", "maxLength"],
+        "integer" | "number" => &[
+            "format",
+            "minimum",
+            "maximum",
+            "exclusiveMinimum",
+            "exclusiveMaximum",
+            "multipleOf",
+        ],
+        "array" => &["items", "uniqueItems", "minItems", "maxItems"],
+        "object" => &[
+            "properties",
+            "required",
+            "additionalProperties",
+            "minProperties",
+            "maxProperties",
+        ],
+        _ => &[],
+    };
+    let mut variant = serde_json::Map::new();
+    variant.insert("type".to_string(), Value::String(name.to_string()));
+    for key in keys {
+        if let Some(value) = schema.get(*key) {
+            variant.insert(key.to_string(), value.clone());
+        }
+    }
+    Value::Object(variant)
+}
+
+fn json_value_type_name(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::String(_) => Some("string"),
+        Value::Bool(_) => Some("boolean"),
+        Value::Number(number) if number.is_f64() => Some("number"),
+        Value::Number(_) => Some("integer"),
+        Value::Object(_) => Some("object"),
+        _ => None,
+    }
 }
 
 impl SchemaEnum {
@@ -67,6 +375,11 @@ impl SchemaEnum {
             SchemaEnum::Number(v) => v.description.as_ref(),
             SchemaEnum::Boolean(_) => None,
             SchemaEnum::Array(v) => v.description.as_ref(),
+            SchemaEnum::AllOf(v) => v.description.as_ref(),
+            SchemaEnum::OneOf(v) => v.description.as_ref(),
+            SchemaEnum::AnyOf(v) => v.description.as_ref(),
+            SchemaEnum::Not(v) => v.description.as_ref(),
+            SchemaEnum::Any(v) => v.description.as_ref(),
         }
     }
 }
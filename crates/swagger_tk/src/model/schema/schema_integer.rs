@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
-use swagger_macro::schema_base_attributes;
+use serde_json::Number;
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
 
 use super::SchemaTypeEnum;
 
+/// 整数使用 `serde_json::Number` 保存，超出 `i64` 的 `u64` 值也能完整读取
 #[schema_base_attributes]
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SchemaInteger {
     pub r#type: SchemaTypeEnum,
@@ -12,9 +15,9 @@ pub struct SchemaInteger {
 
     pub description: Option<String>,
 
-    pub minimum: Option<i32>,
+    pub minimum: Option<Number>,
 
-    pub maximum: Option<i32>,
+    pub maximum: Option<Number>,
 
     #[serde(rename = "exclusiveMinimum")]
     pub exclusive_minimum: Option<bool>,
@@ -23,7 +26,7 @@ pub struct SchemaInteger {
     pub exclusive_maximum: Option<bool>,
 
     #[serde(rename = "multipleOf")]
-    pub multiple_of: Option<i32>,
+    pub multiple_of: Option<Number>,
 
-    pub r#enum: Option<Vec<i32>>,
+    pub r#enum: Option<Vec<Number>>,
 }
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
+
+use super::SchemaEnum;
+
+/// not 组合，成员不成立
+#[schema_base_attributes]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaNot {
+    pub not: Box<SchemaEnum>,
+
+    pub description: Option<String>,
+}
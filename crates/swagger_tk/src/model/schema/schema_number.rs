@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
-use swagger_macro::schema_base_attributes;
+use serde_json::Number;
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
 
 use super::SchemaTypeEnum;
 
+/// 数值使用 `serde_json::Number` 保存，整数不会转为浮点数，序列化时按原样写回
 #[schema_base_attributes]
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SchemaNumber {
     pub r#type: SchemaTypeEnum,
@@ -12,9 +15,9 @@ pub struct SchemaNumber {
 
     pub description: Option<String>,
 
-    pub minimum: Option<f32>,
+    pub minimum: Option<Number>,
 
-    pub maximum: Option<f32>,
+    pub maximum: Option<Number>,
 
     #[serde(rename = "exclusiveMinimum")]
     pub exclusive_minimum: Option<bool>,
@@ -23,7 +26,7 @@ pub struct SchemaNumber {
     pub exclusive_maximum: Option<bool>,
 
     #[serde(rename = "multipleOf")]
-    pub multiple_of: Option<f32>,
+    pub multiple_of: Option<Number>,
 
-    pub r#enum: Option<Vec<f32>>,
+    pub r#enum: Option<Vec<Number>>,
 }
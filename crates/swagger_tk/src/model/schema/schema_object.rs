@@ -1,11 +1,13 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
-use swagger_macro::schema_base_attributes;
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
 
 use super::{SchemaEnum, SchemaObjectAdditionalProperties, SchemaTypeEnum};
+use crate::model::DiscriminatorObject;
 
 #[schema_base_attributes]
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SchemaObject {
     #[serde(rename = "type")]
@@ -13,13 +15,15 @@ pub struct SchemaObject {
 
     pub required: Option<Vec<String>>,
 
-    pub properties: Option<HashMap<String, SchemaEnum>>,
+    pub properties: Option<BTreeMap<String, SchemaEnum>>,
 
     #[serde(rename = "additionalProperties")]
     pub additional_properties: Option<SchemaObjectAdditionalProperties>,
 
     pub description: Option<String>,
 
+    pub discriminator: Option<DiscriminatorObject>,
+
     #[serde(rename = "minProperties")]
     pub min_properties: Option<u32>,
 
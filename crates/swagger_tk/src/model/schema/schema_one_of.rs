@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use swagger_macro::{schema_base_attributes, skip_serializing_none};
+
+use super::SchemaEnum;
+use crate::model::DiscriminatorObject;
+
+/// oneOf 组合，恰好一个成员成立
+#[schema_base_attributes]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaOneOf {
+    #[serde(rename = "oneOf")]
+    pub one_of: Vec<SchemaEnum>,
+
+    pub description: Option<String>,
+
+    pub discriminator: Option<DiscriminatorObject>,
+}
@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+/// 反序列化时被改写为 3.0 形式的关键字及其原始值（`None` 表示原文档中没有该关键字），
+/// 序列化时写回原始写法，保证 OpenAPI 3.1 文档往返不丢失数据
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaSourceKeywords {
+    pub(crate) keywords: BTreeMap<String, Option<Value>>,
+    /// 原文档中的布尔 schema（`true` / `false`），序列化时整体写回
+    pub(crate) boolean: Option<bool>,
+}
+
+impl SchemaSourceKeywords {
+    /// 对比改写前后的 schema，记录发生变化的顶层关键字
+    pub(crate) fn diff(original: &Value, normalized: &Value) -> Self {
+        if let Value::Bool(value) = original {
+            return Self {
+                boolean: Some(*value),
+                ..Self::default()
+            };
+        }
+        let (Some(original), Some(normalized)) = (original.as_object(), normalized.as_object())
+        else {
+            return Self::default();
+        };
+        let keys = original.keys().chain(normalized.keys());
+        Self {
+            keywords: keys
+                .filter(|key| original.get(*key) != normalized.get(*key))
+                .map(|key| (key.clone(), original.get(key).cloned()))
+                .collect(),
+            boolean: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty() && self.boolean.is_none()
+    }
+
+    /// 原文档中的布尔 schema
+    pub fn boolean(&self) -> Option<bool> {
+        self.boolean
+    }
+
+    /// 将记录的关键字恢复为原始写法
+    pub(crate) fn restore(&self, schema: &mut Map<String, Value>) {
+        for (key, value) in &self.keywords {
+            match value {
+                Some(value) => {
+                    schema.insert(key.clone(), value.clone());
+                }
+                None => {
+                    schema.remove(key);
+                }
+            }
+        }
+    }
+}
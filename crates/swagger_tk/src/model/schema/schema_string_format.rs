@@ -17,7 +17,8 @@ pub enum SchemaStringFormat {
 }
 
 impl SchemaStringFormat {
-    fn as_str(&self) -> &str {
+    /// 规范中的格式名称
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Date => "date",
             Self::DateTime => "date-time",
@@ -0,0 +1,4 @@
+use std::collections::BTreeMap;
+
+/// 安全要求，key 为 securitySchemes 中的名称，value 为需要的 scope（非 oauth2 时为空）
+pub type SecurityRequirementObject = BTreeMap<String, Vec<String>>;
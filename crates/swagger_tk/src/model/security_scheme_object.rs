@@ -1,26 +1,41 @@
 use serde::{Deserialize, Serialize};
-
-use super::{OAuthFlowsObject, SecuritySchemeIn, SecuritySchemeType};
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SecuritySchemaObject {
+use swagger_macro::skip_serializing_none;
+
+use super::{
+    OAuthFlowsObject, SecuritySchemeIn, SecuritySchemeType, SpecExtensions, SpecUnknownFields,
+};
+
+/// 安全方案，除 `type` 外的字段只在对应类型下出现：
+/// - apiKey: `name`、`in`
+/// - http: `scheme`、`bearerFormat`
+/// - oauth2: `flows`
+/// - openIdConnect: `openIdConnectUrl`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SecuritySchemeObject {
     #[serde(rename = "type")]
     pub r#type: SecuritySchemeType,
 
     pub description: Option<String>,
 
-    pub name: String,
+    pub name: Option<String>,
 
     #[serde(rename = "in")]
-    pub r#in: SecuritySchemeIn,
+    pub r#in: Option<SecuritySchemeIn>,
 
-    pub scheme: String,
+    pub scheme: Option<String>,
 
     #[serde(rename = "bearerFormat")]
     pub bearer_format: Option<String>,
 
-    pub flows: OAuthFlowsObject,
+    pub flows: Option<OAuthFlowsObject>,
 
     #[serde(rename = "openIdConnectUrl")]
-    pub open_id_connect_url: String,
+    pub open_id_connect_url: Option<String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
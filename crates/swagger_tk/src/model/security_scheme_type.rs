@@ -1,7 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum SecuritySchemeType {
     #[serde(rename = "apiKey")]
     ApiKey,
@@ -14,3 +13,16 @@ pub enum SecuritySchemeType {
     #[serde(rename = "openIdConnect")]
     OpenIDConnect,
 }
+
+impl SecuritySchemeType {
+    /// 规范中的类型名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecuritySchemeType::ApiKey => "apiKey",
+            SecuritySchemeType::Http => "http",
+            SecuritySchemeType::MutualTLS => "mutualTLS",
+            SecuritySchemeType::OAuth2 => "oauth2",
+            SecuritySchemeType::OpenIDConnect => "openIdConnect",
+        }
+    }
+}
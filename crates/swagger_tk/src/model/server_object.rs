@@ -1,14 +1,22 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::ServerVariableObject;
+use super::{ServerVariableObject, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerObject {
     pub url: String,
 
     pub description: Option<String>,
 
-    pub variables: Option<HashMap<String, ServerVariableObject>>,
+    pub variables: Option<BTreeMap<String, ServerVariableObject>>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
+use super::{SpecExtensions, SpecUnknownFields};
+
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerVariableObject {
     #[serde(rename = "enum")]
@@ -8,4 +12,10 @@ pub struct ServerVariableObject {
     pub default: String,
 
     pub description: Option<String>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
@@ -0,0 +1,21 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// 扩展字段（`x-` 开头的 key），通过 `#[serde(flatten)]` 收集对象上未声明的字段，
+/// 其中不以 `x-` 开头的字段会被忽略
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct SpecExtensions(pub(crate) BTreeMap<String, Value>);
+
+impl<'de> Deserialize<'de> for SpecExtensions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut map = BTreeMap::<String, Value>::deserialize(deserializer)?;
+        map.retain(|key, _| key.starts_with("x-"));
+        Ok(Self(map))
+    }
+}
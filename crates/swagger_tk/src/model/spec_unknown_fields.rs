@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// 模型中没有声明的字段（不包含 `x-` 开头的扩展字段），仅用于序列化时原样写回，保证不丢失数据
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct SpecUnknownFields(pub(crate) BTreeMap<String, Value>);
+
+impl<'de> Deserialize<'de> for SpecUnknownFields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut map = BTreeMap::<String, Value>::deserialize(deserializer)?;
+        map.retain(|key, _| !key.starts_with("x-"));
+        Ok(Self(map))
+    }
+}
+
+impl SpecUnknownFields {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+}
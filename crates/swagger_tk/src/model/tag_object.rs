@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
+use swagger_macro::skip_serializing_none;
 
-use super::ExternalDocumentationObject;
+use super::{ExternalDocumentationObject, SpecExtensions, SpecUnknownFields};
 
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TagObject {
     pub name: String,
@@ -10,4 +12,10 @@ pub struct TagObject {
 
     #[serde(rename = "externalDocs")]
     pub external_docs: Option<ExternalDocumentationObject>,
+
+    #[serde(flatten)]
+    pub extensions: SpecExtensions,
+
+    #[serde(flatten)]
+    pub unknown_fields: SpecUnknownFields,
 }
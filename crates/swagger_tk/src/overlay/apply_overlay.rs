@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{JsonPathSegment, get_json_path_mut, select_json_path};
+use crate::loader::{SpecFormat, SpecParseError, parse_spec_value};
+
+/// 读取 Overlay 文档（JSON 或 YAML）
+pub fn load_overlay(path: &Path) -> Result<Value, SpecParseError> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| SpecParseError::new(format!("failed to read {}: {err}", path.display())))?;
+    let format = SpecFormat::resolve(Some(path), &text);
+    parse_spec_value(&text, format).map_err(|err| err.with_file(path))
+}
+
+/// 按顺序应用 Overlay 1.0 文档中的 actions，返回未匹配到任何节点的 action 提示
+///
+/// - `remove: true` 从父级对象或数组中移除目标节点（优先于 `update`）
+/// - `update` 目标为对象时递归合并对象属性，同名的非对象属性被替换；目标为数组时追加元素；其他值直接替换
+pub fn apply_overlay(spec: &mut Value, overlay: &Value) -> Result<Vec<String>, String> {
+    let version = overlay
+        .get("overlay")
+        .and_then(Value::as_str)
+        .ok_or_else(|| "overlay document must declare an `overlay` version".to_string())?;
+    if !version.starts_with("1.") {
+        return Err(format!("unsupported overlay version `{version}`"));
+    }
+    let actions = overlay
+        .get("actions")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "overlay document must declare `actions`".to_string())?;
+
+    let mut warnings = Vec::new();
+    for (index, action) in actions.iter().enumerate() {
+        let target = action
+            .get("target")
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("overlay action {index} has no `target`"))?;
+        let matched = apply_action(spec, target, action)
+            .map_err(|err| format!("overlay action {index}: {err}"))?;
+        if matched == 0 {
+            warnings.push(format!(
+                "overlay action {index} (`{target}`) matched no nodes"
+            ));
+        }
+    }
+    Ok(warnings)
+}
+
+fn apply_action(spec: &mut Value, target: &str, action: &Value) -> Result<usize, String> {
+    let mut locations = select_json_path(spec, target)?;
+    let matched = locations.len();
+
+    if action.get("remove").and_then(Value::as_bool) == Some(true) {
+        // 从后往前删除，避免同一数组中靠前的删除影响后面元素的下标
+        locations.sort();
+        for location in locations.iter().rev() {
+            remove_node(spec, location)?;
+        }
+        return Ok(matched);
+    }
+
+    if let Some(update) = action.get("update") {
+        for location in &locations {
+            if let Some(node) = get_json_path_mut(spec, location) {
+                merge_update(node, update);
+            }
+        }
+    }
+    Ok(matched)
+}
+
+fn remove_node(spec: &mut Value, location: &[JsonPathSegment]) -> Result<(), String> {
+    let Some((last, parent)) = location.split_last() else {
+        return Err("the document root can't be removed".to_string());
+    };
+    match (get_json_path_mut(spec, parent), last) {
+        (Some(Value::Object(map)), JsonPathSegment::Key(key)) => {
+            map.remove(key);
+        }
+        (Some(Value::Array(list)), JsonPathSegment::Index(index)) if *index < list.len() => {
+            list.remove(*index);
+        }
+        // 祖先节点已被同一 action 删除
+        _ => {}
+    }
+    Ok(())
+}
+
+fn merge_update(target: &mut Value, update: &Value) {
+    match (target, update) {
+        (Value::Object(target), Value::Object(update)) => {
+            for (key, value) in update {
+                match target.get_mut(key) {
+                    Some(existing) if existing.is_object() && value.is_object() => {
+                        merge_update(existing, value);
+                    }
+                    _ => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(update)) => target.extend(update.iter().cloned()),
+        (Value::Array(target), update) => target.push(update.clone()),
+        (target, update) => *target = update.clone(),
+    }
+}
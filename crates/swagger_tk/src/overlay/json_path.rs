@@ -0,0 +1,565 @@
+use serde_json::Value;
+
+/// JSONPath 匹配到的节点在文档中的位置
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum JsonPathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// 按 JSONPath（RFC 9535 的常用子集）查找节点，返回匹配节点的位置，按文档顺序排列且不重复
+///
+/// 支持 `$`、`.name`、`['name']`、`.*` / `[*]`、`..`（递归下降）、`[0]` / `[-1]`、`[0:2]`、
+/// 多选 `['a','b']`，以及过滤表达式 `[?(@.name == 'id')]`（`== != < <= > >=`、`&& || !`、存在性判断）
+pub fn select_json_path(root: &Value, path: &str) -> Result<Vec<Vec<JsonPathSegment>>, String> {
+    let steps = PathParser::new(path).parse_path()?;
+    let mut nodes = vec![(Vec::new(), root)];
+    for step in &steps {
+        let mut next = Vec::new();
+        for (location, value) in nodes {
+            let candidates = if step.descendant {
+                collect_descendants(location, value)
+            } else {
+                vec![(location, value)]
+            };
+            for (location, value) in candidates {
+                for selector in &step.selectors {
+                    select_children(&location, value, selector, &mut next);
+                }
+            }
+        }
+        nodes = next;
+    }
+
+    let mut result = Vec::<Vec<JsonPathSegment>>::new();
+    for (location, _) in nodes {
+        if !result.contains(&location) {
+            result.push(location);
+        }
+    }
+    Ok(result)
+}
+
+/// 根据位置获取可变节点
+pub fn get_json_path_mut<'a>(
+    root: &'a mut Value,
+    location: &[JsonPathSegment],
+) -> Option<&'a mut Value> {
+    location
+        .iter()
+        .try_fold(root, |current, segment| match segment {
+            JsonPathSegment::Key(key) => current.as_object_mut()?.get_mut(key),
+            JsonPathSegment::Index(index) => current.as_array_mut()?.get_mut(*index),
+        })
+}
+
+struct Step {
+    descendant: bool,
+    selectors: Vec<Selector>,
+}
+
+enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Slice(Option<i64>, Option<i64>),
+    Filter(FilterExpr),
+}
+
+enum FilterExpr {
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Not(Box<FilterExpr>),
+    Exists(Vec<JsonPathSegment>),
+    Compare(Operand, CompareOp, Operand),
+}
+
+enum Operand {
+    Path(Vec<JsonPathSegment>),
+    Literal(Value),
+}
+
+#[derive(Clone, Copy)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+fn collect_descendants(
+    location: Vec<JsonPathSegment>,
+    value: &Value,
+) -> Vec<(Vec<JsonPathSegment>, &Value)> {
+    let mut result = Vec::new();
+    let mut stack = vec![(location, value)];
+    while let Some((location, value)) = stack.pop() {
+        let children = child_entries(&location, value);
+        result.push((location, value));
+        stack.extend(children.into_iter().rev());
+    }
+    result
+}
+
+fn child_entries<'a>(
+    location: &[JsonPathSegment],
+    value: &'a Value,
+) -> Vec<(Vec<JsonPathSegment>, &'a Value)> {
+    let child = |segment: JsonPathSegment| {
+        let mut location = location.to_vec();
+        location.push(segment);
+        location
+    };
+    match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, item)| (child(JsonPathSegment::Key(key.clone())), item))
+            .collect(),
+        Value::Array(list) => list
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (child(JsonPathSegment::Index(index)), item))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn select_children<'a>(
+    location: &[JsonPathSegment],
+    value: &'a Value,
+    selector: &Selector,
+    result: &mut Vec<(Vec<JsonPathSegment>, &'a Value)>,
+) {
+    let mut push = |segment: JsonPathSegment, item: &'a Value| {
+        let mut location = location.to_vec();
+        location.push(segment);
+        result.push((location, item));
+    };
+    match (selector, value) {
+        (Selector::Name(name), Value::Object(map)) => {
+            if let Some(item) = map.get(name) {
+                push(JsonPathSegment::Key(name.clone()), item);
+            }
+        }
+        (Selector::Wildcard, _) => {
+            for (location, item) in child_entries(location, value) {
+                result.push((location, item));
+            }
+        }
+        (Selector::Index(index), Value::Array(list)) => {
+            if let Some(index) = normalize_index(*index, list.len()) {
+                push(JsonPathSegment::Index(index), &list[index]);
+            }
+        }
+        (Selector::Slice(start, end), Value::Array(list)) => {
+            let len = list.len() as i64;
+            let clamp = |bound: i64| {
+                if bound < 0 {
+                    (len + bound).max(0)
+                } else {
+                    bound.min(len)
+                }
+            };
+            let start = start.map_or(0, clamp);
+            let end = end.map_or(len, clamp);
+            for index in start..end {
+                push(
+                    JsonPathSegment::Index(index as usize),
+                    &list[index as usize],
+                );
+            }
+        }
+        (Selector::Filter(expr), _) => {
+            for (location, item) in child_entries(location, value) {
+                if expr.matches(item) {
+                    result.push((location, item));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { len as i64 + index } else { index };
+    (0..len as i64).contains(&index).then_some(index as usize)
+}
+
+impl FilterExpr {
+    fn matches(&self, current: &Value) -> bool {
+        match self {
+            FilterExpr::Or(left, right) => left.matches(current) || right.matches(current),
+            FilterExpr::And(left, right) => left.matches(current) && right.matches(current),
+            FilterExpr::Not(expr) => !expr.matches(current),
+            FilterExpr::Exists(path) => resolve_relative(current, path).is_some(),
+            FilterExpr::Compare(left, op, right) => {
+                compare(left.resolve(current), *op, right.resolve(current))
+            }
+        }
+    }
+}
+
+impl Operand {
+    fn resolve<'a>(&'a self, current: &'a Value) -> Option<&'a Value> {
+        match self {
+            Operand::Path(path) => resolve_relative(current, path),
+            Operand::Literal(value) => Some(value),
+        }
+    }
+}
+
+fn resolve_relative<'a>(current: &'a Value, path: &[JsonPathSegment]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(current, |value, segment| match segment {
+            JsonPathSegment::Key(key) => value.get(key),
+            JsonPathSegment::Index(index) => value.get(index),
+        })
+}
+
+fn compare(left: Option<&Value>, op: CompareOp, right: Option<&Value>) -> bool {
+    let ordering = match (left, right) {
+        (Some(Value::Number(a)), Some(Value::Number(b))) => a
+            .as_f64()
+            .zip(b.as_f64())
+            .and_then(|(a, b)| a.partial_cmp(&b)),
+        (Some(Value::String(a)), Some(Value::String(b))) => Some(a.cmp(b)),
+        _ => None,
+    };
+    match op {
+        CompareOp::Eq => ordering.map_or(left == right, |v| v.is_eq()),
+        CompareOp::Ne => !ordering.map_or(left == right, |v| v.is_eq()),
+        CompareOp::Lt => ordering.is_some_and(|v| v.is_lt()),
+        CompareOp::Le => ordering.is_some_and(|v| v.is_le()),
+        CompareOp::Gt => ordering.is_some_and(|v| v.is_gt()),
+        CompareOp::Ge => ordering.is_some_and(|v| v.is_ge()),
+    }
+}
+
+struct PathParser<'a> {
+    source: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            chars: source.trim().chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!(
+            "invalid JSONPath `{}` at position {}: {message}",
+            self.source, self.pos
+        )
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(offset, ch)| self.chars.get(self.pos + offset) == Some(&ch))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() == Some(ch) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{ch}`")))
+        }
+    }
+
+    fn parse_path(&mut self) -> Result<Vec<Step>, String> {
+        if self.peek() != Some('$') {
+            return Err(self.error("path must start with `$`"));
+        }
+        self.pos += 1;
+
+        let mut steps = Vec::new();
+        while let Some(ch) = self.peek() {
+            let step = match ch {
+                '.' if self.starts_with("..") => {
+                    self.pos += 2;
+                    let selectors = if self.peek() == Some('[') {
+                        self.parse_bracket()?
+                    } else {
+                        vec![self.parse_dot_member()?]
+                    };
+                    Step {
+                        descendant: true,
+                        selectors,
+                    }
+                }
+                '.' => {
+                    self.pos += 1;
+                    Step {
+                        descendant: false,
+                        selectors: vec![self.parse_dot_member()?],
+                    }
+                }
+                '[' => Step {
+                    descendant: false,
+                    selectors: self.parse_bracket()?,
+                },
+                _ => return Err(self.error("expected `.` or `[`")),
+            };
+            steps.push(step);
+        }
+        Ok(steps)
+    }
+
+    fn parse_dot_member(&mut self) -> Result<Selector, String> {
+        if self.peek() == Some('*') {
+            self.pos += 1;
+            return Ok(Selector::Wildcard);
+        }
+        Ok(Selector::Name(self.parse_name()?))
+    }
+
+    fn parse_name(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|ch| {
+            ch.is_alphanumeric() || matches!(ch, '_' | '-' | '$') || !ch.is_ascii()
+        }) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(self.error("expected member name"));
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn parse_bracket(&mut self) -> Result<Vec<Selector>, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        if self.peek() == Some('?') {
+            self.pos += 1;
+            let expr = self.parse_or()?;
+            self.expect(']')?;
+            return Ok(vec![Selector::Filter(expr)]);
+        }
+
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let selector = match self.peek() {
+                Some('\'' | '"') => Selector::Name(self.parse_string()?),
+                Some('*') => {
+                    self.pos += 1;
+                    Selector::Wildcard
+                }
+                _ => self.parse_index_or_slice()?,
+            };
+            selectors.push(selector);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(selectors);
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn parse_index_or_slice(&mut self) -> Result<Selector, String> {
+        let start = self.parse_optional_integer()?;
+        self.skip_whitespace();
+        if self.peek() != Some(':') {
+            return start
+                .map(Selector::Index)
+                .ok_or_else(|| self.error("expected selector"));
+        }
+        self.pos += 1;
+        let end = self.parse_optional_integer()?;
+        Ok(Selector::Slice(start, end))
+    }
+
+    fn parse_optional_integer(&mut self) -> Result<Option<i64>, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let text = self.chars[start..self.pos].iter().collect::<String>();
+        text.parse()
+            .map(Some)
+            .map_err(|_| self.error(&format!("invalid index `{text}`")))
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        let quote = self.peek().ok_or_else(|| self.error("expected string"))?;
+        self.pos += 1;
+        let mut result = String::new();
+        while let Some(ch) = self.peek() {
+            self.pos += 1;
+            match ch {
+                '\\' => {
+                    let escaped = self
+                        .peek()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.pos += 1;
+                    result.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        other => other,
+                    });
+                }
+                ch if ch == quote => return Ok(result),
+                ch => result.push(ch),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpr, String> {
+        let mut expr = self.parse_and()?;
+        loop {
+            self.skip_whitespace();
+            if !self.starts_with("||") {
+                return Ok(expr);
+            }
+            self.pos += 2;
+            expr = FilterExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpr, String> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            self.skip_whitespace();
+            if !self.starts_with("&&") {
+                return Ok(expr);
+            }
+            self.pos += 2;
+            expr = FilterExpr::And(Box::new(expr), Box::new(self.parse_unary()?));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterExpr, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('!') if !self.starts_with("!=") => {
+                self.pos += 1;
+                Ok(FilterExpr::Not(Box::new(self.parse_unary()?)))
+            }
+            Some('(') => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                self.expect(')')?;
+                Ok(expr)
+            }
+            _ => self.parse_comparison(),
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<FilterExpr, String> {
+        let left = self.parse_operand()?;
+        self.skip_whitespace();
+        let op = [
+            ("==", CompareOp::Eq),
+            ("!=", CompareOp::Ne),
+            ("<=", CompareOp::Le),
+            (">=", CompareOp::Ge),
+            ("<", CompareOp::Lt),
+            (">", CompareOp::Gt),
+        ]
+        .into_iter()
+        .find(|(text, _)| self.starts_with(text));
+        match (op, left) {
+            (Some((text, op)), left) => {
+                self.pos += text.len();
+                let right = self.parse_operand()?;
+                Ok(FilterExpr::Compare(left, op, right))
+            }
+            (None, Operand::Path(path)) => Ok(FilterExpr::Exists(path)),
+            (None, Operand::Literal(_)) => Err(self.error("expected comparison operator")),
+        }
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('@') => {
+                self.pos += 1;
+                Ok(Operand::Path(self.parse_relative_path()?))
+            }
+            Some('\'' | '"') => Ok(Operand::Literal(Value::String(self.parse_string()?))),
+            Some(ch) if ch == '-' || ch.is_ascii_digit() => {
+                let start = self.pos;
+                self.pos += 1;
+                while self.peek().is_some_and(|ch| {
+                    ch.is_ascii_digit() || matches!(ch, '.' | 'e' | 'E' | '+' | '-')
+                }) {
+                    self.pos += 1;
+                }
+                let text = self.chars[start..self.pos].iter().collect::<String>();
+                serde_json::from_str::<serde_json::Number>(&text)
+                    .map(|number| Operand::Literal(Value::Number(number)))
+                    .map_err(|_| self.error(&format!("invalid number `{text}`")))
+            }
+            _ => {
+                for (text, value) in [
+                    ("true", Value::Bool(true)),
+                    ("false", Value::Bool(false)),
+                    ("null", Value::Null),
+                ] {
+                    if self.starts_with(text) {
+                        self.pos += text.len();
+                        return Ok(Operand::Literal(value));
+                    }
+                }
+                Err(self.error("expected `@` path or literal"))
+            }
+        }
+    }
+
+    fn parse_relative_path(&mut self) -> Result<Vec<JsonPathSegment>, String> {
+        let mut path = Vec::new();
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.pos += 1;
+                    path.push(JsonPathSegment::Key(self.parse_name()?));
+                }
+                Some('[') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    let segment = match self.peek() {
+                        Some('\'' | '"') => JsonPathSegment::Key(self.parse_string()?),
+                        _ => match self.parse_optional_integer()? {
+                            Some(index) if index >= 0 => JsonPathSegment::Index(index as usize),
+                            _ => return Err(self.error("expected name or non-negative index")),
+                        },
+                    };
+                    self.expect(']')?;
+                    path.push(segment);
+                }
+                _ => return Ok(path),
+            }
+        }
+    }
+}
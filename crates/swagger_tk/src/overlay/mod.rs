@@ -0,0 +1,5 @@
+mod apply_overlay;
+mod json_path;
+
+pub use apply_overlay::*;
+pub use json_path::*;
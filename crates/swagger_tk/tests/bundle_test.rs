@@ -0,0 +1,158 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use swagger_tk::getter::get_schema_by_name;
+use swagger_tk::loader::{bundle_spec, load_open_api};
+use swagger_tk::model::SchemaEnum;
+
+const ROOT_YAML: &str = r###"
+openapi: 3.0.1
+info:
+  title: multi-file
+  version: 1.0.0
+paths:
+  /users:
+    $ref: ./paths/users.yaml
+components:
+  schemas:
+    Error:
+      type: object
+      properties:
+        message:
+          type: string
+"###;
+
+const USERS_PATH_YAML: &str = r###"
+get:
+  responses:
+    200:
+      description: Success
+      content:
+        application/json:
+          schema:
+            type: array
+            items:
+              $ref: ../schemas/User.json#/User
+    default:
+      description: Failure
+      content:
+        application/json:
+          schema:
+            $ref: ../openapi.yaml#/components/schemas/Error
+"###;
+
+const USER_JSON: &str = r###"
+{
+  "User": {
+    "type": "object",
+    "properties": {
+      "id": { "type": "integer" },
+      "manager": { "$ref": "#/User" },
+      "address": { "$ref": "./Address.json" }
+    }
+  }
+}
+"###;
+
+const ADDRESS_JSON: &str = r###"
+{ "type": "object", "properties": { "city": { "type": "string" } } }
+"###;
+
+fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("swagger_tk_{name}_{}", std::process::id()));
+    for (path, content) in files {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn write_multi_file_spec() -> PathBuf {
+    write_files(
+        "bundle",
+        &[
+            ("openapi.yaml", ROOT_YAML),
+            ("paths/users.yaml", USERS_PATH_YAML),
+            ("schemas/User.json", USER_JSON),
+            ("schemas/Address.json", ADDRESS_JSON),
+        ],
+    )
+}
+
+#[test]
+fn bundle_spec_hoists_external_schemas_test() {
+    let dir = write_multi_file_spec();
+    let bundled = bundle_spec(&dir.join("openapi.yaml")).expect("bundle spec fail");
+
+    let responses = &bundled["paths"]["/users"]["get"]["responses"];
+    assert_eq!(
+        responses["200"]["content"]["application/json"]["schema"]["items"]["$ref"],
+        "#/components/schemas/User"
+    );
+    assert_eq!(
+        responses["default"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/Error"
+    );
+
+    let user = &bundled["components"]["schemas"]["User"];
+    assert_eq!(
+        user["properties"]["manager"]["$ref"],
+        "#/components/schemas/User"
+    );
+    assert_eq!(
+        user["properties"]["address"]["$ref"],
+        "#/components/schemas/Address"
+    );
+    assert_eq!(
+        bundled["components"]["schemas"]["Address"]["properties"]["city"]["type"],
+        "string"
+    );
+}
+
+#[test]
+fn load_open_api_bundles_external_refs_test() {
+    let dir = write_multi_file_spec();
+    let open_api = load_open_api(&dir.join("openapi.yaml")).expect("load open api fail");
+    assert!(open_api.paths.as_ref().unwrap()["/users"].get.is_some());
+    assert!(matches!(
+        get_schema_by_name(&open_api, "User"),
+        Some(SchemaEnum::Object(_))
+    ));
+}
+
+#[test]
+fn bundle_spec_reports_circular_inline_refs_test() {
+    let dir = write_files(
+        "circular",
+        &[
+            (
+                "openapi.json",
+                r#"{ "openapi": "3.0.1", "paths": { "/a": { "$ref": "./a.json" } } }"#,
+            ),
+            ("a.json", r#"{ "$ref": "./b.json" }"#),
+            ("b.json", r#"{ "$ref": "./a.json" }"#),
+        ],
+    );
+    let err = bundle_spec(&dir.join("openapi.json")).expect_err("circular ref should fail");
+    assert!(err.message.starts_with("circular $ref"));
+    assert!(err.message.contains(&display_name(&dir, "a.json")));
+}
+
+#[test]
+fn bundle_spec_reports_missing_file_test() {
+    let dir = write_files(
+        "missing",
+        &[(
+            "openapi.json",
+            r#"{ "openapi": "3.0.1", "paths": { "/a": { "$ref": "./missing.json" } } }"#,
+        )],
+    );
+    let err = bundle_spec(&dir.join("openapi.json")).expect_err("missing file should fail");
+    assert!(err.message.contains("missing.json"));
+    assert!(err.file.is_some());
+}
+
+fn display_name(dir: &Path, name: &str) -> String {
+    dir.join(name).canonicalize().unwrap().display().to_string()
+}
@@ -0,0 +1,172 @@
+use serde_json::Value;
+use swagger_tk::converter::{is_swagger2, swagger2_to_open_api, upgrade_swagger2};
+use swagger_tk::getter::get_schema_by_name;
+use swagger_tk::loader::parse_open_api_auto;
+use swagger_tk::model::{
+    OperationObjectRequestBody, ParameterObjectIn, PathItemParameters, SchemaEnum,
+};
+
+/// Mock Swagger 2.0 spec emitted by an ASP.NET service
+const MOCK_SWAGGER2: &str = r###"
+{
+  "swagger": "2.0",
+  "info": { "title": "legacy-api", "version": "v1" },
+  "host": "api.example.com",
+  "basePath": "/legacy",
+  "schemes": ["https"],
+  "consumes": ["application/json"],
+  "produces": ["application/json"],
+  "paths": {
+    "/orders": {
+      "get": {
+        "tags": ["Order"],
+        "operationId": "Order_List",
+        "parameters": [
+          { "name": "ids", "in": "query", "type": "array", "items": { "type": "integer" }, "collectionFormat": "multi" },
+          { "name": "X-Tenant", "in": "header", "type": "string", "required": true }
+        ],
+        "responses": {
+          "200": {
+            "description": "Success",
+            "schema": { "type": "array", "items": { "$ref": "#/definitions/Order" } }
+          }
+        }
+      },
+      "post": {
+        "tags": ["Order"],
+        "operationId": "Order_Create",
+        "parameters": [
+          { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Order" } }
+        ],
+        "responses": { "200": { "$ref": "#/responses/OrderResponse" } }
+      }
+    },
+    "/orders/{id}/attachment": {
+      "parameters": [
+        { "name": "id", "in": "path", "type": "integer", "format": "int64" }
+      ],
+      "post": {
+        "operationId": "Order_Upload",
+        "consumes": ["multipart/form-data"],
+        "parameters": [
+          { "name": "file", "in": "formData", "type": "file", "required": true },
+          { "name": "remark", "in": "formData", "type": "string" }
+        ],
+        "responses": { "204": { "description": "No Content" } }
+      }
+    }
+  },
+  "definitions": {
+    "Order": {
+      "type": "object",
+      "properties": {
+        "id": { "type": "integer", "format": "int64" },
+        "remark": { "type": "string", "x-nullable": true }
+      }
+    }
+  },
+  "responses": {
+    "OrderResponse": { "description": "Order", "schema": { "$ref": "#/definitions/Order" } }
+  },
+  "securityDefinitions": {
+    "Bearer": { "type": "apiKey", "name": "Authorization", "in": "header" }
+  }
+}
+"###;
+
+fn get_mock_document() -> Value {
+    serde_json::from_str(MOCK_SWAGGER2).expect("parse mock swagger fail")
+}
+
+#[test]
+fn upgrade_document_structure_test() {
+    let document = get_mock_document();
+    assert!(is_swagger2(&document));
+
+    let upgraded = upgrade_swagger2(&document).expect("upgrade swagger fail");
+    assert_eq!(upgraded["openapi"], "3.0.3");
+    assert_eq!(
+        upgraded["servers"][0]["url"],
+        "https://api.example.com/legacy"
+    );
+    assert_eq!(
+        upgraded["paths"]["/orders"]["get"]["responses"]["200"]["content"]["application/json"]["schema"]
+            ["items"]["$ref"],
+        "#/components/schemas/Order"
+    );
+    assert_eq!(
+        upgraded["paths"]["/orders"]["post"]["responses"]["200"]["$ref"],
+        "#/components/responses/OrderResponse"
+    );
+    assert_eq!(
+        upgraded["components"]["schemas"]["Order"]["properties"]["remark"]["nullable"],
+        true
+    );
+    assert_eq!(
+        upgraded["components"]["securitySchemes"]["Bearer"]["type"],
+        "apiKey"
+    );
+}
+
+#[test]
+fn upgrade_parameters_test() {
+    let open_api = swagger2_to_open_api(&get_mock_document()).expect("upgrade swagger fail");
+    let paths = open_api.paths.as_ref().unwrap();
+    let list = paths["/orders"].get.as_ref().unwrap();
+    let parameters = list.parameters.as_ref().unwrap();
+    assert_eq!(parameters.len(), 2);
+
+    let upgraded = upgrade_swagger2(&get_mock_document()).unwrap();
+    let query = &upgraded["paths"]["/orders"]["get"]["parameters"][0];
+    assert_eq!(query["style"], "form");
+    assert_eq!(query["explode"], true);
+    assert_eq!(query["schema"]["items"]["type"], "integer");
+
+    let create = paths["/orders"].post.as_ref().unwrap();
+    let Some(OperationObjectRequestBody::RequestBody(body)) = &create.request_body else {
+        panic!("body parameter should become requestBody");
+    };
+    assert_eq!(body.required, Some(true));
+    assert_eq!(
+        body.content["application/json"].get_ref_schema_name(),
+        Some("Order")
+    );
+
+    let path_id = &upgraded["paths"]["/orders/{id}/attachment"]["parameters"][0];
+    assert_eq!(path_id["required"], true);
+    assert_eq!(path_id["schema"]["format"], "int64");
+}
+
+#[test]
+fn upgrade_form_data_test() {
+    let upgraded = upgrade_swagger2(&get_mock_document()).unwrap();
+    let schema = &upgraded["paths"]["/orders/{id}/attachment"]["post"]["requestBody"]["content"]["multipart/form-data"]
+        ["schema"];
+    assert_eq!(schema["properties"]["file"]["type"], "string");
+    assert_eq!(schema["properties"]["file"]["format"], "binary");
+    assert_eq!(schema["required"][0], "file");
+}
+
+#[test]
+fn loader_upgrades_swagger2_test() {
+    let open_api = parse_open_api_auto(MOCK_SWAGGER2).expect("load swagger fail");
+    assert!(matches!(
+        get_schema_by_name(&open_api, "Order"),
+        Some(SchemaEnum::Object(_))
+    ));
+    let parameters = open_api.paths.as_ref().unwrap()["/orders/{id}/attachment"]
+        .parameters
+        .as_ref()
+        .expect("path level parameters should be kept");
+    assert!(matches!(
+        &parameters[0],
+        PathItemParameters::Parameter(parameter) if matches!(parameter.r#in, ParameterObjectIn::Path)
+    ));
+}
+
+#[test]
+fn reject_openapi3_test() {
+    let document = serde_json::json!({ "openapi": "3.0.0" });
+    assert!(!is_swagger2(&document));
+    assert!(upgrade_swagger2(&document).is_err());
+}
@@ -0,0 +1,288 @@
+use std::str::FromStr;
+use swagger_tk::diff::{ChangeSeverity, SpecChange, diff_open_api, has_breaking_changes};
+use swagger_tk::model::OpenAPIObject;
+
+const OLD_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users": {
+      "get": {
+        "operationId": "listUsers",
+        "parameters": [
+          { "name": "page", "in": "query", "schema": { "type": "integer" } },
+          { "name": "keyword", "in": "query", "schema": { "type": "string" } }
+        ],
+        "responses": {
+          "200": { "description": "Success", "content": { "application/json": {
+            "schema": { "type": "array", "items": { "$ref": "#/components/schemas/User" } }
+          } } }
+        }
+      },
+      "post": {
+        "operationId": "createUser",
+        "requestBody": { "content": { "application/json": {
+          "schema": { "$ref": "#/components/schemas/CreateUser" }
+        } } },
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "/users/{id}": {
+      "delete": {
+        "operationId": "deleteUser",
+        "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "/users/export": {
+      "get": { "operationId": "exportUsers", "responses": { "200": { "description": "Success" } } }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Status": { "type": "string", "enum": ["active", "disabled", "deleted"] },
+      "User": {
+        "type": "object",
+        "required": ["id", "name"],
+        "properties": {
+          "id": { "type": "integer" },
+          "name": { "type": "string" },
+          "status": { "$ref": "#/components/schemas/Status" }
+        }
+      },
+      "CreateUser": {
+        "type": "object",
+        "properties": {
+          "name": { "type": "string" },
+          "score": { "type": "number" }
+        }
+      },
+      "Legacy": { "type": "object" }
+    }
+  }
+}
+"###;
+
+const NEW_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "2.0.0" },
+  "paths": {
+    "/users": {
+      "get": {
+        "operationId": "listUsers",
+        "parameters": [
+          { "name": "page", "in": "query", "required": true, "schema": { "type": "integer" } },
+          { "name": "size", "in": "query", "schema": { "type": "integer" } }
+        ],
+        "responses": {
+          "200": { "description": "Success", "content": { "application/json": {
+            "schema": { "type": "array", "items": { "$ref": "#/components/schemas/User" } }
+          } } }
+        }
+      },
+      "post": {
+        "operationId": "createUser",
+        "requestBody": { "content": { "application/json": {
+          "schema": { "$ref": "#/components/schemas/CreateUser" }
+        } } },
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "/users/{id}": {
+      "post": {
+        "operationId": "deleteUser",
+        "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "/users/import": {
+      "post": { "operationId": "importUsers", "responses": { "200": { "description": "Success" } } }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Status": { "type": "string", "enum": ["active", "disabled", "locked"] },
+      "User": {
+        "type": "object",
+        "required": ["id"],
+        "properties": {
+          "id": { "type": "number" },
+          "name": { "type": "string" },
+          "status": { "$ref": "#/components/schemas/Status" }
+        }
+      },
+      "CreateUser": {
+        "type": "object",
+        "required": ["email"],
+        "properties": {
+          "name": { "type": "string", "maxLength": 20 },
+          "score": { "type": "integer" },
+          "email": { "type": "string" }
+        }
+      }
+    }
+  }
+}
+"###;
+
+fn find<'a>(changes: &'a [SpecChange], rule: &str, pointer: &str) -> &'a SpecChange {
+    changes
+        .iter()
+        .find(|item| item.rule == rule && item.pointer == pointer)
+        .unwrap_or_else(|| panic!("missing {rule} at {pointer}: {changes:#?}"))
+}
+
+#[test]
+fn diff_open_api_classifies_changes_test() {
+    let old = OpenAPIObject::from_str(OLD_OPENAPI).expect("parse openapi fail");
+    let new = OpenAPIObject::from_str(NEW_OPENAPI).expect("parse openapi fail");
+    let changes = diff_open_api(&old, &new);
+    assert!(has_breaking_changes(&changes));
+
+    let breaking = |rule: &str, pointer: &str| {
+        assert_eq!(
+            find(&changes, rule, pointer).severity,
+            ChangeSeverity::Breaking,
+            "{rule} at {pointer}"
+        );
+    };
+    let non_breaking = |rule: &str, pointer: &str| {
+        assert_eq!(
+            find(&changes, rule, pointer).severity,
+            ChangeSeverity::NonBreaking,
+            "{rule} at {pointer}"
+        );
+    };
+
+    breaking("operation-removed", "#/paths/~1users~1export/get");
+    breaking("operation-moved", "#/paths/~1users~1{id}/post");
+    non_breaking("operation-added", "#/paths/~1users~1import/post");
+    breaking(
+        "parameter-became-required",
+        "#/paths/~1users/get/parameters/0",
+    );
+    breaking("parameter-removed", "#/paths/~1users/get/parameters/1");
+    non_breaking("parameter-added", "#/paths/~1users/get/parameters/1");
+    breaking("schema-removed", "#/components/schemas/Legacy");
+
+    // 响应中的类型放宽、枚举成员删除 / 新增、属性变为可选
+    breaking("type-widened", "#/components/schemas/User/properties/id");
+    breaking("enum-member-removed", "#/components/schemas/Status");
+    breaking("enum-member-added", "#/components/schemas/Status");
+    breaking(
+        "property-became-optional",
+        "#/components/schemas/User/properties/name",
+    );
+
+    // 请求中的类型收窄、新增必填属性
+    breaking(
+        "type-narrowed",
+        "#/components/schemas/CreateUser/properties/name",
+    );
+    breaking(
+        "type-narrowed",
+        "#/components/schemas/CreateUser/properties/score",
+    );
+    breaking(
+        "property-added",
+        "#/components/schemas/CreateUser/properties/email",
+    );
+
+    // 没有变化的接口不产生记录
+    assert!(
+        changes
+            .iter()
+            .all(|item| !item.pointer.starts_with("#/paths/~1users/post"))
+    );
+}
+
+#[test]
+fn diff_open_api_same_spec_has_no_changes_test() {
+    let open_api = OpenAPIObject::from_str(OLD_OPENAPI).expect("parse openapi fail");
+    let changes = diff_open_api(&open_api, &open_api);
+    assert!(changes.is_empty(), "{changes:#?}");
+    assert!(!has_breaking_changes(&changes));
+}
+
+fn union_spec(members: &str, levels: &str) -> String {
+    format!(
+        r###"
+{{
+  "openapi": "3.0.1",
+  "info": {{ "title": "mock-api", "version": "1.0.0" }},
+  "paths": {{
+    "/pets": {{
+      "post": {{
+        "operationId": "createPet",
+        "requestBody": {{ "content": {{ "application/json": {{
+          "schema": {{ "$ref": "#/components/schemas/PetInput" }}
+        }} }} }},
+        "responses": {{ "200": {{ "description": "Success", "content": {{ "application/json": {{
+          "schema": {{ "$ref": "#/components/schemas/PetOutput" }}
+        }} }} }} }}
+      }}
+    }}
+  }},
+  "components": {{
+    "schemas": {{
+      "Cat": {{ "type": "object", "properties": {{ "meow": {{ "type": "boolean" }} }} }},
+      "Dog": {{ "type": "object", "properties": {{ "bark": {{ "type": "boolean" }} }} }},
+      "Bird": {{ "type": "object", "properties": {{ "tweet": {{ "type": "boolean" }} }} }},
+      "PetInput": {{ "type": "object", "properties": {{
+        "pet": {{ "oneOf": [{members}] }},
+        "level": {{ "type": "string", "enum": [{levels}] }}
+      }} }},
+      "PetOutput": {{ "type": "object", "properties": {{
+        "pet": {{ "oneOf": [{members}] }},
+        "level": {{ "type": "string", "enum": [{levels}] }}
+      }} }}
+    }}
+  }}
+}}
+"###
+    )
+}
+
+#[test]
+fn diff_open_api_union_and_enum_additions_depend_on_direction_test() {
+    let two = r##"{ "$ref": "#/components/schemas/Cat" }, { "$ref": "#/components/schemas/Dog" }"##;
+    let three = format!(r##"{two}, {{ "$ref": "#/components/schemas/Bird" }}"##);
+    let old = OpenAPIObject::from_str(&union_spec(two, r#""low""#)).expect("parse openapi fail");
+    let new = OpenAPIObject::from_str(&union_spec(&three, r#""low", "high""#))
+        .expect("parse openapi fail");
+
+    // 新增成员：请求中兼容，响应中 breaking
+    let changes = diff_open_api(&old, &new);
+    let severity =
+        |changes: &[SpecChange], rule: &str, pointer: &str| find(changes, rule, pointer).severity;
+    for (schema, expected) in [
+        ("PetInput", ChangeSeverity::NonBreaking),
+        ("PetOutput", ChangeSeverity::Breaking),
+    ] {
+        let pointer = format!("#/components/schemas/{schema}/properties/pet");
+        assert_eq!(severity(&changes, "type-widened", &pointer), expected);
+        let pointer = format!("#/components/schemas/{schema}/properties/level");
+        assert_eq!(severity(&changes, "enum-member-added", &pointer), expected);
+    }
+
+    // 删除 oneOf 成员：请求中 breaking，响应中兼容
+    let changes = diff_open_api(&new, &old);
+    assert_eq!(
+        severity(
+            &changes,
+            "type-narrowed",
+            "#/components/schemas/PetInput/properties/pet"
+        ),
+        ChangeSeverity::Breaking
+    );
+    assert_eq!(
+        severity(
+            &changes,
+            "type-narrowed",
+            "#/components/schemas/PetOutput/properties/pet"
+        ),
+        ChangeSeverity::NonBreaking
+    );
+}
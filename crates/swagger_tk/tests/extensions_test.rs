@@ -0,0 +1,108 @@
+use std::str::FromStr;
+use swagger_tk::getter::get_schema_by_name;
+use swagger_tk::model::{OpenAPIObject, SchemaEnum};
+
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0", "x-logo": { "url": "/logo.png" } },
+  "x-generated-by": "backend",
+  "tags": [{ "name": "User", "x-displayName": "Users" }],
+  "paths": {
+    "/users": {
+      "get": {
+        "operationId": "getUsers",
+        "x-internal": true,
+        "x-aptx-name": "listUsers",
+        "unknownKey": "not-an-extension",
+        "parameters": [
+          { "name": "page", "in": "query", "schema": { "type": "integer" }, "x-example-page": 1 }
+        ],
+        "responses": { "200": { "description": "Success", "x-cache": "public" } }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Status": { "type": "integer", "enum": [0, 1], "x-enumNames": ["Draft", "Published"] },
+      "Remark": { "type": "string", "x-nullable": true }
+    }
+  }
+}
+"###;
+
+#[test]
+fn parse_vendor_extensions_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+
+    assert_eq!(
+        open_api.extensions.get_str("x-generated-by"),
+        Some("backend")
+    );
+    assert!(
+        open_api
+            .info
+            .as_ref()
+            .unwrap()
+            .extensions
+            .get("x-logo")
+            .is_some()
+    );
+    assert_eq!(
+        open_api.tags.as_ref().unwrap()[0]
+            .extensions
+            .get_str("x-displayName"),
+        Some("Users")
+    );
+
+    let operation = open_api.paths.as_ref().unwrap()["/users"]
+        .get
+        .as_ref()
+        .unwrap();
+    assert!(operation.extensions.is_internal());
+    assert_eq!(operation.extensions.aptx_name(), Some("listUsers"));
+    assert!(operation.extensions.get("unknownKey").is_none());
+    assert_eq!(
+        operation.responses.as_ref().unwrap()["200"]
+            .as_response()
+            .unwrap()
+            .extensions
+            .get_str("x-cache"),
+        Some("public")
+    );
+
+    let status = get_schema_by_name(&open_api, "Status").unwrap();
+    assert_eq!(
+        status.get_extensions().unwrap().enum_names(),
+        Some(vec!["Draft", "Published"])
+    );
+    let remark = get_schema_by_name(&open_api, "Remark").unwrap();
+    assert_eq!(remark.get_extensions().unwrap().nullable(), Some(true));
+    assert!(matches!(remark, SchemaEnum::String(v) if v.nullable == Some(true)));
+}
+
+#[test]
+fn serialize_keeps_vendor_extensions_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+    let operation = open_api.paths.as_ref().unwrap()["/users"]
+        .get
+        .as_ref()
+        .unwrap();
+    let value = serde_json::to_value(operation).expect("serialize operation fail");
+    assert_eq!(value["x-internal"], true);
+    assert_eq!(value["unknownKey"], "not-an-extension");
+
+    let mut extensions = operation.extensions.clone();
+    assert!(
+        extensions
+            .insert("summary", serde_json::json!("no"))
+            .is_err()
+    );
+    assert!(
+        extensions
+            .insert("x-owner", serde_json::json!("team-a"))
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(extensions.get_str("x-owner"), Some("team-a"));
+}
@@ -0,0 +1,164 @@
+use std::str::FromStr;
+use swagger_tk::filter::{SpecFilter, filter_open_api, glob_match};
+use swagger_tk::model::OpenAPIObject;
+
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "tags": [{ "name": "User" }, { "name": "Order" }],
+  "paths": {
+    "/users": {
+      "get": {
+        "tags": ["User"],
+        "operationId": "listUsers",
+        "parameters": [{ "$ref": "#/components/parameters/Page" }],
+        "responses": { "200": { "description": "Success", "content": { "application/json": {
+          "schema": { "type": "array", "items": { "$ref": "#/components/schemas/User" } }
+        } } } }
+      },
+      "post": {
+        "tags": ["User"],
+        "operationId": "createUser",
+        "x-internal": true,
+        "requestBody": { "$ref": "#/components/requestBodies/CreateUser" },
+        "responses": { "200": { "description": "Success" } }
+      }
+    },
+    "/orders/{id}": {
+      "get": {
+        "tags": ["Order"],
+        "operationId": "getOrder",
+        "responses": { "200": { "description": "Success", "content": { "application/json": {
+          "schema": { "$ref": "#/components/schemas/Order" }
+        } } } }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": {
+        "type": "object",
+        "properties": {
+          "address": { "$ref": "#/components/schemas/Address" },
+          "pet": {
+            "oneOf": [{ "$ref": "#/components/schemas/Cat" }],
+            "discriminator": { "propertyName": "kind", "mapping": { "dog": "#/components/schemas/Dog" } }
+          }
+        }
+      },
+      "Address": { "type": "object", "properties": { "city": { "type": "string" } } },
+      "Cat": { "type": "object" },
+      "Dog": { "type": "object" },
+      "CreateUser": { "type": "object", "properties": { "name": { "type": "string" } } },
+      "Order": { "type": "object", "properties": { "item": { "$ref": "#/components/schemas/OrderItem" } } },
+      "OrderItem": { "type": "object" }
+    },
+    "parameters": {
+      "Page": { "name": "page", "in": "query", "schema": { "type": "integer" } }
+    },
+    "requestBodies": {
+      "CreateUser": { "content": { "application/json": { "schema": { "$ref": "#/components/schemas/CreateUser" } } } }
+    },
+    "securitySchemes": {
+      "bearer": { "type": "http", "scheme": "bearer" }
+    }
+  }
+}
+"###;
+
+fn operation_ids(open_api: &OpenAPIObject) -> Vec<String> {
+    let mut result = open_api
+        .paths
+        .iter()
+        .flatten()
+        .flat_map(|(_, item)| item.get_operations())
+        .filter_map(|(_, operation)| operation.operation_id.clone())
+        .collect::<Vec<_>>();
+    result.sort();
+    result
+}
+
+fn schema_names(open_api: &OpenAPIObject) -> Vec<String> {
+    open_api
+        .components
+        .as_ref()
+        .and_then(|components| components.schemas.as_ref())
+        .map(|schemas| schemas.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn filter_by_tag_prunes_unreachable_components_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+    let filter = SpecFilter {
+        include_tags: vec!["User".to_string()],
+        exclude_internal: true,
+        ..Default::default()
+    };
+    let filtered = filter_open_api(&open_api, &filter).expect("filter openapi fail");
+
+    assert_eq!(operation_ids(&filtered), ["listUsers"]);
+    // 通过属性、oneOf 和 discriminator mapping 间接引用的 schema 都会保留
+    assert_eq!(schema_names(&filtered), ["Address", "Cat", "Dog", "User"]);
+    let components = filtered.components.as_ref().unwrap();
+    assert!(components.parameters.as_ref().unwrap().contains_key("Page"));
+    assert!(components.request_bodies.is_none());
+    assert!(components.security_schemes.is_some());
+
+    let tags = filtered.tags.as_ref().unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].name, "User");
+    assert!(filtered.paths.as_ref().unwrap().contains_key("/users"));
+    assert!(
+        !filtered
+            .paths
+            .as_ref()
+            .unwrap()
+            .contains_key("/orders/{id}")
+    );
+}
+
+#[test]
+fn filter_by_path_method_and_operation_id_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+
+    let filter = SpecFilter {
+        exclude_paths: vec!["/orders/**".to_string()],
+        include_methods: vec!["POST".to_string()],
+        ..Default::default()
+    };
+    let filtered = filter_open_api(&open_api, &filter).expect("filter openapi fail");
+    assert_eq!(operation_ids(&filtered), ["createUser"]);
+    assert_eq!(schema_names(&filtered), ["CreateUser"]);
+
+    let filter = SpecFilter {
+        exclude_operation_ids: vec!["listUsers".to_string(), "createUser".to_string()],
+        ..Default::default()
+    };
+    let filtered = filter_open_api(&open_api, &filter).expect("filter openapi fail");
+    assert_eq!(operation_ids(&filtered), ["getOrder"]);
+    assert_eq!(schema_names(&filtered), ["Order", "OrderItem"]);
+}
+
+#[test]
+fn empty_filter_keeps_everything_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+    let filtered = filter_open_api(&open_api, &SpecFilter::default()).expect("filter openapi fail");
+    assert_eq!(operation_ids(&filtered), operation_ids(&open_api));
+    assert_eq!(schema_names(&filtered), schema_names(&open_api));
+}
+
+#[test]
+fn glob_match_test() {
+    assert!(glob_match("/users", "/users"));
+    assert!(glob_match("/users/*", "/users/{id}"));
+    assert!(!glob_match("/users/*", "/users/{id}/orders"));
+    assert!(glob_match("/users/**", "/users"));
+    assert!(glob_match("/users/**", "/users/{id}/orders"));
+    assert!(!glob_match("/users/**", "/users-admin"));
+    assert!(glob_match("/**/orders", "/users/{id}/orders"));
+    assert!(glob_match("/**/orders", "/orders"));
+    assert!(glob_match("/api/v?/users", "/api/v2/users"));
+    assert!(!glob_match("/api/v?/users", "/api/v10/users"));
+}
@@ -65,5 +65,5 @@ fn get_mock_openapi() -> OpenAPIObject {
 fn get_paths_from_tag_test() {
     let open_api_object = get_mock_openapi();
     let paths = get_paths_from_tag(&open_api_object, "pet");
-    assert!(paths.len() > 0, "没有找到 pet 标签的路径");
+    assert!(!paths.is_empty(), "没有找到 pet 标签的路径");
 }
@@ -39,7 +39,7 @@ fn get_schema_name_list_test() {
     let schema_list = get_schema_name_list(&open_api_object);
     assert!(schema_list.is_some());
     let schemas = schema_list.unwrap();
-    assert!(schemas.len() > 0);
+    assert!(!schemas.is_empty());
     let order = "Order".to_string();
     let user = "User".to_string();
     assert!(schemas.contains(&&order));
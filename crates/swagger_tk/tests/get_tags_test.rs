@@ -39,7 +39,7 @@ fn get_mock_openapi() -> OpenAPIObject {
 fn get_tags_test() {
     let open_api_object = get_mock_openapi();
     let tags = get_tags(&open_api_object);
-    assert!(tags.len() > 0);
+    assert!(!tags.is_empty());
 }
 
 #[test]
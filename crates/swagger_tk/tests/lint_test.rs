@@ -0,0 +1,175 @@
+use std::str::FromStr;
+use swagger_tk::lint::{LintDiagnostic, has_lint_errors, lint_open_api};
+use swagger_tk::model::OpenAPIObject;
+
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users/{id}": {
+      "get": {
+        "operationId": "getUser",
+        "responses": {
+          "200": {
+            "description": "Success",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Missing" } } }
+          }
+        }
+      },
+      "put": {
+        "operationId": "getUser",
+        "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
+        "requestBody": {
+          "content": { "application/json": { "schema": { "type": "object" } } }
+        },
+        "security": [{ "bearer": [] }],
+        "responses": { "default": { "description": "Error" } }
+      }
+    },
+    "/users": {
+      "post": {
+        "parameters": [{ "$ref": "#/components/parameters/Unknown" }],
+        "responses": { "201": { "description": "Created" } }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Status": { "type": "integer", "enum": [1, 2] },
+      "Payload": { "type": "object", "properties": { "data": {} } }
+    }
+  }
+}
+"###;
+
+fn find<'a>(diagnostics: &'a [LintDiagnostic], rule: &str) -> Vec<&'a str> {
+    diagnostics
+        .iter()
+        .filter(|item| item.rule == rule)
+        .map(|item| item.pointer.as_str())
+        .collect()
+}
+
+#[test]
+fn lint_open_api_reports_rules_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+    let diagnostics = lint_open_api(&open_api);
+
+    assert_eq!(
+        find(&diagnostics, "operation-id-duplicate"),
+        vec!["#/paths/~1users~1{id}/put"]
+    );
+    assert_eq!(
+        find(&diagnostics, "operation-id-missing"),
+        vec!["#/paths/~1users/post"]
+    );
+    assert_eq!(
+        find(&diagnostics, "ref-unresolved"),
+        vec![
+            "#/paths/~1users/post/parameters/0",
+            "#/paths/~1users~1{id}/get/responses/200/content/application~1json/schema",
+        ]
+    );
+    assert_eq!(
+        find(&diagnostics, "path-param-undeclared"),
+        vec!["#/paths/~1users~1{id}/get"]
+    );
+    assert_eq!(
+        find(&diagnostics, "body-untyped"),
+        vec!["#/paths/~1users~1{id}/put/requestBody/content/application~1json/schema"]
+    );
+    assert_eq!(
+        find(&diagnostics, "response-success-missing"),
+        vec!["#/paths/~1users~1{id}/put"]
+    );
+    assert_eq!(
+        find(&diagnostics, "security-scheme-undeclared"),
+        vec!["#/paths/~1users~1{id}/put/security/0"]
+    );
+    assert_eq!(
+        find(&diagnostics, "schema-untyped"),
+        vec!["#/components/schemas/Payload/properties/data"]
+    );
+    assert_eq!(
+        find(&diagnostics, "enum-unnamed"),
+        vec!["#/components/schemas/Status"]
+    );
+    assert!(has_lint_errors(&diagnostics));
+}
+
+#[test]
+fn lint_open_api_clean_spec_test() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users/{id}": {
+      "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "integer" } }],
+      "get": {
+        "operationId": "getUser",
+        "responses": {
+          "200": {
+            "description": "Success",
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/User" } } }
+          }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": { "type": "object", "properties": { "name": { "type": "string" } } }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let diagnostics = lint_open_api(&open_api);
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    assert!(!has_lint_errors(&diagnostics));
+}
+
+#[test]
+fn lint_open_api_resolves_full_schema_ref_pointer_test() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "User": { "type": "object", "properties": { "name": { "type": "string" } } },
+      "name": { "type": "string" },
+      "Order": {
+        "type": "object",
+        "properties": {
+          "owner": { "$ref": "#/components/parameters/User" },
+          "ownerName": { "$ref": "#/components/schemas/User/properties/name" },
+          "ownerAge": { "$ref": "#/components/schemas/User/properties/age" },
+          "status": { "$ref": "#/components/schemas/User/properties/name/items" }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let diagnostics = lint_open_api(&open_api);
+
+    // Only the last segment used to be checked, so `User` and `name` were matched by accident
+    // while the valid nested ref was reported
+    assert_eq!(
+        find(&diagnostics, "ref-unresolved"),
+        vec![
+            "#/components/schemas/Order/properties/owner",
+            "#/components/schemas/Order/properties/ownerAge",
+            "#/components/schemas/Order/properties/status",
+        ]
+    );
+}
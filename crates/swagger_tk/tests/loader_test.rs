@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use swagger_tk::getter::get_schema_by_name;
+use swagger_tk::loader::{SpecFormat, load_open_api, parse_open_api, parse_open_api_auto};
+use swagger_tk::model::SchemaEnum;
+
+/// Mock OpenAPI spec in YAML, with unquoted status code keys
+const MOCK_OPENAPI_YAML: &str = r###"
+openapi: 3.1.0
+info:
+  title: mock-api
+  version: 1.0.0
+paths:
+  /users:
+    get:
+      operationId: getUsers
+      responses:
+        200:
+          description: Success
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/User'
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        id:
+          type: integer
+        name:
+          type: string
+"###;
+
+#[test]
+fn detect_format_test() {
+    assert_eq!(
+        SpecFormat::detect("  {\"openapi\": \"3.0.0\"}"),
+        SpecFormat::Json
+    );
+    assert_eq!(SpecFormat::detect(MOCK_OPENAPI_YAML), SpecFormat::Yaml);
+    assert_eq!(
+        SpecFormat::from_path(Path::new("api/openapi.YML")),
+        Some(SpecFormat::Yaml)
+    );
+    assert_eq!(SpecFormat::from_path(Path::new("openapi.txt")), None);
+    assert_eq!(
+        SpecFormat::resolve(Some(Path::new("openapi.json")), MOCK_OPENAPI_YAML),
+        SpecFormat::Yaml
+    );
+}
+
+#[test]
+fn parse_yaml_test() {
+    let open_api = parse_open_api_auto(MOCK_OPENAPI_YAML).expect("parse yaml openapi fail");
+    let operation = open_api.paths.as_ref().unwrap()["/users"]
+        .get
+        .as_ref()
+        .unwrap();
+    assert!(operation.responses.as_ref().unwrap().contains_key("200"));
+    assert!(matches!(
+        get_schema_by_name(&open_api, "User"),
+        Some(SchemaEnum::Object(_))
+    ));
+}
+
+#[test]
+fn yaml_syntax_error_reports_location_test() {
+    let text = "openapi: 3.1.0\ninfo:\n  title: [broken\n";
+    let err = parse_open_api(text, SpecFormat::Yaml).unwrap_err();
+    assert_eq!(err.format, Some(SpecFormat::Yaml));
+    assert!(err.line.is_some());
+    assert!(err.column.is_some());
+    assert!(err.to_string().starts_with("YAML parse error at line "));
+}
+
+#[test]
+fn yaml_structure_error_reports_location_test() {
+    let text = "openapi: 3.1.0\npaths:\n  /users: 1\n";
+    let err = parse_open_api(text, SpecFormat::Yaml).unwrap_err();
+    assert_eq!(err.line, Some(3));
+}
+
+#[test]
+fn json_error_reports_location_test() {
+    let err = parse_open_api("{\n  \"openapi\": 3\n}", SpecFormat::Json).unwrap_err();
+    assert_eq!(err.format, Some(SpecFormat::Json));
+    assert_eq!(err.line, Some(2));
+}
+
+#[test]
+fn load_open_api_test() {
+    let path = std::env::temp_dir().join(format!("swagger_tk_loader_{}.yaml", std::process::id()));
+    std::fs::write(&path, MOCK_OPENAPI_YAML).expect("write mock openapi fail");
+    let open_api = load_open_api(&path);
+    std::fs::remove_file(&path).ok();
+    assert!(open_api.is_ok(), "{}", open_api.unwrap_err());
+}
+
+#[test]
+fn load_open_api_structure_error_reports_location_test() {
+    let path = std::env::temp_dir().join(format!(
+        "swagger_tk_loader_invalid_{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&path, "{\n  \"openapi\": \"3.0.0\",\n  \"paths\": 1\n}").unwrap();
+    let err = load_open_api(&path).unwrap_err();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(err.format, Some(SpecFormat::Json));
+    assert_eq!(err.line, Some(3));
+}
+
+#[test]
+fn yaml_numbers_keep_original_text_test() {
+    let text = r###"
+openapi: 3.1.0
+info:
+  title: mock-api
+  version: 1.0.0
+components:
+  schemas:
+    Id:
+      type: integer
+      format: int64
+      maximum: 9223372036854775807
+      enum: [-9223372036854775808, 9007199254740993]
+    Price:
+      type: number
+      multipleOf: 0.01
+      maximum: 99999999999.999999999
+      enum: [19.990, 0.12345678901234567890123, 0x1F]
+"###;
+    let open_api = parse_open_api(text, SpecFormat::Yaml).expect("parse yaml openapi fail");
+    let Some(SchemaEnum::Integer(id)) = get_schema_by_name(&open_api, "Id") else {
+        panic!("Id should be an integer schema");
+    };
+    assert_eq!(id.maximum.as_ref().unwrap().as_i64(), Some(i64::MAX));
+    let ids = id
+        .r#enum
+        .iter()
+        .flatten()
+        .map(|v| v.as_i64())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [Some(i64::MIN), Some(9007199254740993)]);
+
+    let Some(SchemaEnum::Number(price)) = get_schema_by_name(&open_api, "Price") else {
+        panic!("Price should be a number schema");
+    };
+    let values = price
+        .r#enum
+        .iter()
+        .flatten()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["19.990", "0.12345678901234567890123", "31"]);
+    assert_eq!(price.multiple_of.as_ref().unwrap().to_string(), "0.01");
+    assert_eq!(
+        price.maximum.as_ref().unwrap().to_string(),
+        "99999999999.999999999"
+    );
+}
@@ -0,0 +1,233 @@
+use std::fs;
+use std::path::PathBuf;
+
+use swagger_tk::loader::load_open_api;
+
+const MANIFEST_YAML: &str = r###"
+info:
+  title: gateway
+  version: 1.0.0
+specs:
+  - input: ./user.json
+    prefix: user
+    basePath: /user-api
+  - input: ./order.yaml
+    prefix: order-service
+    basePath: /order-api/
+"###;
+
+const USER_JSON: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "user", "version": "1.0.0" },
+  "security": [{ "bearer": [] }],
+  "paths": {
+    "/users": {
+      "get": {
+        "tags": ["User"],
+        "operationId": "listUsers",
+        "responses": { "200": { "description": "Success", "content": { "application/json": {
+          "schema": { "type": "array", "items": { "$ref": "#/components/schemas/User" } }
+        } } } }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Page": { "type": "object", "properties": { "index": { "type": "integer" } } },
+      "Status": { "type": "string", "enum": ["active", "disabled"] },
+      "User": { "type": "object", "properties": { "status": { "$ref": "#/components/schemas/Status" } } }
+    },
+    "securitySchemes": {
+      "bearer": { "type": "http", "scheme": "bearer" }
+    }
+  }
+}
+"###;
+
+const ORDER_YAML: &str = r###"
+openapi: 3.0.1
+info:
+  title: order
+  version: 1.0.0
+paths:
+  /orders:
+    get:
+      operationId: listOrders
+      responses:
+        200:
+          description: Success
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Order"
+components:
+  schemas:
+    Page:
+      type: object
+      properties:
+        index:
+          type: integer
+    Status:
+      type: string
+      enum: [pending, paid]
+    Order:
+      type: object
+      properties:
+        status:
+          $ref: "#/components/schemas/Status"
+        page:
+          $ref: "#/components/schemas/Page"
+  securitySchemes:
+    bearer:
+      type: http
+      scheme: bearer
+"###;
+
+fn write_files(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("swagger_tk_{name}_{}", std::process::id()));
+    for (path, content) in files {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn load_open_api_merges_manifest_specs_test() {
+    let dir = write_files(
+        "merge",
+        &[
+            ("services.yaml", MANIFEST_YAML),
+            ("user.json", USER_JSON),
+            ("order.yaml", ORDER_YAML),
+        ],
+    );
+    let open_api = load_open_api(&dir.join("services.yaml")).expect("load merged spec fail");
+    let merged = serde_json::to_value(&open_api).unwrap();
+
+    assert_eq!(merged["info"]["title"], "gateway");
+    assert!(merged.get("security").is_none());
+
+    let users = &merged["paths"]["/user-api/users"]["get"];
+    assert_eq!(users["tags"], serde_json::json!(["user/User"]));
+    assert_eq!(users["security"], serde_json::json!([{ "bearer": [] }]));
+    let orders = &merged["paths"]["/order-api/orders"]["get"];
+    assert_eq!(orders["tags"], serde_json::json!(["order-service"]));
+    assert!(orders.get("security").is_none());
+
+    // 相同的 schema 合并，冲突的 schema 以前缀重命名并改写引用
+    let schemas = merged["components"]["schemas"].as_object().unwrap();
+    let mut names = schemas.keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(
+        names,
+        ["Order", "OrderServiceStatus", "Page", "Status", "User"]
+    );
+    assert_eq!(
+        schemas["User"]["properties"]["status"]["$ref"],
+        "#/components/schemas/Status"
+    );
+    assert_eq!(
+        schemas["Order"]["properties"]["status"]["$ref"],
+        "#/components/schemas/OrderServiceStatus"
+    );
+    assert_eq!(
+        schemas["Order"]["properties"]["page"]["$ref"],
+        "#/components/schemas/Page"
+    );
+    assert_eq!(
+        schemas["OrderServiceStatus"]["enum"],
+        serde_json::json!(["pending", "paid"])
+    );
+    assert_eq!(
+        merged["components"]["securitySchemes"]
+            .as_object()
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn load_open_api_rejects_duplicate_operations_test() {
+    let dir = write_files(
+        "merge_duplicate",
+        &[
+            (
+                "services.json",
+                r#"{ "specs": [{ "input": "./user.json" }, { "input": "./user.json" }] }"#,
+            ),
+            ("user.json", USER_JSON),
+        ],
+    );
+    let err = load_open_api(&dir.join("services.json")).expect_err("duplicate operation");
+    assert!(
+        err.message
+            .contains("operation GET /users is declared by several specs"),
+        "{}",
+        err.message
+    );
+}
+
+#[test]
+fn load_open_api_rejects_manifest_cycles_test() {
+    let dir = write_files(
+        "merge_cycle",
+        &[
+            (
+                "gateway.json",
+                r#"{ "specs": [{ "input": "./user.json" }, { "input": "./nested/services.json" }] }"#,
+            ),
+            (
+                "nested/services.json",
+                r#"{ "specs": [{ "input": "../gateway.json" }] }"#,
+            ),
+            ("user.json", USER_JSON),
+        ],
+    );
+    let err = load_open_api(&dir.join("gateway.json")).expect_err("manifest cycle");
+    assert!(
+        err.message.contains("merge manifest includes itself"),
+        "{}",
+        err.message
+    );
+    assert!(err.message.contains("gateway.json -> "), "{}", err.message);
+}
+
+#[test]
+fn load_open_api_renames_bare_discriminator_mapping_test() {
+    let payment = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "payment", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Status": { "type": "object", "properties": { "kind": { "type": "string" }, "paidAt": { "type": "string" } } },
+      "Event": {
+        "oneOf": [{ "$ref": "#/components/schemas/Status" }],
+        "discriminator": { "propertyName": "kind", "mapping": { "paid": "Status" } }
+      }
+    }
+  }
+}
+"###;
+    let dir = write_files(
+        "merge_mapping",
+        &[
+            (
+                "services.json",
+                r#"{ "specs": [{ "input": "./user.json" }, { "input": "./payment.json", "prefix": "pay" }] }"#,
+            ),
+            ("user.json", USER_JSON),
+            ("payment.json", payment),
+        ],
+    );
+    let open_api = load_open_api(&dir.join("services.json")).expect("load merged spec fail");
+    let merged = serde_json::to_value(&open_api).unwrap();
+    let event = &merged["components"]["schemas"]["Event"];
+    assert_eq!(event["oneOf"][0]["$ref"], "#/components/schemas/PayStatus");
+    assert_eq!(event["discriminator"]["mapping"]["paid"], "PayStatus");
+}
@@ -0,0 +1,249 @@
+use std::str::FromStr;
+use swagger_tk::loader::normalize_spec;
+use swagger_tk::model::OpenAPIObject;
+
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": {
+    "title": "mock-api",
+    "version": "1.0.0",
+    "termsOfService": "https://example.com/terms",
+    "x-logo": { "url": "/logo.png" }
+  },
+  "servers": [{ "url": "https://{env}.example.com", "variables": { "env": { "default": "api", "enum": ["api", "staging"] } } }],
+  "tags": [{ "name": "User", "description": "Users" }],
+  "security": [{ "bearer": [] }],
+  "paths": {
+    "/users/{id}": {
+      "summary": "User item",
+      "parameters": [{ "name": "id", "in": "path", "required": true, "schema": { "type": "string" } }],
+      "get": {
+        "operationId": "getUser",
+        "tags": ["User"],
+        "deprecated": true,
+        "responses": {
+          "200": {
+            "description": "Success",
+            "content": {
+              "application/json": {
+                "schema": { "$ref": "#/components/schemas/User" },
+                "examples": { "basic": { "summary": "A user", "value": { "id": "1", "name": "Tom" } } }
+              }
+            },
+            "links": { "self": { "operationId": "getUser", "parameters": { "id": "$response.body#/id" } } }
+          }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": {
+        "type": "object",
+        "title": "User",
+        "required": ["id"],
+        "properties": {
+          "id": { "type": "string", "format": "uuid", "readOnly": true },
+          "name": { "type": "string", "maxLength": 20, "example": "Tom" },
+          "age": { "type": "integer", "minimum": 0 },
+          "price": { "type": "number", "minimum": 0, "multipleOf": 0.01, "enum": [19.99, 100] },
+          "flags": { "type": "integer", "format": "int64", "enum": [4294967296] },
+          "tags": { "type": "array", "items": { "type": "string" }, "uniqueItems": true }
+        },
+        "additionalProperties": false,
+        "xml": { "name": "user" }
+      }
+    },
+    "securitySchemes": {
+      "bearer": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" }
+    }
+  }
+}
+"###;
+
+#[test]
+fn serialize_round_trip_is_lossless_test() {
+    let original = serde_json::from_str::<serde_json::Value>(MOCK_OPENAPI).unwrap();
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+    let value = serde_json::to_value(&open_api).expect("serialize openapi fail");
+    assert_eq!(value, original);
+}
+
+#[test]
+fn normalize_spec_sorts_keys_and_removes_duplicates_test() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "version": "1.0.0", "title": "mock-api" },
+  "tags": [{ "name": "User" }, { "name": "User", "description": "again" }],
+  "paths": {
+    "/users": {
+      "get": {
+        "tags": ["User", "User"],
+        "parameters": [
+          { "name": "page", "in": "query", "schema": { "type": "integer" } },
+          { "name": "page", "in": "query", "schema": { "type": "integer" } },
+          { "name": "page", "in": "header", "schema": { "type": "integer" } }
+        ],
+        "responses": {
+          "200": { "description": "Success", "content": { "application/json": {
+            "schema": { "type": "array", "items": { "type": "string" } },
+            "example": ["a", "a"]
+          } } }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Status": { "type": "string", "enum": ["a", "b", "a"] },
+      "User": { "type": "object", "required": ["name", "id", "name"], "properties": {
+        "name": { "type": "string" }, "id": { "type": "string" }
+      } }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let value = normalize_spec(&open_api).expect("normalize spec fail");
+
+    let keys = value.as_object().unwrap().keys().collect::<Vec<_>>();
+    assert_eq!(keys, ["components", "info", "openapi", "paths", "tags"]);
+    let info_keys = value["info"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .collect::<Vec<_>>();
+    assert_eq!(info_keys, ["title", "version"]);
+
+    assert_eq!(value["tags"].as_array().unwrap().len(), 1);
+    let operation = &value["paths"]["/users"]["get"];
+    assert_eq!(operation["tags"], serde_json::json!(["User"]));
+    let parameters = operation["parameters"].as_array().unwrap();
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(parameters[1]["in"], "header");
+
+    let schemas = &value["components"]["schemas"];
+    assert_eq!(schemas["Status"]["enum"], serde_json::json!(["a", "b"]));
+    assert_eq!(
+        schemas["User"]["required"],
+        serde_json::json!(["name", "id"])
+    );
+    // 示例值原样保留
+    assert_eq!(
+        operation["responses"]["200"]["content"]["application/json"]["example"],
+        serde_json::json!(["a", "a"])
+    );
+}
+
+#[test]
+fn serialize_round_trip_keeps_openapi_31_keywords_test() {
+    let source = r###"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Pet": {
+        "properties": {
+          "name": { "type": ["string", "null"], "maxLength": 20 },
+          "code": { "type": ["string", "integer"], "format": "int32" },
+          "kind": { "const": "pet" },
+          "age": { "type": "integer", "exclusiveMinimum": 0 },
+          "note": { "type": "string", "x-nullable": true },
+          "level": { "enum": [1, 2, null] },
+          "nothing": { "type": "null" }
+        }
+      }
+    }
+  }
+}
+"###;
+    let original = serde_json::from_str::<serde_json::Value>(source).unwrap();
+    let open_api = OpenAPIObject::from_str(source).expect("parse openapi fail");
+
+    // 模型中是 3.0 形式，供生成器使用
+    let schemas = open_api
+        .components
+        .as_ref()
+        .unwrap()
+        .schemas
+        .as_ref()
+        .unwrap();
+    let Some(swagger_tk::model::SchemaEnum::Object(pet)) = schemas.get("Pet") else {
+        panic!("Pet should be inferred as object");
+    };
+    let Some(swagger_tk::model::SchemaEnum::String(name)) =
+        pet.properties.as_ref().unwrap().get("name")
+    else {
+        panic!("name should be a string schema");
+    };
+    assert_eq!(name.nullable, Some(true));
+
+    let value = serde_json::to_value(&open_api).expect("serialize openapi fail");
+    assert_eq!(value, original);
+    let normalized = normalize_spec(&open_api).expect("normalize spec fail");
+    assert_eq!(normalized, original);
+}
+
+#[test]
+fn normalize_spec_decides_data_by_position_test() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "version": "1.0.0", "title": "mock-api" },
+  "paths": {
+    "/settings": {
+      "get": {
+        "responses": {
+          "default": { "description": "Settings", "content": { "application/json": {
+            "schema": { "$ref": "#/components/schemas/Settings" },
+            "examples": { "default": { "value": { "required": ["a", "a"] } } }
+          } } }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Settings": { "type": "object", "properties": {
+        "default": { "type": "object", "required": ["mode", "mode"], "properties": {
+          "mode": { "type": "string", "enum": ["on", "off", "on"], "default": "on" }
+        } },
+        "value": { "type": "array", "items": { "type": "string" }, "default": ["x", "x"] }
+      } },
+      "default": { "type": "string", "enum": ["a", "a"] }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let value = normalize_spec(&open_api).expect("normalize spec fail");
+
+    // 名为 default / value 的属性和 schema 仍按结构去重
+    let schemas = &value["components"]["schemas"];
+    let default_property = &schemas["Settings"]["properties"]["default"];
+    assert_eq!(default_property["required"], serde_json::json!(["mode"]));
+    assert_eq!(
+        default_property["properties"]["mode"]["enum"],
+        serde_json::json!(["on", "off"])
+    );
+    assert_eq!(schemas["default"]["enum"], serde_json::json!(["a"]));
+    // schema 的默认值和名为 default 的示例中的值是数据，原样保留
+    assert_eq!(
+        schemas["Settings"]["properties"]["value"]["default"],
+        serde_json::json!(["x", "x"])
+    );
+    let media =
+        &value["paths"]["/settings"]["get"]["responses"]["default"]["content"]["application/json"];
+    assert_eq!(
+        media["examples"]["default"]["value"]["required"],
+        serde_json::json!(["a", "a"])
+    );
+}
@@ -0,0 +1,168 @@
+use std::fs;
+
+use serde_json::json;
+use swagger_tk::loader::load_open_api_with_overlays;
+use swagger_tk::overlay::{JsonPathSegment, apply_overlay, select_json_path};
+
+fn mock_spec() -> serde_json::Value {
+    json!({
+      "openapi": "3.0.1",
+      "info": { "title": "mock-api", "version": "1.0.0" },
+      "paths": {
+        "/users": {
+          "get": {
+            "tags": ["Users"],
+            "parameters": [
+              { "name": "page", "in": "query", "schema": { "type": "integer" } },
+              { "name": "X-Trace-Id", "in": "header", "schema": { "type": "string" } }
+            ],
+            "responses": { "200": { "description": "Success" } }
+          }
+        },
+        "/users/{id}": {
+          "delete": {
+            "tags": ["Users"],
+            "parameters": [
+              { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+              { "name": "X-Trace-Id", "in": "header", "schema": { "type": "string" } }
+            ],
+            "responses": { "200": { "description": "Success" } }
+          }
+        }
+      },
+      "components": {
+        "schemas": {
+          "User": {
+            "type": "object",
+            "properties": {
+              "email": { "type": "string" },
+              "status": { "type": "string" }
+            }
+          }
+        }
+      }
+    })
+}
+
+#[test]
+fn select_json_path_test() {
+    let spec = mock_spec();
+    let select = |path: &str| select_json_path(&spec, path).expect("invalid path");
+
+    assert_eq!(
+        select("$.paths['/users'].get.parameters[-1].name"),
+        [[
+            JsonPathSegment::Key("paths".to_string()),
+            JsonPathSegment::Key("/users".to_string()),
+            JsonPathSegment::Key("get".to_string()),
+            JsonPathSegment::Key("parameters".to_string()),
+            JsonPathSegment::Index(1),
+            JsonPathSegment::Key("name".to_string()),
+        ]]
+    );
+    assert_eq!(select("$.paths.*.*").len(), 2);
+    assert_eq!(select("$..parameters[?(@.in == 'header')]").len(), 2);
+    assert_eq!(
+        select("$..parameters[?@.required && @.name != 'page']").len(),
+        1
+    );
+    assert_eq!(select("$.paths['/users'].get.parameters[0:1]").len(), 1);
+    assert_eq!(
+        select("$.components.schemas.User.properties['email','status']").len(),
+        2
+    );
+    assert!(select("$.paths.missing").is_empty());
+    assert!(select_json_path(&spec, "paths").is_err());
+    assert!(select_json_path(&spec, "$.paths[?(@.a ==)]").is_err());
+}
+
+#[test]
+fn apply_overlay_updates_and_removes_test() {
+    let mut spec = mock_spec();
+    let overlay = json!({
+      "overlay": "1.0.0",
+      "info": { "title": "fixes", "version": "1.0.0" },
+      "actions": [
+        {
+          "target": "$.components.schemas.User.properties.email",
+          "update": { "nullable": true, "format": "email" }
+        },
+        {
+          "target": "$.components.schemas.User.properties.status",
+          "update": { "enum": ["active", "disabled"] }
+        },
+        { "target": "$.paths.*.*.tags", "update": "User" },
+        {
+          "target": "$..parameters[?(@.name == 'X-Trace-Id')]",
+          "remove": true
+        },
+        { "target": "$.paths['/legacy']", "remove": true }
+      ]
+    });
+
+    let warnings = apply_overlay(&mut spec, &overlay).expect("apply overlay fail");
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("$.paths['/legacy']"), "{warnings:?}");
+
+    let properties = &spec["components"]["schemas"]["User"]["properties"];
+    assert_eq!(
+        properties["email"],
+        json!({ "type": "string", "nullable": true, "format": "email" })
+    );
+    assert_eq!(properties["status"]["enum"], json!(["active", "disabled"]));
+
+    let list = &spec["paths"]["/users"]["get"];
+    assert_eq!(list["tags"], json!(["Users", "User"]));
+    assert_eq!(list["parameters"].as_array().unwrap().len(), 1);
+    assert_eq!(list["parameters"][0]["name"], "page");
+    let delete = &spec["paths"]["/users/{id}"]["delete"];
+    assert_eq!(delete["parameters"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn apply_overlay_rejects_invalid_document_test() {
+    let mut spec = mock_spec();
+    let err = apply_overlay(&mut spec, &json!({ "overlay": "2.0.0", "actions": [] }))
+        .expect_err("unsupported version");
+    assert!(err.contains("unsupported overlay version"), "{err}");
+
+    let err = apply_overlay(
+        &mut spec,
+        &json!({ "overlay": "1.0.0", "actions": [{ "target": "$", "remove": true }] }),
+    )
+    .expect_err("remove root");
+    assert!(err.contains("overlay action 0"), "{err}");
+}
+
+#[test]
+fn load_open_api_with_overlays_test() {
+    let dir = std::env::temp_dir().join(format!("swagger_tk_overlay_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let spec_path = dir.join("openapi.json");
+    let overlay_path = dir.join("fixes.overlay.yaml");
+    fs::write(&spec_path, mock_spec().to_string()).unwrap();
+    fs::write(
+        &overlay_path,
+        r#"
+overlay: 1.0.0
+info:
+  title: fixes
+  version: 1.0.0
+actions:
+  - target: $.info
+    update:
+      title: patched-api
+  - target: $.paths['/users/{id}']
+    remove: true
+"#,
+    )
+    .unwrap();
+
+    let (open_api, warnings) =
+        load_open_api_with_overlays(&spec_path, &[overlay_path]).expect("load spec fail");
+    assert!(warnings.is_empty(), "{warnings:?}");
+    assert_eq!(open_api.info.unwrap().title, "patched-api");
+    let paths = open_api.paths.unwrap();
+    assert!(paths.contains_key("/users"));
+    assert!(!paths.contains_key("/users/{id}"));
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+use swagger_tk::getter::{
+    get_example_by_ref, get_header_by_ref, get_parameter_by_ref, get_path_item_by_ref,
+    get_request_body_by_ref, get_response_by_ref,
+};
+use swagger_tk::model::{OpenAPIObject, ParameterObjectIn, ResponseHeaders};
+
+/// Mock spec whose operations reuse components through `$ref`
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/users": {
+      "get": {
+        "parameters": [{ "$ref": "#/components/parameters/Limit" }],
+        "responses": { "200": { "$ref": "#/components/responses/UserList" } }
+      }
+    }
+  },
+  "components": {
+    "parameters": {
+      "PageSize": { "name": "pageSize", "in": "query", "schema": { "type": "integer" } },
+      "Limit": { "$ref": "#/components/parameters/PageSize" },
+      "Loop": { "$ref": "#/components/parameters/Loop" }
+    },
+    "requestBodies": {
+      "UserBody": {
+        "required": true,
+        "content": { "application/json": { "schema": { "type": "string" } } }
+      }
+    },
+    "responses": {
+      "UserList": {
+        "description": "Success",
+        "headers": { "X-Total": { "$ref": "#/components/headers/Total" } }
+      }
+    },
+    "headers": {
+      "Total": { "description": "total count", "schema": { "type": "integer" } }
+    },
+    "examples": {
+      "Alice": { "summary": "a user", "value": { "name": "alice" } }
+    },
+    "pathItems": {
+      "Users": { "$ref": "#/paths/~1users" }
+    }
+  }
+}
+"###;
+
+fn get_mock_openapi() -> OpenAPIObject {
+    OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse mock openapi fail")
+}
+
+#[test]
+fn resolve_parameter_chain_test() {
+    let open_api = get_mock_openapi();
+    let parameter = get_parameter_by_ref(&open_api, "#/components/parameters/Limit")
+        .expect("parameter should resolve");
+    assert_eq!(parameter.name, "pageSize");
+    assert_eq!(parameter.r#in, ParameterObjectIn::Query);
+    assert!(get_parameter_by_ref(&open_api, "#/components/parameters/Loop").is_none());
+    assert!(get_parameter_by_ref(&open_api, "#/components/schemas/PageSize").is_none());
+}
+
+#[test]
+fn resolve_component_types_test() {
+    let open_api = get_mock_openapi();
+    let body = get_request_body_by_ref(&open_api, "#/components/requestBodies/UserBody")
+        .expect("request body should resolve");
+    assert_eq!(body.required, Some(true));
+
+    let response = get_response_by_ref(&open_api, "#/components/responses/UserList")
+        .expect("response should resolve");
+    let header = response.headers.as_ref().unwrap()["X-Total"]
+        .resolve(&open_api)
+        .expect("header should resolve");
+    assert!(matches!(
+        response.headers.as_ref().unwrap()["X-Total"],
+        ResponseHeaders::Reference(_)
+    ));
+    assert_eq!(header.description.as_deref(), Some("total count"));
+    assert!(get_header_by_ref(&open_api, "#/components/headers/Total").is_some());
+
+    let example = get_example_by_ref(&open_api, "#/components/examples/Alice")
+        .expect("example should resolve");
+    assert_eq!(example.value.as_ref().unwrap()["name"], "alice");
+
+    let path_item = get_path_item_by_ref(&open_api, "#/components/pathItems/Users")
+        .expect("path item should resolve");
+    assert!(path_item.get.is_some());
+}
+
+#[test]
+fn resolve_operation_refs_test() {
+    let open_api = get_mock_openapi();
+    let operation = open_api.paths.as_ref().unwrap()["/users"]
+        .get
+        .as_ref()
+        .unwrap();
+    let parameter = operation.parameters.as_ref().unwrap()[0]
+        .resolve(&open_api)
+        .expect("operation parameter should resolve");
+    assert_eq!(parameter.name, "pageSize");
+    let response = operation.responses.as_ref().unwrap()["200"]
+        .resolve(&open_api)
+        .expect("operation response should resolve");
+    assert_eq!(response.description, "Success");
+}
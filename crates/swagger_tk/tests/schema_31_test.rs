@@ -0,0 +1,196 @@
+use std::str::FromStr;
+use swagger_tk::getter::{get_all_schema_defs, get_schema_by_name};
+use swagger_tk::model::{OpenAPIObject, SchemaEnum};
+
+/// Mock OpenAPI 3.1 spec using JSON Schema 2020-12 keywords
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.1.0",
+  "jsonSchemaDialect": "https://spec.openapis.org/oas/3.1/dialect/base",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Order": {
+        "type": "object",
+        "properties": {
+          "remark": { "type": ["string", "null"], "examples": ["urgent"] },
+          "status": { "type": "string", "enum": ["open", "closed", null] },
+          "version": { "const": 2 },
+          "amount": { "type": "number", "exclusiveMinimum": 0 },
+          "address": { "$ref": "#/$defs/Address" }
+        },
+        "$defs": {
+          "Address": {
+            "type": "object",
+            "properties": { "city": { "type": "string" } }
+          }
+        }
+      }
+    }
+  }
+}
+"###;
+
+fn get_mock_openapi() -> OpenAPIObject {
+    OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse mock openapi fail")
+}
+
+fn get_order_property<'a>(open_api: &'a OpenAPIObject, name: &str) -> &'a SchemaEnum {
+    get_schema_by_name(open_api, "Order")
+        .and_then(|schema| schema.get_object_property(name))
+        .expect("order property should exist")
+}
+
+#[test]
+fn json_schema_dialect_test() {
+    let open_api = get_mock_openapi();
+    assert_eq!(
+        open_api.json_schema_dialect.as_deref(),
+        Some("https://spec.openapis.org/oas/3.1/dialect/base")
+    );
+}
+
+#[test]
+fn type_array_with_null_test() {
+    let open_api = get_mock_openapi();
+    let SchemaEnum::String(remark) = get_order_property(&open_api, "remark") else {
+        panic!("remark should be a string schema");
+    };
+    assert_eq!(remark.nullable, Some(true));
+    assert_eq!(remark.examples.as_ref().map(Vec::len), Some(1));
+
+    let SchemaEnum::String(status) = get_order_property(&open_api, "status") else {
+        panic!("status should be a string schema");
+    };
+    assert_eq!(status.nullable, Some(true));
+    assert_eq!(status.r#enum.as_ref().map(Vec::len), Some(2));
+}
+
+#[test]
+fn const_and_exclusive_bound_test() {
+    let open_api = get_mock_openapi();
+    let SchemaEnum::Integer(version) = get_order_property(&open_api, "version") else {
+        panic!("const integer should infer an integer schema");
+    };
+    assert_eq!(version.r#enum, Some(vec![2.into()]));
+
+    let SchemaEnum::Number(amount) = get_order_property(&open_api, "amount") else {
+        panic!("amount should be a number schema");
+    };
+    assert_eq!(amount.minimum.as_ref().and_then(|v| v.as_f64()), Some(0.0));
+    assert_eq!(amount.exclusive_minimum, Some(true));
+}
+
+#[test]
+fn defs_test() {
+    let open_api = get_mock_openapi();
+    let defs = get_all_schema_defs(&open_api).expect("defs should not conflict");
+    assert!(defs.contains_key("Address"));
+    assert!(matches!(
+        get_schema_by_name(&open_api, "Address"),
+        Some(SchemaEnum::Object(_))
+    ));
+}
+
+#[test]
+fn conflicting_defs_test() {
+    let spec = |customer_money: &str| {
+        format!(
+            r###"
+{{
+  "openapi": "3.1.0",
+  "info": {{ "title": "mock-api", "version": "1.0.0" }},
+  "paths": {{}},
+  "components": {{
+    "schemas": {{
+      "Order": {{ "type": "object", "$defs": {{ "Money": {{ "type": "number" }} }} }},
+      "Customer": {{ "type": "object", "$defs": {{ "Money": {customer_money} }} }}
+    }}
+  }}
+}}
+"###
+        )
+    };
+
+    let same = OpenAPIObject::from_str(&spec(r#"{ "type": "number" }"#)).unwrap();
+    assert_eq!(get_all_schema_defs(&same).unwrap().len(), 1);
+
+    let conflicting = OpenAPIObject::from_str(&spec(r#"{ "type": "string" }"#)).unwrap();
+    let err = get_all_schema_defs(&conflicting).unwrap_err();
+    assert!(err.contains("#/components/schemas/Order/$defs/Money"));
+    assert!(err.contains("#/components/schemas/Customer/$defs/Money"));
+    assert!(get_schema_by_name(&conflicting, "Money").is_none());
+}
+
+#[test]
+fn nested_subschema_defs_test() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Order": {
+        "type": "object",
+        "properties": {
+          "lines": {
+            "type": "array",
+            "items": {
+              "type": "object",
+              "$defs": { "Sku": { "type": "string" } },
+              "properties": { "sku": { "$ref": "#/$defs/Sku" } }
+            }
+          },
+          "payment": { "oneOf": [{ "type": "object", "$defs": { "Card": { "type": "object" } } }] }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("parse openapi fail");
+    let defs = get_all_schema_defs(&open_api).expect("defs should not conflict");
+    assert_eq!(defs.keys().copied().collect::<Vec<_>>(), ["Card", "Sku"]);
+}
+
+#[test]
+fn boolean_schema_test() {
+    let source = r###"
+{
+  "openapi": "3.1.0",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Bag": {
+        "type": "object",
+        "properties": { "anything": true, "nothing": false }
+      }
+    }
+  }
+}
+"###;
+    let open_api = OpenAPIObject::from_str(source).expect("parse openapi fail");
+    let SchemaEnum::Any(_) = get_bag_property(&open_api, "anything") else {
+        panic!("`true` should be an any schema");
+    };
+    let SchemaEnum::Not(nothing) = get_bag_property(&open_api, "nothing") else {
+        panic!("`false` should be a never schema");
+    };
+    assert!(matches!(nothing.not.as_ref(), SchemaEnum::Any(_)));
+
+    let original = serde_json::from_str::<serde_json::Value>(source).unwrap();
+    let value = serde_json::to_value(&open_api).expect("serialize openapi fail");
+    assert_eq!(value, original);
+}
+
+fn get_bag_property<'a>(open_api: &'a OpenAPIObject, name: &str) -> &'a SchemaEnum {
+    let Some(SchemaEnum::Object(bag)) = get_schema_by_name(open_api, "Bag") else {
+        panic!("Bag should be an object schema");
+    };
+    &bag.properties.as_ref().unwrap()[name]
+}
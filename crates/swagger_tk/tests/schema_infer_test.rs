@@ -0,0 +1,138 @@
+use std::str::FromStr;
+use swagger_tk::getter::{get_schema_by_name, get_untyped_schemas};
+use swagger_tk::model::{OpenAPIObject, SchemaEnum};
+
+/// Mock spec whose schemas omit `type`
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {
+    "/pets": {
+      "get": {
+        "responses": {
+          "200": {
+            "description": "Success",
+            "content": { "application/json": { "schema": { "description": "free form" } } }
+          }
+        }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "Pet": {
+        "properties": {
+          "tags": { "items": { "type": "string" } },
+          "status": { "enum": ["available", "sold"] },
+          "level": { "enum": [1, 2, 3] },
+          "extra": {},
+          "removed": { "type": "null" }
+        }
+      }
+    }
+  }
+}
+"###;
+
+fn get_mock_openapi() -> OpenAPIObject {
+    OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse mock openapi fail")
+}
+
+fn get_pet_property<'a>(open_api: &'a OpenAPIObject, name: &str) -> &'a SchemaEnum {
+    get_schema_by_name(open_api, "Pet")
+        .and_then(|schema| schema.get_object_property(name))
+        .expect("pet property should exist")
+}
+
+#[test]
+fn infer_type_from_keywords_test() {
+    let open_api = get_mock_openapi();
+    assert!(matches!(
+        get_schema_by_name(&open_api, "Pet"),
+        Some(SchemaEnum::Object(_))
+    ));
+    assert!(matches!(
+        get_pet_property(&open_api, "tags"),
+        SchemaEnum::Array(_)
+    ));
+    assert!(matches!(
+        get_pet_property(&open_api, "status"),
+        SchemaEnum::String(_)
+    ));
+    assert!(matches!(
+        get_pet_property(&open_api, "level"),
+        SchemaEnum::Integer(_)
+    ));
+}
+
+#[test]
+fn fallback_to_any_schema_test() {
+    let open_api = get_mock_openapi();
+    assert!(matches!(
+        get_pet_property(&open_api, "extra"),
+        SchemaEnum::Any(_)
+    ));
+    let SchemaEnum::Any(removed) = get_pet_property(&open_api, "removed") else {
+        panic!("removed should be an any schema");
+    };
+    assert_eq!(removed.nullable, Some(true));
+}
+
+#[test]
+fn get_untyped_schemas_test() {
+    let open_api = get_mock_openapi();
+    assert_eq!(
+        get_untyped_schemas(&open_api),
+        vec![
+            "#/components/schemas/Pet/properties/extra",
+            "#/components/schemas/Pet/properties/removed",
+            "#/paths/~1pets/get/responses/200/content/application~1json/schema",
+        ]
+    );
+}
+
+#[test]
+fn unknown_type_fallback_to_any_schema_test() {
+    let open_api = OpenAPIObject::from_str(
+        r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "paths": {},
+  "components": {
+    "schemas": {
+      "Upload": {
+        "type": "object",
+        "properties": {
+          "file": { "type": "file" },
+          "odd": { "type": "weird", "properties": { "a": { "type": "string" } } }
+        }
+      }
+    }
+  }
+}
+"###,
+    )
+    .expect("unknown types should not stop the load");
+    let upload = get_schema_by_name(&open_api, "Upload").expect("upload should exist");
+    for name in ["file", "odd"] {
+        assert!(matches!(
+            upload.get_object_property(name),
+            Some(SchemaEnum::Any(_))
+        ));
+    }
+    assert_eq!(
+        get_untyped_schemas(&open_api),
+        vec![
+            "#/components/schemas/Upload/properties/file",
+            "#/components/schemas/Upload/properties/odd",
+        ]
+    );
+
+    // The original `type` is written back when serializing
+    let value = serde_json::to_value(&open_api).expect("serialize openapi fail");
+    let properties = &value["components"]["schemas"]["Upload"]["properties"];
+    assert_eq!(properties["file"]["type"], "file");
+    assert_eq!(properties["odd"]["type"], "weird");
+}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+use swagger_tk::getter::{get_operation_security, get_security_scheme};
+use swagger_tk::model::{OpenAPIObject, SecuritySchemeIn, SecuritySchemeType};
+
+const MOCK_OPENAPI: &str = r###"
+{
+  "openapi": "3.0.1",
+  "info": { "title": "mock-api", "version": "1.0.0" },
+  "security": [{ "apiKey": [] }],
+  "paths": {
+    "/login": {
+      "post": { "security": [], "responses": { "200": { "description": "Success" } } }
+    },
+    "/users": {
+      "get": { "responses": { "200": { "description": "Success" } } }
+    }
+  },
+  "components": {
+    "securitySchemes": {
+      "apiKey": { "type": "apiKey", "name": "X-Api-Key", "in": "header" },
+      "bearer": { "type": "http", "scheme": "bearer" },
+      "oidc": { "type": "openIdConnect", "openIdConnectUrl": "https://example.com/.well-known" },
+      "oauth": {
+        "type": "oauth2",
+        "flows": {
+          "implicit": { "authorizationUrl": "https://example.com/auth", "scopes": {} },
+          "password": { "tokenUrl": "https://example.com/token", "scopes": {} }
+        }
+      },
+      "alias": { "$ref": "#/components/securitySchemes/bearer" }
+    }
+  }
+}
+"###;
+
+#[test]
+fn parse_security_schemes_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+
+    let api_key = get_security_scheme(&open_api, "apiKey").expect("apiKey");
+    assert_eq!(api_key.r#type, SecuritySchemeType::ApiKey);
+    assert_eq!(api_key.r#in, Some(SecuritySchemeIn::Header));
+    assert_eq!(api_key.name.as_deref(), Some("X-Api-Key"));
+
+    let alias = get_security_scheme(&open_api, "alias").expect("alias");
+    assert_eq!(alias.r#type, SecuritySchemeType::Http);
+    assert_eq!(alias.scheme.as_deref(), Some("bearer"));
+
+    let oauth = get_security_scheme(&open_api, "oauth").expect("oauth");
+    let flows = oauth.flows.as_ref().expect("flows");
+    assert!(flows.implicit.as_ref().unwrap().token_url.is_none());
+    assert!(flows.password.as_ref().unwrap().authorization_url.is_none());
+
+    assert_eq!(
+        get_security_scheme(&open_api, "oidc").map(|item| &item.r#type),
+        Some(&SecuritySchemeType::OpenIDConnect)
+    );
+    assert!(get_security_scheme(&open_api, "missing").is_none());
+}
+
+#[test]
+fn operation_security_overrides_global_test() {
+    let open_api = OpenAPIObject::from_str(MOCK_OPENAPI).expect("parse openapi fail");
+    let paths = open_api.paths.as_ref().unwrap();
+
+    let login = paths["/login"].post.as_ref().unwrap();
+    assert_eq!(
+        get_operation_security(&open_api, login).map(Vec::len),
+        Some(0)
+    );
+
+    let users = paths["/users"].get.as_ref().unwrap();
+    let security = get_operation_security(&open_api, users).expect("global security");
+    assert!(security[0].contains_key("apiKey"));
+}
@@ -46,8 +46,8 @@ fn tags_test() {
     let paths = paths.as_ref().unwrap();
     let path_item = paths.get("/pet");
     assert!(path_item.is_some(), "没有找到 /pet 路径");
-    let tags = get_tags_from_path_item(&path_item.unwrap());
-    assert!(tags.len() > 0);
+    let tags = get_tags_from_path_item(path_item.unwrap());
+    assert!(!tags.is_empty());
     let tags = get_tags_from_paths(paths);
-    assert!(tags.len() > 0);
+    assert!(!tags.is_empty());
 }
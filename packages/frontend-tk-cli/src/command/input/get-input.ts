@@ -21,7 +21,7 @@ async function downloadJsonFile(url: string): Promise<string> {
     const response = await fetch(url, {
       signal: controller.signal,
       headers: {
-        Accept: "application/json",
+        Accept: "application/json, application/yaml;q=0.9, */*;q=0.8",
       },
     });
 
@@ -36,12 +36,15 @@ async function downloadJsonFile(url: string): Promise<string> {
 
     const content = await response.text();
     const trimmed = content.trim();
-    if (!trimmed.startsWith("{") && !trimmed.startsWith("[")) {
-      throw new Error("Downloaded content is not JSON.");
+    if (!trimmed) {
+      throw new Error("Downloaded content is empty.");
     }
 
     // Validate JSON early, avoid passing invalid payload to parser phase later.
-    JSON.parse(content);
+    // YAML payloads are validated by the parser, which reports line/column.
+    if (trimmed.startsWith("{") || trimmed.startsWith("[")) {
+      JSON.parse(content);
+    }
 
     const cacheDir = os.tmpdir();
     const fileName = buildTempFileName(url);
@@ -68,7 +71,7 @@ function buildTempFileName(url: string): string {
   const urlObj = new URL(url);
   const pathName = urlObj.pathname || "";
   const baseName = path.basename(pathName) || "swagger.json";
-  const safeName = baseName.includes(".json") ? baseName : `${baseName}.json`;
+  const safeName = /\.(json|ya?ml)$/i.test(baseName) ? baseName : `${baseName}.json`;
   const hash = crypto
     .createHash("sha1")
     .update(url)
@@ -11,6 +11,12 @@ export * from './types';
 // Export CLI factory and interface
 export { createCli, Cli } from './cli';
 
+// Export shared endpoint filter options
+export { specFilterOptions, buildSpecFilterArgs } from './spec-filter';
+
+// Export shared spec overlay option
+export { specOverlayOption, buildSpecOverlayArgs } from './spec-overlay';
+
 // Re-export commonly used types for convenience
 export type {
   Plugin,
@@ -0,0 +1,48 @@
+import type { OptionDescriptor } from './types';
+
+/**
+ * Endpoint filter flags shared by codegen commands.
+ * Each flag accepts a comma-separated list.
+ */
+const FILTER_FLAGS = [
+  ['include-tag', 'includeTag', 'Only generate endpoints with these tags'],
+  ['exclude-tag', 'excludeTag', 'Skip endpoints with these tags'],
+  ['include-path', 'includePath', 'Only generate endpoints whose path matches these globs (*, **, ?)'],
+  ['exclude-path', 'excludePath', 'Skip endpoints whose path matches these globs'],
+  ['include-method', 'includeMethod', 'Only generate endpoints with these HTTP methods'],
+  ['exclude-method', 'excludeMethod', 'Skip endpoints with these HTTP methods'],
+  ['include-operation-id', 'includeOperationId', 'Only generate endpoints with these operationIds'],
+  ['exclude-operation-id', 'excludeOperationId', 'Skip endpoints with these operationIds'],
+] as const;
+
+/**
+ * Option descriptors for endpoint filtering.
+ * Schemas no longer referenced by the remaining endpoints are pruned.
+ */
+export const specFilterOptions: OptionDescriptor[] = [
+  ...FILTER_FLAGS.map(([flag, , description]) => ({
+    flags: `--${flag} <list>`,
+    description: `${description} (comma-separated)`,
+  })),
+  {
+    flags: '--exclude-internal',
+    description: 'Skip endpoints marked with x-internal: true',
+    defaultValue: false,
+  },
+];
+
+/**
+ * Convert parsed filter options back into native command arguments.
+ */
+export function buildSpecFilterArgs(args: Record<string, unknown>): string[] {
+  const options: string[] = [];
+  for (const [flag, key] of FILTER_FLAGS) {
+    const value = args[key];
+    const values = Array.isArray(value) ? value : value ? [value] : [];
+    values.forEach((item) => options.push(`--${flag}`, String(item)));
+  }
+  if (args.excludeInternal) {
+    options.push('--exclude-internal');
+  }
+  return options;
+}
@@ -0,0 +1,19 @@
+import type { OptionDescriptor } from './types';
+
+/**
+ * Overlay option shared by codegen commands.
+ * The native binding applies the overlays to the input before it is parsed.
+ */
+export const specOverlayOption: OptionDescriptor = {
+  flags: '--overlay <files...>',
+  description: 'OpenAPI Overlay 1.0 documents applied to the input before generation, in order',
+};
+
+/**
+ * Convert the parsed overlay option back into native command arguments.
+ */
+export function buildSpecOverlayArgs(args: Record<string, unknown>): string[] {
+  const value = args.overlay;
+  const values = Array.isArray(value) ? value : value ? [value] : [];
+  return values.flatMap((item) => ['--overlay', String(item)]);
+}
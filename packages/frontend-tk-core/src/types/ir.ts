@@ -67,6 +67,7 @@ export interface RendererExecution {
 export interface ExecutionPlan {
   endpoint_count: number;
   transform_steps: string[];
+  parse_warnings: string[];
   renderer_reports: RendererExecution[];
   planned_files: PlannedFile[];
   skipped_files: number;
@@ -1,4 +1,11 @@
-import { CommandDescriptor, PluginContext } from '@aptx/frontend-tk-core';
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  CommandDescriptor,
+  PluginContext,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
 
 /**
  * aptx:functions command - Generate functions module code
@@ -58,6 +65,8 @@ export const functionsCommand: CommandDescriptor = {
       description: 'Preview mode: generate deletion report without updating manifest',
       defaultValue: false,
     },
+    ...specFilterOptions,
+    specOverlayOption,
   ],
   examples: [
     'aptx-ft aptx functions -i openapi.json -o ./generated',
@@ -65,6 +74,7 @@ export const functionsCommand: CommandDescriptor = {
     'aptx-ft aptx functions -i openapi.json -o ./generated --no-manifest',
     'aptx-ft aptx functions -i https://api.example.com/openapi.json -o ./src/api',
     'aptx-ft aptx functions -i openapi.json -o ./generated --client-mode local --client-path ./api/client.ts',
+    'aptx-ft aptx functions -i openapi.json -o ./generated --include-tag User,Order --exclude-internal',
   ],
   handler: async (ctx: PluginContext, args: Record<string, unknown>) => {
     const { binding, log } = ctx;
@@ -122,6 +132,8 @@ export const functionsCommand: CommandDescriptor = {
     if (dryRun) {
       options.push('--dry-run');
     }
+    options.push(...buildSpecFilterArgs(args));
+    options.push(...buildSpecOverlayArgs(args));
 
     log(`Generating functions module from ${input} to ${output}`);
     binding.runCli({
@@ -2,7 +2,7 @@
  * @aptx/frontend-tk-plugin-aptx
  *
  * Aptx namespace commands for the frontend toolkit CLI.
- * Provides commands like aptx:functions, aptx:react-query, aptx:vue-query, barrel:gen.
+ * Provides commands like aptx:functions, aptx:react-query, aptx:vue-query, aptx:webhooks, barrel:gen.
  */
 
 import type {
@@ -13,6 +13,7 @@ import { barrelCommand } from './barrel';
 import { functionsCommand } from './functions';
 import { reactQueryCommand } from './react-query';
 import { vueQueryCommand } from './vue-query';
+import { webhooksCommand } from './webhooks';
 
 /**
  * Aptx plugin descriptor
@@ -21,9 +22,9 @@ const aptxPlugin: Plugin = {
   descriptor: {
     name: '@aptx/frontend-tk-plugin-aptx',
     version: '0.1.0',
-    namespaceDescription: '@aptx ecosystem (functions, react-query, vue-query)',
+    namespaceDescription: '@aptx ecosystem (functions, react-query, vue-query, webhooks)',
   },
-  commands: [barrelCommand, functionsCommand, reactQueryCommand, vueQueryCommand],
+  commands: [barrelCommand, functionsCommand, reactQueryCommand, vueQueryCommand, webhooksCommand],
   renderers: [],
 };
 
@@ -1,4 +1,11 @@
-import { CommandDescriptor, PluginContext } from '@aptx/frontend-tk-core';
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  CommandDescriptor,
+  PluginContext,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
 
 /**
  * aptx:react-query command - Generate React Query hooks code
@@ -58,6 +65,8 @@ export const reactQueryCommand: CommandDescriptor = {
       description: 'Preview mode: generate deletion report without updating manifest',
       defaultValue: false,
     },
+    ...specFilterOptions,
+    specOverlayOption,
   ],
   examples: [
     'aptx-ft aptx react-query -i openapi.json -o ./generated',
@@ -122,6 +131,8 @@ export const reactQueryCommand: CommandDescriptor = {
     if (dryRun) {
       options.push('--dry-run');
     }
+    options.push(...buildSpecFilterArgs(args));
+    options.push(...buildSpecOverlayArgs(args));
 
     log(`Generating React Query hooks from ${input} to ${output}`);
     binding.runCli({
@@ -1,4 +1,11 @@
-import { CommandDescriptor, PluginContext } from '@aptx/frontend-tk-core';
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  CommandDescriptor,
+  PluginContext,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
 
 /**
  * aptx:vue-query command - Generate Vue Query composables code
@@ -58,6 +65,8 @@ export const vueQueryCommand: CommandDescriptor = {
       description: 'Preview mode: generate deletion report without updating manifest',
       defaultValue: false,
     },
+    ...specFilterOptions,
+    specOverlayOption,
   ],
   examples: [
     'aptx-ft aptx vue-query -i openapi.json -o ./generated',
@@ -122,6 +131,8 @@ export const vueQueryCommand: CommandDescriptor = {
     if (dryRun) {
       options.push('--dry-run');
     }
+    options.push(...buildSpecFilterArgs(args));
+    options.push(...buildSpecOverlayArgs(args));
 
     log(`Generating Vue Query composables from ${input} to ${output}`);
     binding.runCli({
@@ -0,0 +1,104 @@
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  CommandDescriptor,
+  PluginContext,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
+
+/**
+ * aptx:webhooks command - Generate webhook/callback handler types and a dispatcher
+ */
+export const webhooksCommand: CommandDescriptor = {
+  name: 'aptx:webhooks',
+  summary: 'Generate webhook handler types and a dispatcher from OpenAPI specification',
+  description: 'Generates payload types, typed handler signatures and a dispatcher keyed by event name for the webhooks and operation callbacks declared in the OpenAPI specification. Useful for receiving webhooks in a Node BFF.',
+  options: [
+    {
+      flags: '-i, --input <path>',
+      description: 'OpenAPI specification file path or URL',
+      required: true,
+    },
+    {
+      flags: '-o, --output <dir>',
+      description: 'Output directory for generated files',
+      required: true,
+    },
+    {
+      flags: '--model-mode <mode>',
+      description: 'Model import mode: relative (default) | package',
+    },
+    {
+      flags: '--model-path <path>',
+      description: 'Model import base path/package (e.g. ../../domains or @my-org/models)',
+    },
+    {
+      flags: '--no-manifest',
+      description: 'Disable manifest tracking and deletion report generation',
+      defaultValue: true,
+    },
+    {
+      flags: '--manifest-dir <path>',
+      description: 'Custom directory for manifest files (default: .generated)',
+      defaultValue: '.generated',
+    },
+    {
+      flags: '--dry-run',
+      description: 'Preview mode: generate deletion report without updating manifest',
+      defaultValue: false,
+    },
+    ...specFilterOptions,
+    specOverlayOption,
+  ],
+  examples: [
+    'aptx-ft aptx webhooks -i openapi.json -o ./src/webhooks',
+    'aptx-ft aptx webhooks -i openapi.json -o ./src/webhooks --model-mode package --model-path @my-org/models',
+  ],
+  handler: async (ctx: PluginContext, args: Record<string, unknown>) => {
+    const { binding, log } = ctx;
+    const input = args.input as string;
+    const output = args.output as string;
+    const modelMode = args.modelMode as string | undefined;
+    const modelPath = args.modelPath as string | undefined;
+    // Commander.js treats --no-manifest as a negation flag, setting `manifest` (not `noManifest`)
+    const noManifest = (args.manifest as boolean | undefined) === false;
+    const manifestDir = args.manifestDir as string | undefined;
+    const dryRun = args.dryRun as boolean | undefined;
+
+    if (!input) {
+      throw new Error('--input is required');
+    }
+    if (!output) {
+      throw new Error('--output is required');
+    }
+
+    const options = ['--output', output];
+    if (modelMode) {
+      options.push('--model-mode', modelMode);
+    }
+    if (modelPath) {
+      options.push('--model-path', modelPath);
+    }
+
+    if (noManifest) {
+      options.push('--no-manifest');
+    }
+    if (manifestDir) {
+      options.push('--manifest-dir', manifestDir);
+    }
+    if (dryRun) {
+      options.push('--dry-run');
+    }
+    options.push(...buildSpecFilterArgs(args));
+    options.push(...buildSpecOverlayArgs(args));
+
+    log(`Generating webhook handlers from ${input} to ${output}`);
+    binding.runCli({
+      input,
+      command: 'aptx:webhooks',
+      options,
+    });
+    log('Webhook handlers generated successfully');
+  },
+};
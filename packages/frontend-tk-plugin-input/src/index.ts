@@ -6,6 +6,13 @@ import type {
   OptionDescriptor,
 } from "@aptx/frontend-tk-core";
 import { runInputDownload } from "./download";
+import {
+  specBundleCommand,
+  specDiffCommand,
+  specLintCommand,
+  specNormalizeCommand,
+  specOverlayCommand,
+} from "./spec";
 
 /**
  * Plugin descriptor
@@ -13,7 +20,7 @@ import { runInputDownload } from "./download";
 const descriptor: PluginDescriptor = {
   name: "@aptx/frontend-tk-plugin-input",
   version: "0.1.0",
-  namespaceDescription: "Input handling (download)",
+  namespaceDescription: "Input handling (download, bundle, lint, normalize, diff, overlay)",
 };
 
 /**
@@ -73,7 +80,14 @@ const inputDownloadCommand: CommandDescriptor = {
  */
 const plugin: Plugin = {
   descriptor,
-  commands: [inputDownloadCommand],
+  commands: [
+    inputDownloadCommand,
+    specBundleCommand,
+    specDiffCommand,
+    specLintCommand,
+    specNormalizeCommand,
+    specOverlayCommand,
+  ],
 };
 
 export default plugin;
@@ -0,0 +1,282 @@
+import path from "path";
+import type {
+  CommandDescriptor,
+  CommandHandler,
+  PluginContext,
+} from "@aptx/frontend-tk-core";
+
+/**
+ * Ensure a path is absolute
+ * @param p - Path to convert
+ * @returns Absolute path
+ */
+function ensureAbsolutePath(p: string): string {
+  if (path.isAbsolute(p)) {
+    return p;
+  }
+  return path.join(process.cwd(), p);
+}
+
+/**
+ * Run a native spec:* command
+ * @param ctx - Plugin context
+ * @param command - Native command name
+ * @param options - Native command options
+ * @param input - Spec loaded by the binding before the command runs
+ */
+function runSpecCommand(
+  ctx: PluginContext,
+  command: string,
+  options: string[],
+  input?: string,
+): void {
+  const binding = ctx.binding as any;
+  if (typeof binding.runCli !== "function") {
+    ctx.log(`Native binding runCli not available for command: ${command}`);
+    return;
+  }
+  binding.runCli({ input, command, options });
+}
+
+/**
+ * Command handler for spec:bundle
+ */
+const specBundleHandler: CommandHandler = async (ctx, args): Promise<void> => {
+  const input = args.input as string | undefined;
+  const output = args.output as string | undefined;
+
+  if (!input) {
+    throw new Error("`input` is required. Use -i or set config.input.");
+  }
+  if (!output) {
+    throw new Error("--output option is required");
+  }
+
+  // The root file is read by the command itself so relative `$ref`s resolve against it
+  runSpecCommand(ctx, "spec:bundle", [
+    "--input",
+    ensureAbsolutePath(input),
+    "--output",
+    ensureAbsolutePath(output),
+  ]);
+};
+
+/**
+ * Command descriptor for spec:bundle
+ */
+export const specBundleCommand: CommandDescriptor = {
+  name: "spec:bundle",
+  summary: "Bundle a multi-file OpenAPI spec into a single document",
+  description:
+    "Resolves relative-file `$ref`s (e.g. ./schemas/User.json#/User), moves referenced schemas, parameters and responses into components, and writes one JSON or YAML document.",
+  options: [
+    {
+      flags: "-o, --output <file>",
+      description: "Output file path (.yaml/.yml writes YAML, otherwise JSON)",
+      required: true,
+    },
+  ],
+  examples: [
+    "aptx-ft -i ./spec/openapi.yaml spec bundle -o ./dist/openapi.json",
+  ],
+  handler: specBundleHandler,
+};
+
+/**
+ * Command handler for spec:lint
+ */
+const specLintHandler: CommandHandler = async (ctx, args): Promise<void> => {
+  const input = args.input as string | undefined;
+  if (!input) {
+    throw new Error("`input` is required. Use -i or set config.input.");
+  }
+
+  const options = ["--format", String(args.format ?? "text")];
+  if (args.output) {
+    options.push("--output", ensureAbsolutePath(String(args.output)));
+  }
+  // The native command throws when any diagnostic has error severity
+  runSpecCommand(ctx, "spec:lint", options, ensureAbsolutePath(input));
+};
+
+/**
+ * Command descriptor for spec:lint
+ */
+export const specLintCommand: CommandDescriptor = {
+  name: "spec:lint",
+  summary: "Check an OpenAPI spec for problems that degrade generated code",
+  description:
+    "Reports missing or duplicate operationIds, unresolved $refs, undeclared path parameters, untyped bodies, missing 2xx responses, unnamed numeric enums and more. Each diagnostic has a severity and a JSON pointer; the command fails when any error is found.",
+  options: [
+    {
+      flags: "--format <format>",
+      description: "Report format: text or json",
+      defaultValue: "text",
+    },
+    {
+      flags: "-o, --output <file>",
+      description: "Write the report to a file instead of stdout",
+    },
+  ],
+  examples: [
+    "aptx-ft -i ./openapi.json spec lint",
+    "aptx-ft -i ./openapi.json spec lint --format json -o ./lint-report.json",
+  ],
+  handler: specLintHandler,
+};
+
+/**
+ * Command handler for spec:normalize
+ */
+const specNormalizeHandler: CommandHandler = async (ctx, args): Promise<void> => {
+  const input = args.input as string | undefined;
+  const output = args.output as string | undefined;
+
+  if (!input) {
+    throw new Error("`input` is required. Use -i or set config.input.");
+  }
+  if (!output) {
+    throw new Error("--output option is required");
+  }
+
+  runSpecCommand(
+    ctx,
+    "spec:normalize",
+    ["--output", ensureAbsolutePath(output)],
+    ensureAbsolutePath(input),
+  );
+};
+
+/**
+ * Command descriptor for spec:normalize
+ */
+export const specNormalizeCommand: CommandDescriptor = {
+  name: "spec:normalize",
+  summary: "Re-serialize an OpenAPI spec in a stable, sorted form",
+  description:
+    "Loads the spec through the same model used by code generation and writes it back with sorted keys, de-duplicated required/enum/tags/parameters/security lists and pretty-printed output, so spec changes produce readable diffs.",
+  options: [
+    {
+      flags: "-o, --output <file>",
+      description: "Output file path (.yaml/.yml writes YAML, otherwise JSON)",
+      required: true,
+    },
+  ],
+  examples: [
+    "aptx-ft -i ./openapi.json spec normalize -o ./openapi.normalized.json",
+  ],
+  handler: specNormalizeHandler,
+};
+
+/**
+ * Command handler for spec:diff
+ */
+const specDiffHandler: CommandHandler = async (ctx, args): Promise<void> => {
+  const input = args.input as string | undefined;
+  const base = args.base as string | undefined;
+
+  if (!input) {
+    throw new Error("`input` is required. Use -i or set config.input.");
+  }
+  if (!base) {
+    throw new Error("--base option is required");
+  }
+
+  const options = [
+    "--base",
+    ensureAbsolutePath(base),
+    "--format",
+    String(args.format ?? "markdown"),
+  ];
+  if (args.output) {
+    options.push("--output", ensureAbsolutePath(String(args.output)));
+  }
+  // The native command throws when any change is breaking
+  runSpecCommand(ctx, "spec:diff", options, ensureAbsolutePath(input));
+};
+
+/**
+ * Command descriptor for spec:diff
+ */
+export const specDiffCommand: CommandDescriptor = {
+  name: "spec:diff",
+  summary: "Compare two OpenAPI specs and report breaking changes",
+  description:
+    "Structurally compares the input spec against --base and reports removed or moved operations, parameters that became required, changed request/response schemas, removed enum members and narrowed types. Each change is classified as breaking or non-breaking; the command fails when any breaking change is found.",
+  options: [
+    {
+      flags: "--base <file>",
+      description: "Previous version of the spec",
+      required: true,
+    },
+    {
+      flags: "--format <format>",
+      description: "Report format: markdown or json",
+      defaultValue: "markdown",
+    },
+    {
+      flags: "-o, --output <file>",
+      description: "Write the report to a file instead of stdout",
+    },
+  ],
+  examples: [
+    "aptx-ft -i ./openapi.json spec diff --base ./openapi.prev.json",
+    "aptx-ft -i ./openapi.json spec diff --base ./openapi.prev.json --format json -o ./diff.json",
+  ],
+  handler: specDiffHandler,
+};
+
+/**
+ * Command handler for spec:overlay
+ */
+const specOverlayHandler: CommandHandler = async (ctx, args): Promise<void> => {
+  const input = args.input as string | undefined;
+  const output = args.output as string | undefined;
+  const overlay = args.overlay as string[] | string | undefined;
+  const overlays = Array.isArray(overlay) ? overlay : overlay ? [overlay] : [];
+
+  if (!input) {
+    throw new Error("`input` is required. Use -i or set config.input.");
+  }
+  if (overlays.length === 0) {
+    throw new Error("--overlay option is required");
+  }
+  if (!output) {
+    throw new Error("--output option is required");
+  }
+
+  // The spec is read by the command itself so the patched document is written as-is
+  runSpecCommand(ctx, "spec:overlay", [
+    "--input",
+    ensureAbsolutePath(input),
+    ...overlays.flatMap((item) => ["--overlay", ensureAbsolutePath(item)]),
+    "--output",
+    ensureAbsolutePath(output),
+  ]);
+};
+
+/**
+ * Command descriptor for spec:overlay
+ */
+export const specOverlayCommand: CommandDescriptor = {
+  name: "spec:overlay",
+  summary: "Apply OpenAPI Overlay documents to a spec",
+  description:
+    "Applies the JSONPath-targeted update/remove actions of one or more OpenAPI Overlay 1.0 documents, in order, and writes the patched spec. Actions whose target matches nothing are reported as warnings.",
+  options: [
+    {
+      flags: "--overlay <files...>",
+      description: "Overlay documents (JSON or YAML), applied in order",
+      required: true,
+    },
+    {
+      flags: "-o, --output <file>",
+      description: "Output file path (.yaml/.yml writes YAML, otherwise JSON)",
+      required: true,
+    },
+  ],
+  examples: [
+    "aptx-ft -i ./openapi.json spec overlay --overlay ./fixes.overlay.yaml -o ./openapi.fixed.json",
+  ],
+  handler: specOverlayHandler,
+};
@@ -5,6 +5,12 @@
  * Provides commands like model:gen, model:ir, model:enum-plan, model:enum-apply.
  */
 
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
 import type {
   Plugin,
   PluginContext,
@@ -69,12 +75,15 @@ function createModelCommands(): CommandDescriptor[] {
           description: 'Preview mode: generate deletion report without updating manifest',
           defaultValue: false,
         },
+        ...specFilterOptions,
+        specOverlayOption,
       ],
       examples: [
         'aptx-ft model gen --input openapi.json --output ./src/models',
         'aptx-ft model gen --input openapi.json --output ./src/models --style module',
         'aptx-ft model gen --input openapi.json --output ./src/models --dry-run',
         'aptx-ft model gen --input openapi.json --output ./src/models --no-manifest',
+        'aptx-ft model gen --input openapi.json --output ./src/models --include-path "/users/**"',
       ],
       handler: async (ctx: PluginContext, args: Record<string, unknown>) => {
         const binding = ctx.binding as any;
@@ -91,6 +100,8 @@ function createModelCommands(): CommandDescriptor[] {
           if ((args.manifest as boolean | undefined) === false) options.push('--no-manifest');
           if (args.manifestDir) options.push('--manifest-dir', String(args.manifestDir));
           if (args.dryRun) options.push('--dry-run');
+          options.push(...buildSpecFilterArgs(args));
+          options.push(...buildSpecOverlayArgs(args));
 
           binding.runCli({
             input: args.input as string | undefined,
@@ -109,6 +120,8 @@ function createModelCommands(): CommandDescriptor[] {
       description: 'Exports the internal IR representation of OpenAPI schemas.',
       options: [
         ...commonModelOptions,
+        ...specFilterOptions,
+        specOverlayOption,
       ],
       examples: [
         'aptx-ft model ir --input openapi.json --output ./model-ir.json',
@@ -118,6 +131,8 @@ function createModelCommands(): CommandDescriptor[] {
         if (typeof binding.runCli === 'function') {
           const options: string[] = [];
           if (args.output) options.push('--output', String(args.output));
+          options.push(...buildSpecFilterArgs(args));
+          options.push(...buildSpecOverlayArgs(args));
 
           binding.runCli({
             input: args.input as string | undefined,
@@ -1,4 +1,11 @@
-import { CommandDescriptor, PluginContext } from '@aptx/frontend-tk-core';
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  CommandDescriptor,
+  PluginContext,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
 
 /**
  * python:functions command - Generate Python functions module code
@@ -46,6 +53,8 @@ export const functionsCommand: CommandDescriptor = {
         'Preview mode: generate deletion report without updating manifest',
       defaultValue: false,
     },
+    ...specFilterOptions,
+    specOverlayOption,
   ],
   examples: [
     'aptx-ft python functions -i openapi.json -o ./generated',
@@ -85,6 +94,8 @@ export const functionsCommand: CommandDescriptor = {
     if (dryRun) {
       options.push('--dry-run');
     }
+    options.push(...buildSpecFilterArgs(args));
+    options.push(...buildSpecOverlayArgs(args));
 
     log(`Generating Python functions from ${input} to ${output}`);
     binding.runCli({
@@ -1,4 +1,11 @@
-import { CommandDescriptor, PluginContext } from '@aptx/frontend-tk-core';
+import {
+  buildSpecFilterArgs,
+  buildSpecOverlayArgs,
+  CommandDescriptor,
+  PluginContext,
+  specFilterOptions,
+  specOverlayOption,
+} from '@aptx/frontend-tk-core';
 
 /**
  * python:model command - Generate Python Pydantic models from OpenAPI specification
@@ -37,6 +44,8 @@ export const modelCommand: CommandDescriptor = {
         'Preview mode: generate deletion report without updating manifest',
       defaultValue: false,
     },
+    ...specFilterOptions,
+    specOverlayOption,
   ],
   examples: [
     'aptx-ft python model -i openapi.json -o ./models',
@@ -68,6 +77,8 @@ export const modelCommand: CommandDescriptor = {
     if (dryRun) {
       options.push('--dry-run');
     }
+    options.push(...buildSpecFilterArgs(args));
+    options.push(...buildSpecOverlayArgs(args));
 
     log(`Generating Python models from ${input} to ${output}`);
     binding.runCli({